@@ -32,7 +32,7 @@
 
 use std::sync::Arc;
 
-use clamor_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use clamor_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Hash, Index};
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 pub use sc_rpc_api::DenyUnsafe;
@@ -68,10 +68,12 @@ where
 	C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: pallet_protos_rpc::ProtosRuntimeApi<Block, AccountId>,
+	C::Api: pallet_fragments_rpc::FragmentsRuntimeApi<Block, AccountId, AssetId, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
 	use pallet_contracts_rpc::{Contracts, ContractsApiServer};
+	use pallet_fragments_rpc::{Fragments, FragmentsApiServer};
 	use pallet_protos_rpc::{Protos, ProtosApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_rpc::dev::{Dev, DevApiServer};
@@ -89,7 +91,9 @@ where
 
 	io.merge(Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
-	io.merge(Protos::new(client).into_rpc())?;
+	io.merge(Protos::new(client.clone()).into_rpc())?;
+
+	io.merge(Fragments::<_, (Block, AssetId, BlockNumber)>::new(client).into_rpc())?;
 
 	Ok(io)
 }
@@ -24,6 +24,8 @@ mod benchmarking;
 
 mod weights;
 
+pub mod migration;
+
 /// keccak256(Lock(address,bytes,uint256)). Try it here: https://emn178.github.io/online-tools/keccak_256.html
 ///
 /// https://github.com/fragcolor-xyz/hasten-contracts/blob/clamor/contracts/FragToken.sol
@@ -81,13 +83,13 @@ pub mod crypto {
 	}
 }
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 pub use pallet::*;
 
 use sp_io::{crypto as Crypto, hashing::blake2_256, hashing::keccak_256};
 use sp_runtime::offchain::storage::StorageValueRef;
 use sp_runtime::MultiSigner;
-use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
+use sp_std::{vec, vec::Vec};
 
 use frame_system::offchain::{
 	AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
@@ -104,11 +106,14 @@ use serde_json::{json, Value};
 
 use ethabi::ParamType;
 
-use frame_support::traits::ReservableCurrency;
+use frame_support::{
+	traits::{ConstU32, ReservableCurrency},
+	BoundedBTreeSet,
+};
 
 pub type DiscordID = u64;
 
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub enum ExternalID {
 	Discord(DiscordID),
 }
@@ -141,7 +146,7 @@ pub struct EthLockUpdate<TPublic> {
 }
 
 /// **Struct** representing the details about the **total amount of locked FRAG Token of a particular Ethereum Account** in the **Fragnova-owned Ethereum Smart Contract** .
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct EthLock<TBalance, TBlockNum> {
 	/// Total amount of FRAG token locked (not just the newly locked FRAG token) by a particular Ethereum Account
 	pub amount: TBalance,
@@ -156,7 +161,7 @@ impl<T: SigningTypes> SignedPayload<T> for EthLockUpdate<T::Public> {
 }
 
 /// **Struct** representing the details about accounts created off-chain by various parties and integrations.
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct AccountInfo<TAccountID, TMoment> {
 	/// The actual account ID
 	pub account_id: TAccountID,
@@ -215,9 +220,12 @@ pub mod pallet {
 		}
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// **StorageMap** that maps an **Ethereum Account ID** to a to an ***Ethlock* struct of the aforementioned Ethereum Account Id (the struct contains the amount of FRAG token locked, amongst other things)**
@@ -251,17 +259,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type EVMLinkVotingClosed<T: Config> = StorageMap<_, Identity, H256, T::BlockNumber>;
 	// consumed by Protos pallet
-	/// **List of Clamor Accounts** whose **(FRAG staking)-related Storage Items** are **yet to be cleared**
+	/// **StorageMap** of the **Clamor Accounts** whose **(FRAG staking)-related Storage Items** are **yet to be cleared**
 	#[pallet::storage]
-	pub type PendingUnlinks<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+	pub type PendingUnlinks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ()>;
 
 	// These are the public keys representing the actual keys that can Sign messages
 	// to present to external chains to detach onto
 	/// **StorageValue** that equals the **List of Clamor Account IDs** that both ***validate*** and ***send*** **unsigned transactions with signed payload**
 	///
-	/// NOTE: Only the Root User of the Clamor Blockchain (i.e the local node itself) can edit `this list
+	/// NOTE: Only the Root User of the Clamor Blockchain (i.e the local node itself) can edit `this list, which can have at most 32 keys
 	#[pallet::storage]
-	pub type FragKeys<T: Config> = StorageValue<_, BTreeSet<ed25519::Public>, ValueQuery>;
+	pub type FragKeys<T: Config> =
+		StorageValue<_, BoundedBTreeSet<ed25519::Public, ConstU32<32>>, ValueQuery>;
 
 	#[pallet::storage]
 	pub type ExternalID2Account<T: Config> =
@@ -303,6 +312,8 @@ pub mod pallet {
 		AccountAlreadyExists,
 		/// Too many proxies
 		TooManyProxies,
+		/// Too many keys in `FragKeys`
+		TooManyKeys,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -320,11 +331,10 @@ pub mod pallet {
 
 			log::debug!("New key: {:?}", public);
 
-			<FragKeys<T>>::mutate(|validators| {
-				validators.insert(public);
-			});
-
-			Ok(())
+			<FragKeys<T>>::try_mutate(|validators| -> DispatchResult {
+				validators.try_insert(public).map_err(|_| Error::<T>::TooManyKeys)?;
+				Ok(())
+			})
 		}
 
 		/// Remove a Clamor Account ID from `FragKeys`
@@ -488,7 +498,7 @@ pub mod pallet {
 							// reset usage counter
 							<FragUsage<T>>::remove(linked.clone()); // Remove the Clamor Account `linked` from FragUsage
 										// force dereferencing of protos and more
-							<PendingUnlinks<T>>::append(linked.clone()); // Unlink `linked` from `sender`
+							<PendingUnlinks<T>>::insert(linked.clone(), ()); // Unlink `linked` from `sender`
 						}
 					}
 				}
@@ -680,7 +690,7 @@ pub mod pallet {
 				assert!(<FragKeys<T>>::get().is_empty(), "FragKeys are already initialized!");
 				for key in keys {
 					<FragKeys<T>>::mutate(|keys| {
-						keys.insert(*key);
+						assert!(keys.try_insert(*key).is_ok(), "Too many FragKeys!");
 					});
 				}
 			}
@@ -888,7 +898,7 @@ pub mod pallet {
 			// reset usage counter
 			<FragUsage<T>>::remove(sender.clone());
 			// force dereferencing of protos and more
-			<PendingUnlinks<T>>::append(sender.clone());
+			<PendingUnlinks<T>>::insert(sender.clone(), ());
 
 			// also emit event
 			Self::deposit_event(Event::Unlinked { sender, eth_key: account });
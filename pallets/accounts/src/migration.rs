@@ -0,0 +1,86 @@
+//! Storage migrations for the pallet `accounts`
+
+use crate::*;
+
+use frame_support::{
+	storage::migration::take_storage_value,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, storage::migration::get_storage_value, traits::OnRuntimeUpgradeHelpersExt};
+
+/// Version 1 moves the `Vec<T::AccountId>` of `PendingUnlinks` to one storage entry per account
+pub mod v1 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 0** to **version 1**
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!("accounts: skipping v1 migration, on-chain version is {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+			let mut writes: Weight = 1;
+
+			let unlinks =
+				take_storage_value::<Vec<T::AccountId>>(pallet, b"PendingUnlinks", &[]).unwrap_or_default();
+			for unlink in unlinks {
+				<PendingUnlinks<T>>::insert(unlink, ());
+				writes += 1;
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 1;
+
+			log::info!("accounts: migrated to storage version 1");
+
+			T::DbWeight::get().reads_writes(2, writes)
+		}
+
+		/// **Record** the **number of pending unlinks** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 1,
+				"accounts: v1 migration was already applied"
+			);
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+			let mut unlinks =
+				get_storage_value::<Vec<T::AccountId>>(pallet, b"PendingUnlinks", &[]).unwrap_or_default();
+			// the old list could contain the same account twice
+			unlinks.sort();
+			unlinks.dedup();
+
+			Self::set_temp_storage(unlinks.len() as u32, "accounts_v1_pending_unlinks");
+
+			Ok(())
+		}
+
+		/// **Check** that **every pending unlink** was **migrated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"accounts: storage version was not updated to 1"
+			);
+
+			let unlinks: u32 = Self::get_temp_storage("accounts_v1_pending_unlinks")
+				.ok_or("accounts: missing pre_upgrade data")?;
+			ensure!(
+				<PendingUnlinks<T>>::iter_keys().count() as u32 == unlinks,
+				"accounts: `PendingUnlinks` entries were lost"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -145,7 +145,7 @@ mod unlink_tests {
 
 			assert!(<FragUsage<Test>>::contains_key(&link.clamor_account_id) == false);
 
-			assert!(<PendingUnlinks<Test>>::get().contains(&link.clamor_account_id));
+			assert!(<PendingUnlinks<Test>>::contains_key(&link.clamor_account_id));
 
 			let event = <frame_system::Pallet<Test>>::events()
 				.pop()
@@ -480,7 +480,7 @@ mod internal_lock_update_tests {
             // TODO - Lock more FRAG token
 
             assert!(<FragUsage<Test>>::contains_key(&link.clamor_account_id) == false);
-            assert!(<PendingUnlinks<Test>>::get().contains(&link.clamor_account_id));
+            assert!(<PendingUnlinks<Test>>::contains_key(&link.clamor_account_id));
             
         });
 	}
@@ -557,7 +557,7 @@ mod internal_lock_update_tests {
 
 			assert!(<FragUsage<Test>>::contains_key(&link.clamor_account_id) == false);
 
-			assert!(<PendingUnlinks<Test>>::get().contains(&link.clamor_account_id));
+			assert!(<PendingUnlinks<Test>>::contains_key(&link.clamor_account_id));
 
 			let event = System::events()
 				.get(System::events().len() - 2)
@@ -594,3 +594,53 @@ mod internal_lock_update_tests {
 	}
 
 }
+
+mod add_key_tests {
+	use super::*;
+
+	#[test]
+	fn add_key_should_not_work_if_too_many_keys() {
+		new_test_ext().execute_with(|| {
+			for i in 0..32u8 {
+				assert_ok!(Accounts::add_key(Origin::root(), ed25519::Public::from_raw([i; 32])));
+			}
+			assert_noop!(
+				Accounts::add_key(Origin::root(), ed25519::Public::from_raw([32u8; 32])),
+				Error::<Test>::TooManyKeys
+			);
+			assert_eq!(<FragKeys<Test>>::get().len(), 32);
+		});
+	}
+}
+
+mod migration_tests {
+	use super::*;
+	use frame_support::{
+		storage::migration::put_storage_value,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	};
+
+	#[test]
+	fn migration_to_v1_should_split_pending_unlinks() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Accounts>();
+
+			let first = sp_core::ed25519::Public::from_raw([1u8; 32]);
+			let second = sp_core::ed25519::Public::from_raw([2u8; 32]);
+			// the old list could contain the same account twice
+			put_storage_value(
+				<Accounts as PalletInfoAccess>::name().as_bytes(),
+				b"PendingUnlinks",
+				&[],
+				vec![first, second, first],
+			);
+
+			migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Accounts::on_chain_storage_version(), 1);
+			assert!(<PendingUnlinks<Test>>::contains_key(first));
+			assert!(<PendingUnlinks<Test>>::contains_key(second));
+			assert_eq!(<PendingUnlinks<Test>>::iter_keys().count(), 2);
+		});
+	}
+}
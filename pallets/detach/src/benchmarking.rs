@@ -3,7 +3,7 @@
 use super::*;
 #[allow(unused)]
 use crate::Pallet as Detach;
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 
 const PUBLIC: [u8; 33] = [
@@ -29,5 +29,19 @@ benchmarks! {
 		assert!(!EthereumAuthorities::<T>::get().contains(&validator));
 	}
 
+	cancel_detach {
+		let caller: T::AccountId = whitelisted_caller();
+		let class = [7u8; 16];
+		Detach::<T>::add_detach_request(DetachRequest {
+			hash: DetachHash::Instance(class, 1, 1, 1),
+			target_chain: SupportedChains::EthereumGoerli,
+			target_account: vec![7u8; 20].try_into().unwrap(),
+		})?;
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + T::DetachTimeout::get());
+	}: _(RawOrigin::Signed(caller), class, 1, 1)
+	verify {
+		assert!(!DetachedInstances::<T>::contains_key((class, 1, 1)));
+	}
+
 	impl_benchmark_test_suite!(Detach, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -1,4 +1,4 @@
-//! This pallet `detach` performs logic related to Detaching a Proto-Fragment from the Clamor
+//! This pallet `detach` performs logic related to Detaching a Proto-Fragment or a Fragment Instance from the Clamor
 //! Blockchain to an External Blockchain
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -14,6 +14,8 @@ mod benchmarking;
 
 mod weights;
 
+pub mod migration;
+
 use sp_core::{crypto::KeyTypeId, ecdsa, ed25519, U256};
 
 /// Defines application identifier for crypto keys of this module.
@@ -66,35 +68,55 @@ pub mod crypto {
 pub use pallet::*;
 pub use weights::WeightInfo;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedBTreeSet, BoundedVec};
 use sp_io::{crypto as Crypto, hashing::keccak_256, offchain_index};
 use sp_runtime::{offchain::storage::StorageValueRef, MultiSigner};
 use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
 
-use sp_clamor::Hash256;
+use sp_clamor::{Hash128, Hash256};
 
 use frame_system::offchain::{
 	AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
 	SigningTypes,
 };
 
+/// **Account Address** on an **External Blockchain** (e.g an eth address), it can be at most 32 bytes long
+pub type TargetAccount = BoundedVec<u8, ConstU32<32>>;
+
+/// **Set of public keys** that are **authorized** to **sign** detach requests (see `EthereumAuthorities` and `FragKeys`), it can have at most 32 keys
+pub type KeySet<TPublic> = BoundedBTreeSet<TPublic, ConstU32<32>>;
+
 /// **Possible Blockchains into which a **Proto-Fragment** can be **detached**
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 pub enum SupportedChains {
 	EthereumMainnet,
 	EthereumRinkeby,
 	EthereumGoerli,
 }
 
-/// **Struct** that **represents** a **request to detach a Proto-Fragment**
-#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo)]
+/// **Enum** of **what** can be **detached**
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+pub enum DetachHash {
+	/// **Proto-Fragment**, identified by the **hash** of its **data**
+	Proto(Hash256),
+	/// **Fragment Instance**, identified by its **Fragment Definition ID**, its **Edition ID** and its **Copy ID**,
+	/// followed by the **number of items** it **represents** (`1` unless it is a **stack of stackable items**)
+	///
+	/// A Fragment Instance is **detached** as an **ERC-1155 token** (or an **ERC-721 token** when the number of items is `1`),
+	/// see `add_hash_to_payload`
+	Instance(Hash128, u64, u64, u64),
+}
+
+/// **Struct** that **represents** a **request to detach a Proto-Fragment or a Fragment Instance**
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 pub struct DetachRequest {
-	/// **Hash** of the **data** of the **Proto-Fragment**
-	pub hash: Hash256,
+	/// **Proto-Fragment** or **Fragment Instance** to **detach**
+	pub hash: DetachHash,
 	/// **External Blockchain** to **send** the **Proto-Fragment** to
 	pub target_chain: SupportedChains,
 	/// **Account Address** on the `target_chain` to send the `Proto-Fragment` to
-	pub target_account: Vec<u8>, // an eth address or so
+	pub target_account: TargetAccount, // an eth address or so
 }
 
 /// **Struct** that **represents** a ***signed* request** (signed by the Clamor Account Address `public`) to detach a **Proto-Fragment**
@@ -102,12 +124,12 @@ pub struct DetachRequest {
 pub struct DetachInternalData<TPublic> {
 	/// Clamor Public Account Address (the account address should be in FragKey, otherwise it fails)  (问Gio)
 	pub public: TPublic,
-	/// Proto-Fragment's Data's Hash
-	pub hash: Hash256,
+	/// Proto-Fragment or Fragment Instance to detach
+	pub hash: DetachHash,
 	/// External Blockchain to transfer the Proto-Fragment to
 	pub target_chain: SupportedChains,
 	/// PublicAccount Address of the External Blockchain to transfer the Proto-Fragment to
-	pub target_account: Vec<u8>, // an eth address or so
+	pub target_account: TargetAccount, // an eth address or so
 	/// Signature that is signed by an EthereumAuthority on the payload
 	pub remote_signature: Vec<u8>,
 	/// The number of signed detaches done by the `target_account` (whether successful or unsuccessful)
@@ -123,12 +145,12 @@ impl<T: SigningTypes> SignedPayload<T> for DetachInternalData<T::Public> {
 }
 
 /// **Struct** that **contains information** about a **detached Proto-Fragment**
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
 pub struct ExportData {
 	/// The **Blockchain** the **Proto-Fragment** was **detached onto**
 	chain: SupportedChains,
 	/// The account address (on the blockchain `SupportedChain`) that the Proto-Fragment was transfered into
-	owner: Vec<u8>,
+	owner: TargetAccount,
 	/// For now we don't allow to re-attach but in the future we will,
 	/// this nonce is in 1:1 relationship with the remote chain,
 	/// so that e.g. if we detach on ethereum the message cannot be repeated and needs to go 1:1 with clamor (INCDT)
@@ -154,6 +176,15 @@ use super::*;
 		type WeightInfo: WeightInfo;
 		/// The identifier type for an offchain worker.
 		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The **maximum number** of **detach requests** that can be **queued in a single block**
+		#[pallet::constant]
+		type MaxDetachRequests: Get<u32>;
+
+		/// The **number of blocks** after which the **detach request** of a **Fragment Instance** that the **offchain worker** **never finalized**
+		/// can be **cancelled** (see the `cancel_detach` extrinsic)
+		#[pallet::constant]
+		type DetachTimeout: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::genesis_config]
@@ -172,34 +203,72 @@ use super::*;
 		}
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// **StorageValue** that equals a **list of detach requests**
 	#[pallet::storage]
-	pub type DetachRequests<T: Config> = StorageValue<_, Vec<DetachRequest>, ValueQuery>;
+	pub type DetachRequests<T: Config> =
+		StorageValue<_, BoundedVec<DetachRequest, T::MaxDetachRequests>, ValueQuery>;
 
 	/// **StorageDoubleMap** that maps an **account address on an external blockchain and the external blockchain itself** to a **nonce**
 	#[pallet::storage]
 	pub type DetachNonces<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, Vec<u8>, Twox64Concat, SupportedChains, u64>;
+		StorageDoubleMap<_, Twox64Concat, TargetAccount, Twox64Concat, SupportedChains, u64>;
 
 	/// **StorageMap** that maps a **detached Proto-Fragment's hash** to an ***ExportData* enum (this enum contains information about the Proto-Fragment' detachment)**
 	#[pallet::storage]
 	pub type DetachedHashes<T: Config> = StorageMap<_, Identity, Hash256, ExportData>;
 
+	/// **StorageNMap** that maps the **Fragment Definition ID, the Edition ID and the Copy ID of a Fragment Instance**
+	/// that is **detached** (or **being detached**) to its ***ExportData***, which is `None` until the **detach** is **finalized**
+	///
+	/// A Fragment Instance is **added** as soon as its **detach request** is **queued**, so that it is **frozen** right away
+	#[pallet::storage]
+	pub type DetachedInstances<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, u64>,
+			// Copies
+			storage::Key<Identity, u64>,
+		),
+		Option<ExportData>,
+	>;
+
+	/// **StorageNMap** that maps the **Fragment Definition ID, the Edition ID and the Copy ID of a Fragment Instance** whose **detach** is **pending**
+	/// to the **block number** from which its **detach request** can be **cancelled** (see the `cancel_detach` extrinsic)
+	///
+	/// An entry is **removed** when the **detach** is **finalized** or **cancelled**
+	#[pallet::storage]
+	pub type PendingDetaches<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, u64>,
+			// Copies
+			storage::Key<Identity, u64>,
+		),
+		T::BlockNumber,
+	>;
+
 	/// **StorageValue** that equals the **set of ECDSA public keys of the Ethereum accounts** that are **authorized to detach a Proto-Fragment** onto **Fragnova's Ethereum Smart Contract**
 	#[pallet::storage]
-	pub type EthereumAuthorities<T: Config> = StorageValue<_, BTreeSet<ecdsa::Public>, ValueQuery>;
+	pub type EthereumAuthorities<T: Config> = StorageValue<_, KeySet<ecdsa::Public>, ValueQuery>;
 
 	// These are the public keys representing the actual keys that can Sign messages
 	// to present to external chains to detach onto
 	/// **StorageValue** that equals the **List of Clamor Account IDs** that both ***validate*** and ***send*** **unsigned transactions with signed payload**
 	/// NOTE: Only the Root User of the Clamor Blockchain (i.e the local node itself) can edit `FragKeys`
 	#[pallet::storage]
-	pub type FragKeys<T: Config> = StorageValue<_, BTreeSet<ed25519::Public>, ValueQuery>;
+	pub type FragKeys<T: Config> = StorageValue<_, KeySet<ed25519::Public>, ValueQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -208,7 +277,17 @@ use super::*;
 		Patched { fragment_data_hash: Hash256 },
 		MetadataChanged { fragment_data_hash: Hash256, remote_signature: Vec<u8> },
 		Detached { fragment_data_hash: Hash256, remote_signature: Vec<u8> },
+		InstanceDetached {
+			fragment_hash: Hash128,
+			fragment_id: (u64, u64),
+			remote_signature: Vec<u8>,
+		},
 		Transferred { fragment_data_hash: Hash256, account_id: T::AccountId },
+		/// The pending detach request of a Fragment Instance has been cancelled, the Fragment Instance is no longer frozen
+		DetachCancelled {
+			fragment_hash: Hash128,
+			fragment_id: (u64, u64),
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -220,6 +299,14 @@ use super::*;
 		NoValidator,
 		/// Failed to sign message
 		SigningFailed,
+		/// Too many detach requests in this block
+		TooManyDetachRequests,
+		/// No pending detach request
+		NotPending,
+		/// The detach request has not timed out yet
+		NotTimedOut,
+		/// Too many keys in the set
+		TooManyKeys,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -236,11 +323,10 @@ use super::*;
 
 			log::debug!("New eth auth: {:?}", public);
 
-			<EthereumAuthorities<T>>::mutate(|validators| {
-				validators.insert(public);
-			});
-
-			Ok(())
+			<EthereumAuthorities<T>>::try_mutate(|validators| -> DispatchResult {
+				validators.try_insert(public).map_err(|_| Error::<T>::TooManyKeys)?;
+				Ok(())
+			})
 		}
 
 		/// **Remove** an **ECDSA public key** from the **set of Ethereum accounts that are authorized to detach a Proto-Fragment onto Fragnova's Ethereum Smart Contract**
@@ -264,11 +350,10 @@ use super::*;
 
 			log::debug!("New key: {:?}", public);
 
-			<FragKeys<T>>::mutate(|validators| {
-				validators.insert(public);
-			});
-
-			Ok(())
+			<FragKeys<T>>::try_mutate(|validators| -> DispatchResult {
+				validators.try_insert(public).map_err(|_| Error::<T>::TooManyKeys)?;
+				Ok(())
+			})
 		}
 
 		/// Remove an **Ed25519 public key** from the **set of Clamor accounts that are authorized to sign a message**, where the **message** is a **request to detach a Proto-Fragment from Clamor**
@@ -285,8 +370,8 @@ use super::*;
 			Ok(())
 		}
 
-		// Detached a Proto-Fragment from this blockchain (Clamor) by emitting an event that includes a signature (note: the event is placed to the System pallet's runtime storage for the block this transaction runs it).
-		// The owner of the Proto-Fragment can then attach the Proto-Fragment to the remote target blockchain by using the aforementioned signature .
+		// Detached a Proto-Fragment or a Fragment Instance from this blockchain (Clamor) by emitting an event that includes a signature (note: the event is placed to the System pallet's runtime storage for the block this transaction runs it).
+		// The owner of the Proto-Fragment (or of the Fragment Instance) can then attach it to the remote target blockchain by using the aforementioned signature .
 		#[pallet::weight(25_000)] // TODO #1 - weight
 		pub fn internal_finalize_detach(
 			origin: OriginFor<T>,
@@ -295,6 +380,14 @@ use super::*;
 		) -> DispatchResult {
 			ensure_none(origin)?;
 
+			if let DetachHash::Instance(class, edition, copy, _amount) = data.hash {
+				// a cancelled detach request can no longer be finalized
+				ensure!(
+					<PendingDetaches<T>>::contains_key((class, edition, copy)),
+					Error::<T>::NotPending
+				);
+			}
+
 			// Update nonce
 			<DetachNonces<T>>::insert(&data.target_account, data.target_chain, data.nonce);
 
@@ -304,20 +397,78 @@ use super::*;
 				nonce: data.nonce,
 			};
 
-			// add to Detached protos map
-			<DetachedHashes<T>>::insert(data.hash, export_data);
+			match data.hash {
+				DetachHash::Proto(proto_hash) => {
+					// add to Detached protos map
+					<DetachedHashes<T>>::insert(proto_hash, export_data);
 
-			// emit event
-			// The function `deposit_event` places the event in the System pallet's runtime storage for that block (https://docs.substrate.io/v3/runtime/events-and-errors/)
-			Self::deposit_event(Event::Detached {
-				fragment_data_hash: data.hash,
-				remote_signature: data.remote_signature.clone(),
-			});
+					// emit event
+					// The function `deposit_event` places the event in the System pallet's runtime storage for that block (https://docs.substrate.io/v3/runtime/events-and-errors/)
+					Self::deposit_event(Event::Detached {
+						fragment_data_hash: proto_hash,
+						remote_signature: data.remote_signature.clone(),
+					});
+				},
+				DetachHash::Instance(class, edition, copy, _amount) => {
+					// the Fragment Instance was already frozen when the request was queued
+					<DetachedInstances<T>>::insert((class, edition, copy), Some(export_data));
+					<PendingDetaches<T>>::remove((class, edition, copy));
+
+					Self::deposit_event(Event::InstanceDetached {
+						fragment_hash: class,
+						fragment_id: (edition, copy),
+						remote_signature: data.remote_signature.clone(),
+					});
+				},
+			}
 
 			log::debug!("Detached hash: {:?} signature: {:?}", data.hash, data.remote_signature);
 
 			Ok(())
 		}
+
+		/// **Cancel** the **pending detach request** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`**, which **unfreezes** the **Fragment Instance** (see `DetachedInstances`).
+		///
+		/// A **cancelled** detach request can **no longer** be **finalized** by the **offchain worker**.
+		///
+		/// Note: **Anyone** is **allowed** to cancel a detach request, but **only once** `DetachTimeout` **blocks** have **passed** since it was **queued**
+		/// **without** the **offchain worker** **finalizing** it
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - **Fragment Definition ID** of the **Fragment Instance**
+		/// * `edition` - **Edition ID** of the **Fragment Instance**
+		/// * `copy` - **Copy ID** of the **Fragment Instance**
+		#[pallet::weight(T::WeightInfo::cancel_detach())]
+		pub fn cancel_detach(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: u64,
+			copy: u64,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let timeout_at =
+				<PendingDetaches<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotPending)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= timeout_at,
+				Error::<T>::NotTimedOut
+			);
+
+			// ! Writing
+
+			<PendingDetaches<T>>::remove((class, edition, copy));
+			<DetachedInstances<T>>::remove((class, edition, copy));
+
+			Self::deposit_event(Event::DetachCancelled {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+
+			Ok(())
+		}
 	}
 
 	/// Define some logic that should be executed regularly in some context, for e.g. `on_initialize`.
@@ -418,6 +569,28 @@ use super::*;
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// **Queue** a **detach request** that will be **processed by the offchain worker** at the **end of the current block**
+		///
+		/// If the request is for a **Fragment Instance**, it is **added** to `DetachedInstances` **immediately**,
+		/// and it can be **cancelled** `DetachTimeout` **blocks** later if it is **never finalized** (see `PendingDetaches`)
+		pub fn add_detach_request(request: DetachRequest) -> DispatchResult {
+			let hash = request.hash;
+
+			<DetachRequests<T>>::try_mutate(|requests| {
+				requests.try_push(request).map_err(|_| Error::<T>::TooManyDetachRequests)
+			})?;
+
+			if let DetachHash::Instance(class, edition, copy, _amount) = hash {
+				<DetachedInstances<T>>::insert((class, edition, copy), None::<ExportData>);
+				<PendingDetaches<T>>::insert(
+					(class, edition, copy),
+					<frame_system::Pallet<T>>::block_number() + T::DetachTimeout::get(),
+				);
+			}
+
+			Ok(())
+		}
+
 		/// Initializes the set of ECDSA public keys of the Ethereum accounts that are authorized to detach a Proto-Fragment
 		fn initialize_eth_authorities(authorities: &[ecdsa::Public]) {
 			if !authorities.is_empty() {
@@ -427,7 +600,10 @@ use super::*;
 				);
 				for authority in authorities {
 					<EthereumAuthorities<T>>::mutate(|authorities| {
-						authorities.insert(*authority);
+						assert!(
+							authorities.try_insert(*authority).is_ok(),
+							"Too many EthereumAuthorities!"
+						);
 					});
 				}
 			}
@@ -439,7 +615,7 @@ use super::*;
 				assert!(<FragKeys<T>>::get().is_empty(), "FragKeys are already initialized!");
 				for key in keys {
 					<FragKeys<T>>::mutate(|keys| {
-						keys.insert(*key);
+						assert!(keys.try_insert(*key).is_ok(), "Too many FragKeys!");
 					});
 				}
 			}
@@ -449,6 +625,8 @@ use super::*;
 		/// Signs the list of detach requests using an authority in `EthereumAuthorities`.
 		/// The format of each detach request (which is then signed) is of a tuple as follows:
 		/// (<Proto-Fragment Hash>, <Target Chain ID>, <An Account Address on the Target Chain>, <Nonce>)
+		/// or, for a Fragment Instance:
+		/// (<Fragment Definition ID>, <Edition ID>, <Copy ID>, <Amount>, <Target Chain ID>, <An Account Address on the Target Chain>, <Nonce>)
 		///
 		/// Then, for each of the signed detach requests - send an unsigned transaction with a signed payload onto the Clamor Blockchain
 		/// (NOTE: the signed payload consists of a payload and a signature.
@@ -514,8 +692,8 @@ use super::*;
 										*/
 
 										// The creation of the payload
-										// Add `request.hash.encode()` to payload
-										let mut payload = request.hash.encode();
+										let mut payload = Vec::new();
+										Self::add_hash_to_payload(&request, &mut payload);
 
 										Self::add_chainid_to_payload(&request, &mut payload);
 
@@ -649,6 +827,21 @@ use super::*;
 			nonce
 		}
 
+		/// Add what is detached to the payload: the 32 bytes of the hash of a Proto-Fragment, or
+		/// the 16 bytes of the Fragment Definition ID followed by the big endian Edition ID, Copy ID and amount (8 bytes each) of a Fragment Instance,
+		/// which are the token ID and the amount of an ERC-1155 (or ERC-721) bridge contract
+		fn add_hash_to_payload(request: &DetachRequest, payload: &mut Vec<u8>) {
+			match request.hash {
+				DetachHash::Proto(proto_hash) => payload.extend(&proto_hash[..]),
+				DetachHash::Instance(class, edition, copy, amount) => {
+					payload.extend(&class[..]);
+					payload.extend(edition.to_be_bytes());
+					payload.extend(copy.to_be_bytes());
+					payload.extend(amount.to_be_bytes());
+				},
+			}
+		}
+
 		fn add_chainid_to_payload(request: &DetachRequest, payload: &mut Vec<u8>) {
 			let chain_id = match request.target_chain {
 				SupportedChains::EthereumMainnet => U256::from(1),
@@ -0,0 +1,168 @@
+//! Storage migrations for the pallet `detach`
+
+use crate::*;
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+/// Version 1 bounds the target accounts of `DetachRequests`, `DetachNonces` and `DetachedHashes` to 32 bytes.
+///
+/// The encoding is the same as the previous `Vec<u8>`, so only the storage version is updated.
+pub mod v1 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 0** to **version 1**
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!("detach: skipping v1 migration, on-chain version is {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!("detach: migrated to storage version 1");
+
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		/// **Check** that **every detached Proto-Fragment** has a **target account** that **fits** the **new bound**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 1,
+				"detach: v1 migration was already applied"
+			);
+			ensure!(
+				<DetachedHashes<T>>::iter_keys().count() == <DetachedHashes<T>>::iter_values().count(),
+				"detach: some `DetachedHashes` entries have a target account longer than 32 bytes"
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that the **storage version** was **updated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"detach: storage version was not updated to 1"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 2 makes `DetachRequest::hash` a `DetachHash` (a Proto-Fragment or a Fragment Instance) instead of the hash of a Proto-Fragment,
+/// and times out the detach requests of Fragment Instances (see `PendingDetaches`).
+///
+/// Note: the detach requests that are waiting in the offchain storage (under "fragments-detach-requests") are not migrated,
+/// they are overwritten by the requests of the next block that has any.
+pub mod v2 {
+	use super::*;
+
+	/// **Layout** of a **`DetachRequest`** in **version 1**, when only a **Proto-Fragment** could be **detached**
+	#[derive(Encode, Decode)]
+	pub struct OldDetachRequest {
+		pub hash: Hash256,
+		pub target_chain: SupportedChains,
+		pub target_account: TargetAccount,
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 1** to **version 2**
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 2 {
+				log::info!(
+					"detach: skipping v2 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads: Weight = 2;
+			let mut writes: Weight = 2;
+
+			// `DetachRequests` is drained at the end of every block, so it should be empty here
+			let translated = <DetachRequests<T>>::translate::<
+				BoundedVec<OldDetachRequest, T::MaxDetachRequests>,
+				_,
+			>(|requests| {
+				requests.and_then(|requests| {
+					requests
+						.into_inner()
+						.into_iter()
+						.map(|request| DetachRequest {
+							hash: DetachHash::Proto(request.hash),
+							target_chain: request.target_chain,
+							target_account: request.target_account,
+						})
+						.collect::<Vec<_>>()
+						.try_into()
+						.ok()
+				})
+			});
+			if translated.is_err() {
+				log::error!("detach: failed to decode `DetachRequests`, they were dropped");
+				<DetachRequests<T>>::kill();
+			}
+
+			// the Fragment Instances that are already waiting for the offchain worker get a full timeout from now on
+			let timeout_at = <frame_system::Pallet<T>>::block_number() + T::DetachTimeout::get();
+			for ((class, edition, copy), export_data) in <DetachedInstances<T>>::iter() {
+				reads += 1;
+				if export_data.is_none() {
+					<PendingDetaches<T>>::insert((class, edition, copy), timeout_at);
+					writes += 1;
+				}
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("detach: migrated to storage version 2");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// **Check** that the **migration** was **not applied** already
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 2,
+				"detach: v2 migration was already applied"
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every pending detach** of a **Fragment Instance** can **time out** and that the **storage version** was **updated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"detach: storage version was not updated to 2"
+			);
+			ensure!(
+				<DetachedInstances<T>>::iter().all(|(key, export_data)| {
+					export_data.is_some() || <PendingDetaches<T>>::contains_key(key)
+				}),
+				"detach: some pending `DetachedInstances` entries can't time out"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -1,6 +1,6 @@
 pub use crate as pallet_detach;
 use crate::*;
-use frame_support::{pallet_prelude::ConstU32, parameter_types};
+use frame_support::{pallet_prelude::ConstU32, parameter_types, traits::ConstU64};
 use frame_system as system;
 use sp_core::{ed25519::Signature, H256};
 use sp_runtime::{
@@ -92,6 +92,8 @@ impl pallet_detach::Config for Test {
 	type Event = Event;
 	type WeightInfo = ();
 	type AuthorityId = pallet_detach::crypto::DetachAuthId;
+	type MaxDetachRequests = ConstU32<8>;
+	type DetachTimeout = ConstU64<10>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1 +1,68 @@
 // See Proto & Fragments for tests.
+
+use crate::{migration::v2::OldDetachRequest, mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+};
+
+#[test]
+fn migration_to_v2_should_wrap_proto_hashes() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<DetachPallet>();
+
+		let target_account: TargetAccount = vec![7u8; 20].try_into().unwrap();
+		put_storage_value(
+			<DetachPallet as PalletInfoAccess>::name().as_bytes(),
+			b"DetachRequests",
+			&[],
+			vec![OldDetachRequest {
+				hash: [1u8; 32],
+				target_chain: SupportedChains::EthereumGoerli,
+				target_account: target_account.clone(),
+			}],
+		);
+		// a Fragment Instance that is waiting for the offchain worker and one that is detached
+		<DetachedInstances<Test>>::insert(([1u8; 16], 1, 1), None::<ExportData>);
+		<DetachedInstances<Test>>::insert(
+			([2u8; 16], 1, 1),
+			Some(ExportData {
+				chain: SupportedChains::EthereumGoerli,
+				owner: target_account.clone(),
+				nonce: 1,
+			}),
+		);
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(DetachPallet::on_chain_storage_version(), 2);
+		assert_eq!(
+			<DetachRequests<Test>>::get().into_inner(),
+			vec![DetachRequest {
+				hash: DetachHash::Proto([1u8; 32]),
+				target_chain: SupportedChains::EthereumGoerli,
+				target_account,
+			}]
+		);
+		assert_eq!(<PendingDetaches<Test>>::get(([1u8; 16], 1, 1)), Some(10));
+		assert!(!<PendingDetaches<Test>>::contains_key(([2u8; 16], 1, 1)));
+	});
+}
+
+#[test]
+fn add_key_should_not_work_if_too_many_keys() {
+	new_test_ext().execute_with(|| {
+		for i in 0..32u8 {
+			assert_ok!(DetachPallet::add_key(Origin::root(), ed25519::Public::from_raw([i; 32])));
+		}
+		assert_noop!(
+			DetachPallet::add_key(Origin::root(), ed25519::Public::from_raw([32u8; 32])),
+			Error::<Test>::TooManyKeys
+		);
+		// keys that are already in the set can still be added
+		assert_ok!(DetachPallet::add_key(Origin::root(), ed25519::Public::from_raw([0u8; 32])));
+		assert_eq!(<FragKeys<Test>>::get().len(), 32);
+	});
+}
@@ -46,6 +46,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_eth_auth() -> Weight;
 	fn del_eth_auth() -> Weight;
+	fn cancel_detach() -> Weight;
 }
 
 /// Weights for pallet_detach using the Substrate node and recommended hardware.
@@ -63,6 +64,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Detach PendingDetaches (r:1 w:1)
+	// Storage: Detach DetachedInstances (r:0 w:1)
+	fn cancel_detach() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -79,4 +87,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Detach PendingDetaches (r:1 w:1)
+	// Storage: Detach DetachedInstances (r:0 w:1)
+	fn cancel_detach() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
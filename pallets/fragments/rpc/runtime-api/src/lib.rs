@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+
+use sp_std::vec::Vec;
+
+use pallet_fragments::{
+	GetDefinitionsParams, GetInstancesParams, GetOwnersParams, Listing, VaultBalances,
+};
+use sp_clamor::Hash128;
+
+sp_api::decl_runtime_apis! {
+	pub trait FragmentsApi<AccountId, AssetId, BlockNumber>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		BlockNumber: Codec
+	{
+		/// Balances that the creator of the Fragment Definition `fragment_hash` can withdraw from its vault
+		fn get_vault_balances(fragment_hash: Hash128) -> Option<VaultBalances<AssetId>>;
+		/// Royalty owed when a Fragment Instance of the Fragment Definition `fragment_hash` is resold for `price`,
+		/// as (beneficiary, amount)
+		fn get_royalty(fragment_hash: Hash128, price: u128) -> Option<(AccountId, u128)>;
+		/// Fragment Instances of the Fragment Definition `fragment_hash` that are listed on the secondary market,
+		/// as (Edition ID, Copy ID, Listing)
+		fn get_listings(fragment_hash: Hash128) -> Vec<(u64, u64, Listing<AssetId, AccountId, BlockNumber>)>;
+		/// Query Fragment Definitions (e.g the ones of a Proto-Fragment or the ones on sale), as a JSON string
+		fn get_definitions(params: GetDefinitionsParams<AccountId, Vec<u8>>) -> Result<Vec<u8>, Vec<u8>>;
+		/// Query Fragment Instances (e.g the inventory of an account) with their data, as a JSON string
+		fn get_instances(params: GetInstancesParams<AccountId, Vec<u8>>) -> Result<Vec<u8>, Vec<u8>>;
+		/// Query the owners of a Fragment Definition, as a JSON string
+		fn get_owners(params: GetOwnersParams<Vec<u8>>) -> Result<Vec<u8>, Vec<u8>>;
+		/// Total price of `quantity` Fragment Instances of the Fragment Definition `fragment_hash` if they were bought now,
+		/// following the pricing curve of its sale. `None` if they can't be bought
+		fn quote_price(fragment_hash: Hash128, quantity: u64) -> Option<u128>;
+	}
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_fragments::{GetDefinitionsParams, GetInstancesParams, GetOwnersParams};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_fragments_rpc_runtime_api::FragmentsApi as FragmentsRuntimeApi;
+
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait FragmentsApi<BlockHash, AccountId> {
+	#[method(name = "fragments_getDefinitions")]
+	fn get_definitions(
+		&self,
+		params: GetDefinitionsParams<AccountId, String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	#[method(name = "fragments_getInstances")]
+	fn get_instances(
+		&self,
+		params: GetInstancesParams<AccountId, String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	#[method(name = "fragments_getOwners")]
+	fn get_owners(
+		&self,
+		params: GetOwnersParams<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+}
+
+/// An implementation of fragments specific RPC methods.
+pub struct Fragments<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, P> Fragments<C, P> {
+	/// Create new `Fragments` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Fragments { client, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, AssetId, BlockNumber>
+	FragmentsApiServer<<Block as BlockT>::Hash, AccountId>
+	for Fragments<C, (Block, AssetId, BlockNumber)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: FragmentsRuntimeApi<Block, AccountId, AssetId, BlockNumber>,
+	AccountId: Codec,
+	AssetId: Codec + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+{
+	fn get_definitions(
+		&self,
+		params: GetDefinitionsParams<AccountId, String>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+
+		// If the block hash is not supplied in `at`, use the best block's hash
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let params_no_std = GetDefinitionsParams::<AccountId, Vec<u8>> {
+			desc: params.desc,
+			from: params.from,
+			limit: params.limit,
+			proto_hash: params.proto_hash.map(|s| s.into_bytes()),
+			creator: params.creator,
+			on_sale: params.on_sale,
+			metadata_keys: params.metadata_keys.into_iter().map(|s| s.into_bytes()).collect(),
+		};
+
+		into_rpc_result(api.get_definitions(&at, params_no_std))
+	}
+
+	fn get_instances(
+		&self,
+		params: GetInstancesParams<AccountId, String>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+
+		// If the block hash is not supplied in `at`, use the best block's hash
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let params_no_std = GetInstancesParams::<AccountId, Vec<u8>> {
+			from: params.from,
+			limit: params.limit,
+			definition_hash: params.definition_hash.map(|s| s.into_bytes()),
+			owner: params.owner,
+			metadata_keys: params.metadata_keys.into_iter().map(|s| s.into_bytes()).collect(),
+		};
+
+		into_rpc_result(api.get_instances(&at, params_no_std))
+	}
+
+	fn get_owners(
+		&self,
+		params: GetOwnersParams<String>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+
+		// If the block hash is not supplied in `at`, use the best block's hash
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let params_no_std = GetOwnersParams::<Vec<u8>> {
+			from: params.from,
+			limit: params.limit,
+			definition_hash: params.definition_hash.into_bytes(),
+		};
+
+		into_rpc_result(api.get_owners(&at, params_no_std))
+	}
+}
+
+/// Turn the JSON bytes returned by a runtime query function into the result of an RPC method
+fn into_rpc_result(
+	result: Result<Result<Vec<u8>, Vec<u8>>, sp_api::ApiError>,
+) -> RpcResult<String> {
+	match result {
+		Err(e) => Err(runtime_error_into_rpc_err(e)),
+		Ok(result) => match result {
+			Err(e) => Err(runtime_error_into_rpc_err(String::from_utf8_lossy(&e))),
+			Ok(result) => Ok(String::from_utf8(result).unwrap_or(String::from(""))),
+		},
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime error",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
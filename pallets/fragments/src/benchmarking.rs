@@ -3,13 +3,27 @@
 use super::*;
 #[allow(unused)]
 use crate::Pallet as Fragments;
-use frame_benchmarking::{benchmarks, vec, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, vec, whitelisted_caller};
+use frame_support::traits::tokens::fungibles::{Create, Mutate};
 use frame_system::RawOrigin;
 use protos::{
 	categories::{Categories, TextCategories},
 	permissions::FragmentPerms,
 };
 use sp_io::hashing::blake2_128;
+use sp_runtime::{
+	traits::{TrailingZeroInput, Zero},
+	DispatchError,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+const SEED: u32 = 0;
+
+/// Maximum number of Fragment Instances that are created in a single benchmarked call
+const MAX_QUANTITY: u32 = 1_000;
+
+/// Maximum size (in bytes) of the unique data that is attached to a Fragment Instance in a benchmarked call
+const MAX_DATA_LENGTH: u32 = 1_000_000;
 
 const PROTO_HASH: Hash256 = [
 	30, 138, 136, 186, 232, 46, 112, 65, 122, 54, 110, 89, 123, 195, 7, 150, 12, 134, 10, 179, 245,
@@ -20,6 +34,59 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Upload a Proto-Fragment owned by `caller` and create a Fragment Definition out of it, returning the ID of the Fragment Definition
+fn create_definition<T: Config>(
+	caller: &T::AccountId,
+	permissions: FragmentPerms,
+	unique: Option<UniqueOptions>,
+) -> Result<Hash128, DispatchError>
+where
+	T::AccountId: AsRef<[u8]>,
+{
+	let immutable_data = vec![0u8; 1 as usize];
+	let proto_hash = blake2_256(immutable_data.as_slice());
+	pallet_protos::Pallet::<T>::upload(RawOrigin::Signed(caller.clone()).into(), vec![], Categories::Text(TextCategories::Plain), <Vec<Vec<u8>>>::new(), None, None, immutable_data)?;
+
+	let fragment_data =
+		FragmentMetadata { name: "name".as_bytes().to_vec().try_into().unwrap(), currency: None };
+	let hash = blake2_128(
+		&[&proto_hash[..], &fragment_data.name.encode(), &fragment_data.currency.encode()].concat(),
+	);
+	fund::<T>(&Fragments::<T>::get_vault_id(hash));
+	Fragments::<T>::create(RawOrigin::Signed(caller.clone()).into(), proto_hash, fragment_data, permissions, unique, None, false, None, None, false)?;
+
+	Ok(hash)
+}
+
+/// Give `who` enough NOVA to pay for any benchmarked purchase
+fn fund<T: Config>(who: &T::AccountId) -> u128 {
+	let min_balance = <pallet_balances::Pallet<T> as Currency<T::AccountId>>::minimum_balance();
+	<pallet_balances::Pallet<T> as Currency<T::AccountId>>::make_free_balance_be(
+		who,
+		min_balance * (MAX_QUANTITY * 10).into(),
+	);
+	// the price of a single Fragment Instance, large enough to create the vault account
+	min_balance.saturated_into()
+}
+
+/// Create the asset `id` (in `pallet_assets`) administered by `admin` and mint some of it to `who`, returning the Asset ID
+fn create_asset<T: Config>(
+	id: u32,
+	admin: &T::AccountId,
+	who: &T::AccountId,
+) -> Result<T::AssetId, DispatchError> {
+	let asset_id = T::AssetId::decode(&mut TrailingZeroInput::new(&id.encode()))
+		.map_err(|_| DispatchError::Other("invalid asset id"))?;
+	<pallet_assets::Pallet<T> as Create<T::AccountId>>::create(
+		asset_id,
+		admin.clone(),
+		true,
+		1u32.into(),
+	)?;
+	<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::mint_into(asset_id, who, 100u32.into())?;
+	Ok(asset_id)
+}
+
 benchmarks! {
 	where_clause { where
 		T::AccountId: AsRef<[u8]>
@@ -32,18 +99,507 @@ benchmarks! {
 		let references = vec![PROTO_HASH];
 		pallet_protos::Pallet::<T>::upload(RawOrigin::Signed(caller.clone()).into(), references, Categories::Text(TextCategories::Plain), <Vec<Vec<u8>>>::new(), None, None, immutable_data.clone())?;
 		let fragment_data = FragmentMetadata {
-			name: "name".as_bytes().to_vec(),
+			name: "name".as_bytes().to_vec().try_into().unwrap(),
 			currency: None,
 		};
 
 		let hash = blake2_128(
 			&[&proto_hash[..], &fragment_data.name.encode(), &fragment_data.currency.encode()].concat(),
 		);
-
-	}: _(RawOrigin::Signed(caller.clone()), proto_hash, fragment_data, FragmentPerms::NONE, None, None)
+		fund::<T>(&Fragments::<T>::get_vault_id(hash));
+	}: _(RawOrigin::Signed(caller.clone()), proto_hash, fragment_data, FragmentPerms::NONE, None, None, false, None, None, false)
 	verify {
 		assert_last_event::<T>(Event::<T>::DefinitionCreated { fragment_hash: hash }.into())
 	}
 
+	publish {
+		let p in 0 .. T::MaxSalePhases::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let phases = (1..=p).map(|i| SalePhase { starts_at: now + i.into(), price: Compact(1), allowlist: Some(Allowlist::Accounts), max_per_account: Some(Compact(MAX_QUANTITY as Unit)) }).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, Some(MAX_QUANTITY as Unit), None, None, Some(Allowlist::Accounts), Some(MAX_QUANTITY as Unit), Some(now), phases, Some(PriceCurve::Decay { floor: Compact(1), duration: 10u32.into() }))
+	verify {
+		assert_last_event::<T>(Event::<T>::Publishing { fragment_hash }.into())
+	}
+
+	unpublish {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::publish(RawOrigin::Signed(caller.clone()).into(), fragment_hash, 1, None, None, None, None, None, None, vec![], None)?;
+	}: _(RawOrigin::Signed(caller), fragment_hash)
+	verify {
+		assert_last_event::<T>(Event::<T>::Unpublishing { fragment_hash }.into())
+	}
+
+	// minting through a minter is the worst case
+	mint_quantity {
+		let q in 1 .. MAX_QUANTITY;
+		let caller: T::AccountId = whitelisted_caller();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::set_minter(RawOrigin::Signed(caller).into(), fragment_hash, T::Lookup::unlookup(minter.clone()), Some(MinterTerms { quota: Some(Compact(MAX_QUANTITY as Unit)), expiration: None }))?;
+	}: mint(RawOrigin::Signed(minter.clone()), fragment_hash, FragmentBuyOptions::Quantity(q as Unit), None)
+	verify {
+		assert_last_event::<T>(Event::<T>::MinterUsed { fragment_hash, minter, quantity: q as Unit }.into())
+	}
+
+	mint_unique {
+		let s in 1 .. MAX_DATA_LENGTH;
+		let caller: T::AccountId = whitelisted_caller();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, Some(UniqueOptions { mutable: false }))?;
+		Fragments::<T>::set_minter(RawOrigin::Signed(caller).into(), fragment_hash, T::Lookup::unlookup(minter.clone()), Some(MinterTerms { quota: Some(Compact(1)), expiration: None }))?;
+	}: mint(RawOrigin::Signed(minter.clone()), fragment_hash, FragmentBuyOptions::UniqueData(vec![7u8; s as usize]), None)
+	verify {
+		assert_last_event::<T>(Event::<T>::MinterUsed { fragment_hash, minter, quantity: 1 }.into())
+	}
+
+	mint_to_many {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::set_minter(RawOrigin::Signed(caller).into(), fragment_hash, T::Lookup::unlookup(minter.clone()), Some(MinterTerms { quota: Some(Compact(n as Unit)), expiration: None }))?;
+		let recipients = (0..n).map(|i| T::Lookup::unlookup(account("recipient", i, SEED))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(minter.clone()), fragment_hash, recipients, None)
+	verify {
+		assert_last_event::<T>(Event::<T>::MinterUsed { fragment_hash, minter, quantity: n as Unit }.into())
+	}
+
+	buy_quantity {
+		let q in 1 .. MAX_QUANTITY;
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let price = fund::<T>(&buyer);
+		// checking the allowlist, counting the purchases and pricing every Fragment Instance on an exponential curve is the worst case
+		Fragments::<T>::set_allowlist(RawOrigin::Signed(caller.clone()).into(), fragment_hash, vec![T::Lookup::unlookup(buyer.clone())], true)?;
+		let curve = PriceCurve::Exponential { rate: Permill::from_parts(2_000) };
+		Fragments::<T>::publish(RawOrigin::Signed(caller.clone()).into(), fragment_hash, price, None, None, None, Some(Allowlist::Accounts), Some(MAX_QUANTITY as Unit), None, vec![], Some(curve))?;
+	}: buy(RawOrigin::Signed(buyer.clone()), fragment_hash, FragmentBuyOptions::Quantity(q as Unit), None)
+	verify {
+		assert!(<Inventory<T>>::contains_key((buyer, fragment_hash, q as Unit, 1)));
+	}
+
+	buy_unique {
+		let s in 1 .. MAX_DATA_LENGTH;
+		let caller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, Some(UniqueOptions { mutable: false }))?;
+		let price = fund::<T>(&buyer);
+		// checking the allowlist and counting the purchases is the worst case
+		Fragments::<T>::set_allowlist(RawOrigin::Signed(caller.clone()).into(), fragment_hash, vec![T::Lookup::unlookup(buyer.clone())], true)?;
+		Fragments::<T>::publish(RawOrigin::Signed(caller.clone()).into(), fragment_hash, price, None, None, None, Some(Allowlist::Accounts), Some(MAX_QUANTITY as Unit), None, vec![], None)?;
+	}: buy(RawOrigin::Signed(buyer.clone()), fragment_hash, FragmentBuyOptions::UniqueData(vec![7u8; s as usize]), None)
+	verify {
+		assert!(<Inventory<T>>::contains_key((buyer, fragment_hash, 1, 1)));
+	}
+
+	// the copy path of `give` is the heaviest one, as it also writes `CopiesCount` and `Expirations`
+	give {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER | FragmentPerms::COPY, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let expiration = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, T::Lookup::unlookup(to.clone()), None, Some(expiration))
+	verify {
+		assert_last_event::<T>(Event::<T>::InventoryAdded { account_id: to, fragment_hash, fragment_id: (1, 2) }.into())
+	}
+
+	give_many {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(n as Unit), None)?;
+		let items = (1..=n as Unit).map(|edition| (fragment_hash, edition, 1, T::Lookup::unlookup(to.clone()))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), items)
+	verify {
+		assert_last_event::<T>(Event::<T>::InventoryAdded { account_id: to, fragment_hash, fragment_id: (n as Unit, 1) }.into())
+	}
+
+	approve {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1, Some(T::Lookup::unlookup(spender.clone())))
+	verify {
+		assert_last_event::<T>(Event::<T>::Approval { fragment_hash, fragment_id: (1, 1), owner: caller, spender: Some(spender) }.into())
+	}
+
+	set_approval_for_all {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, T::Lookup::unlookup(operator.clone()), true)
+	verify {
+		assert_last_event::<T>(Event::<T>::ApprovalForAll { fragment_hash, owner: caller, operator, approved: true }.into())
+	}
+
+	transfer_from {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		// going through the operators after the approval is the worst case
+		Fragments::<T>::set_approval_for_all(RawOrigin::Signed(caller.clone()).into(), fragment_hash, T::Lookup::unlookup(operator.clone()), true)?;
+	}: _(RawOrigin::Signed(operator), fragment_hash, 1, 1, T::Lookup::unlookup(caller), T::Lookup::unlookup(to.clone()), None, None)
+	verify {
+		assert_last_event::<T>(Event::<T>::InventoryAdded { account_id: to, fragment_hash, fragment_id: (1, 1) }.into())
+	}
+
+	create_account {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let fragment_account = Fragments::<T>::get_fragment_account_id(fragment_hash, 1, 1);
+		// the owner pays for the deposit
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1)
+	verify {
+		assert!(!<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserved_balance(&fragment_account).is_zero());
+	}
+
+	as_fragment {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, Box::new(call))
+
+	renew {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let price = fund::<T>(&caller);
+		Definitions::<T>::mutate(fragment_hash, |definition| {
+			if let Some(definition) = definition {
+				definition.renewal = Some(Renewal { price: Compact(price), period: 100u32.into() });
+			}
+		});
+		let now = frame_system::Pallet::<T>::block_number();
+		Fragments::<T>::mint_fragments(&caller, &fragment_hash, None, &FragmentBuyOptions::Quantity(1), 1, now, Some(now + 10u32.into()), None)?;
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1)
+	verify {
+		assert_last_event::<T>(Event::<T>::Renewed { fragment_hash, fragment_id: (1, 1), expiring_at: now + 110u32.into() }.into())
+	}
+
+	process_expirations {
+		let n in 1 .. T::MaxExpirationsPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let expiration = now + 1u32.into();
+		Fragments::<T>::mint_fragments(&caller, &fragment_hash, None, &FragmentBuyOptions::Quantity(n as Unit), n as Unit, now, Some(expiration), None)?;
+		NextExpiration::<T>::put(expiration);
+	}: {
+		Fragments::<T>::process_expirations(expiration);
+	}
+	verify {
+		assert_eq!(Expirations::<T>::iter_prefix((expiration,)).count(), 0);
+		assert_last_event::<T>(Event::<T>::Expired { account_id: caller, fragment_hash, fragment_id: (n as Unit, 1) }.into())
+	}
+
+	process_listing_expirations {
+		let n in 1 .. T::MaxExpirationsPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(n as Unit), None)?;
+		let price = fund::<T>(&caller);
+		let expiration = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		for edition in 1..=(n as Unit) {
+			Fragments::<T>::list_instance(RawOrigin::Signed(caller.clone()).into(), fragment_hash, edition, 1, price, None, Some(expiration))?;
+		}
+		NextListingExpiration::<T>::put(expiration);
+	}: {
+		Fragments::<T>::process_listing_expirations(expiration);
+	}
+	verify {
+		assert_eq!(ListingExpirations::<T>::iter_prefix((expiration,)).count(), 0);
+		assert_last_event::<T>(Event::<T>::Delisted { fragment_hash, fragment_id: (n as Unit, 1) }.into())
+	}
+
+	process_auction_ends {
+		let n in 1 .. T::MaxAuctionsPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		// settling an English auction of a Fragment Instance that pays a royalty is the worst case
+		Definitions::<T>::mutate(fragment_hash, |definition| {
+			if let Some(definition) = definition {
+				definition.royalty = Some(Royalty { beneficiary: account("beneficiary", 0, SEED), percentage: Permill::from_percent(10) });
+			}
+		});
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(n as Unit), None)?;
+		let price = fund::<T>(&caller);
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+		for edition in 1..=(n as Unit) {
+			let kind = AuctionKind::English { reserve: Compact(price), extension: 0u32.into() };
+			Fragments::<T>::create_auction(RawOrigin::Signed(caller.clone()).into(), fragment_hash, AuctionItem::Instance { edition, copy: 1 }, kind, None, 1u32.into())?;
+			Fragments::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), edition - 1, price)?;
+		}
+		let end = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		NextAuctionEnd::<T>::put(end);
+	}: {
+		Fragments::<T>::process_auction_ends(end);
+	}
+	verify {
+		assert_eq!(AuctionEnds::<T>::iter_prefix(end).count(), 0);
+		assert_last_event::<T>(Event::<T>::AuctionSettled { auction_id: n as u64 - 1, winner: bidder, price }.into())
+	}
+
+	close_account {
+		let n in 0 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		fund::<T>(&caller);
+		Fragments::<T>::create_account(RawOrigin::Signed(caller.clone()).into(), fragment_hash, 1, 1)?;
+		let fragment_account = Fragments::<T>::get_fragment_account_id(fragment_hash, 1, 1);
+		// sweeping a balance of every asset is the worst case
+		let assets = (0..n).map(|i| create_asset::<T>(i, &caller, &fragment_account)).collect::<Result<Vec<_>, _>>()?;
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, assets.clone())
+	verify {
+		assert!(<pallet_balances::Pallet<T> as Currency<T::AccountId>>::total_balance(&fragment_account).is_zero());
+		for asset in assets {
+			assert!(<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::balance(asset, &fragment_account).is_zero());
+		}
+	}
+
+	burn {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::BURN, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1)
+	verify {
+		assert_last_event::<T>(Event::<T>::Burned { account_id: caller, fragment_hash, fragment_id: (1, 1) }.into())
+	}
+
+	split {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::EDIT, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), Some(100))?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1, 50)
+	verify {
+		assert_last_event::<T>(Event::<T>::InventoryAdded { account_id: caller, fragment_hash, fragment_id: (1, 2) }.into())
+	}
+
+	merge {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::EDIT, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(2), Some(100))?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1, 2, 1)
+	verify {
+		assert_last_event::<T>(Event::<T>::InventoryUpdated { account_id: caller, fragment_hash, fragment_id: (2, 1) }.into())
+	}
+
+	// consuming the whole stack is the heaviest path, as the Fragment Instance is burned
+	consume {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::EDIT, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), Some(100))?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1, 100)
+	verify {
+		assert_last_event::<T>(Event::<T>::Burned { account_id: caller, fragment_hash, fragment_id: (1, 1) }.into())
+	}
+
+	update_unique_data {
+		let s in 1 .. MAX_DATA_LENGTH;
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::EDIT, Some(UniqueOptions { mutable: true }))?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::UniqueData(vec![0u8]), None)?;
+		let data = vec![7u8; s as usize];
+		let data_hash = blake2_256(&data);
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, data)
+	verify {
+		assert_eq!(<UniqueData2Edition<T>>::get(fragment_hash, data_hash), Some(1));
+	}
+
+	withdraw_proceeds {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let amount = <pallet_balances::Pallet<T> as Currency<T::AccountId>>::free_balance(&Fragments::<T>::get_vault_id(fragment_hash)).saturated_into::<u128>();
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, amount, None)
+	verify {
+		assert_last_event::<T>(Event::<T>::ProceedsWithdrawn { fragment_hash, account_id: caller, currency: None, amount }.into())
+	}
+
+	list_instance {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller);
+		let expiration = Some(frame_system::Pallet::<T>::block_number() + 10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1, price, None, expiration)
+	verify {
+		assert_last_event::<T>(Event::<T>::Listed { fragment_hash, fragment_id: (1, 1), seller: caller, price, currency: None, expiration }.into())
+	}
+
+	cancel_listing {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller);
+		let expiration = Some(frame_system::Pallet::<T>::block_number() + 10u32.into());
+		Fragments::<T>::list_instance(RawOrigin::Signed(caller.clone()).into(), fragment_hash, 1, 1, price, None, expiration)?;
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, 1, 1)
+	verify {
+		assert_last_event::<T>(Event::<T>::Delisted { fragment_hash, fragment_id: (1, 1) }.into())
+	}
+
+	buy_instance {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		// paying a royalty is the worst case
+		Definitions::<T>::mutate(fragment_hash, |definition| {
+			if let Some(definition) = definition {
+				definition.royalty = Some(Royalty { beneficiary: account("beneficiary", 0, SEED), percentage: Permill::from_percent(10) });
+			}
+		});
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller) * 100;
+		Fragments::<T>::list_instance(RawOrigin::Signed(caller.clone()).into(), fragment_hash, 1, 1, price, None, None)?;
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		fund::<T>(&buyer);
+	}: _(RawOrigin::Signed(buyer.clone()), fragment_hash, 1, 1)
+	verify {
+		assert_last_event::<T>(Event::<T>::InstanceSold { fragment_hash, fragment_id: (1, 1), seller: caller, buyer, price, currency: None }.into())
+	}
+
+	create_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller);
+		let kind = AuctionKind::English { reserve: Compact(price), extension: 10u32.into() };
+	}: _(RawOrigin::Signed(caller.clone()), fragment_hash, AuctionItem::Instance { edition: 1, copy: 1 }, kind, None, 100u32.into())
+	verify {
+		assert_last_event::<T>(Event::<T>::AuctionCreated { auction_id: 0, fragment_hash, seller: caller }.into())
+	}
+
+	bid {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		// settling a Dutch auction of a Fragment Instance that pays a royalty is the worst case
+		Definitions::<T>::mutate(fragment_hash, |definition| {
+			if let Some(definition) = definition {
+				definition.royalty = Some(Royalty { beneficiary: account("beneficiary", 0, SEED), percentage: Permill::from_percent(10) });
+			}
+		});
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller) * 100;
+		let kind = AuctionKind::Dutch { start_price: Compact(price), end_price: Compact(price) };
+		Fragments::<T>::create_auction(RawOrigin::Signed(caller.clone()).into(), fragment_hash, AuctionItem::Instance { edition: 1, copy: 1 }, kind, None, 100u32.into())?;
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+	}: _(RawOrigin::Signed(bidder.clone()), 0, price)
+	verify {
+		assert_last_event::<T>(Event::<T>::AuctionSettled { auction_id: 0, winner: bidder, price }.into())
+	}
+
+	cancel_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&caller);
+		let kind = AuctionKind::English { reserve: Compact(price), extension: 10u32.into() };
+		Fragments::<T>::create_auction(RawOrigin::Signed(caller.clone()).into(), fragment_hash, AuctionItem::Instance { edition: 1, copy: 1 }, kind, None, 100u32.into())?;
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_last_event::<T>(Event::<T>::AuctionClosed { auction_id: 0 }.into())
+	}
+
+	detach {
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		// delisting the Fragment Instance is the worst case
+		let price = fund::<T>(&caller);
+		let expiration = Some(frame_system::Pallet::<T>::block_number() + 10u32.into());
+		Fragments::<T>::list_instance(RawOrigin::Signed(caller.clone()).into(), fragment_hash, 1, 1, price, None, expiration)?;
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, SupportedChains::EthereumMainnet, vec![7u8; 20])
+	verify {
+		assert!(DetachedInstances::<T>::contains_key((fragment_hash, 1, 1)));
+		assert_last_event::<T>(Event::<T>::Delisted { fragment_hash, fragment_id: (1, 1) }.into())
+	}
+
+	set_allowlist {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let accounts = (0..n).map(|i| T::Lookup::unlookup(account("buyer", i, SEED))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), fragment_hash, accounts, true)
+	verify {
+		assert!(<SaleAllowlists<T>>::contains_key(fragment_hash, account::<T::AccountId>("buyer", n - 1, SEED)));
+		assert_last_event::<T>(Event::<T>::AllowlistUpdated { fragment_hash }.into())
+	}
+
+	set_definition_metadata {
+		let s in 1 .. MAX_DATA_LENGTH;
+		let caller: T::AccountId = whitelisted_caller();
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let metadata_key = b"description".to_vec();
+	}: _(RawOrigin::Signed(caller), fragment_hash, metadata_key.clone(), vec![7u8; s as usize])
+	verify {
+		assert_last_event::<T>(Event::<T>::DefinitionMetadataChanged { fragment_hash, metadata_key }.into())
+	}
+
+	set_game_authority {
+		let caller: T::AccountId = whitelisted_caller();
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+	}: _(RawOrigin::Signed(caller), fragment_hash, Some(T::Lookup::unlookup(authority.clone())))
+	verify {
+		assert_last_event::<T>(Event::<T>::GameAuthorityChanged { fragment_hash, authority: Some(authority) }.into())
+	}
+
+	set_instance_metadata {
+		let s in 1 .. MAX_DATA_LENGTH;
+		let caller: T::AccountId = whitelisted_caller();
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::EDIT, None)?;
+		Fragments::<T>::mint(RawOrigin::Signed(caller.clone()).into(), fragment_hash, FragmentBuyOptions::Quantity(1), None)?;
+		// the owner is checked after the game authority
+		Fragments::<T>::set_game_authority(RawOrigin::Signed(caller.clone()).into(), fragment_hash, Some(T::Lookup::unlookup(authority)))?;
+		// the Fragment Instance already has metadata under all the Metadata Keys it can have but one
+		let metadata = (1..T::MaxInstanceMetadata::get()).map(|i| (Compact(u64::MAX - i as u64), [7u8; 32])).collect::<BTreeMap<_, _>>();
+		<InstanceMetadata<T>>::insert((fragment_hash, 1, 1), BoundedBTreeMap::try_from(metadata).unwrap());
+		let metadata_key = b"level".to_vec();
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, metadata_key.clone(), vec![7u8; s as usize])
+	verify {
+		assert_last_event::<T>(Event::<T>::InstanceMetadataChanged { fragment_hash, fragment_id: (1, 1), metadata_key }.into())
+	}
+
+	set_minter {
+		let caller: T::AccountId = whitelisted_caller();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::NONE, None)?;
+		let terms = Some(MinterTerms { quota: Some(Compact(1)), expiration: Some(1000u32.into()) });
+	}: _(RawOrigin::Signed(caller), fragment_hash, T::Lookup::unlookup(minter.clone()), terms.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::MinterChanged { fragment_hash, minter, terms }.into())
+	}
+
+	// burning a Fragment Instance whose auction has a bid to refund is the worst case
+	revoke {
+		let caller: T::AccountId = whitelisted_caller();
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let fragment_hash = create_definition::<T>(&caller, FragmentPerms::TRANSFER, None)?;
+		<Definitions<T>>::mutate(fragment_hash, |definition| definition.as_mut().map(|definition| definition.revocable = true));
+		Fragments::<T>::mint_to(RawOrigin::Signed(caller.clone()).into(), fragment_hash, T::Lookup::unlookup(holder.clone()), FragmentBuyOptions::Quantity(1), None)?;
+		let price = fund::<T>(&holder);
+		let kind = AuctionKind::English { reserve: Compact(price), extension: 10u32.into() };
+		Fragments::<T>::create_auction(RawOrigin::Signed(holder.clone()).into(), fragment_hash, AuctionItem::Instance { edition: 1, copy: 1 }, kind, None, 100u32.into())?;
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+		Fragments::<T>::bid(RawOrigin::Signed(bidder).into(), 0, price)?;
+	}: _(RawOrigin::Signed(caller), fragment_hash, 1, 1, true)
+	verify {
+		assert_last_event::<T>(Event::<T>::Revoked { account_id: holder, fragment_hash, fragment_id: (1, 1), burned: true }.into())
+	}
+
 	impl_benchmark_test_suite!(Fragments, crate::mock::new_test_ext(), crate::mock::Test);
 }
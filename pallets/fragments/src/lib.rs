@@ -36,46 +36,111 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod weights;
+pub mod weights;
 
-use codec::{Compact, Decode, Encode};
+pub mod migration;
+
+use base58::ToBase58;
+use codec::{Compact, Decode, Encode, MaxEncodedLen};
 pub use pallet::*;
-use sp_clamor::{Hash128, Hash256};
+use sp_clamor::{Hash128, Hash256, CID_PREFIX};
 use sp_io::{
 	hashing::{blake2_128, blake2_256},
 	transaction_index,
 };
-use sp_std::vec::Vec;
+use sp_std::{boxed::Box, vec, vec::Vec};
 pub use weights::WeightInfo;
 
 use protos::permissions::FragmentPerms;
 
-use frame_support::dispatch::DispatchResult;
-use sp_runtime::traits::StaticLookup;
+use pallet_detach::{
+	DetachHash, DetachRequest, DetachedHashes, DetachedInstances, SupportedChains, TargetAccount,
+};
+use pallet_protos::{MetaKeys, MetaKeysIndex, MetadataKey, Proto, ProtoOwner, Protos};
+
+use frame_support::{
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	transactional,
+	weights::{GetDispatchInfo, PostDispatchInfo, Weight},
+	BoundedBTreeMap, BoundedVec,
+};
+use sp_runtime::{
+	traits::{Dispatchable, One, Saturating, StaticLookup, Zero},
+	DispatchError,
+};
 
 use frame_support::traits::{
-	tokens::fungibles::Inspect, tokens::fungibles::Transfer, Currency, ExistenceRequirement,
-	ReservableCurrency,
+	tokens::fungibles::Inspect, tokens::fungibles::Transfer, BalanceStatus, ConstU32, Currency,
+	ExistenceRequirement, Get, OriginTrait, ReservableCurrency,
+};
+use sp_runtime::{
+	FixedPointNumber, FixedU128, PerThing, Permill, Perquintill, SaturatedConversion,
+};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use scale_info::prelude::{
+	format,
+	string::{String, ToString},
 };
-use sp_runtime::SaturatedConversion;
+use serde_json::{json, Value};
 
 type Unit = u64;
 
+/// **Maximum number of hashes** in the **Merkle proof** of an **allowlist** (see `Allowlist::MerkleRoot`),
+/// enough for **2^32 accounts**
+pub const MAX_PROOF_LEN: usize = 32;
+
+/// **Constants** of `FragmentPerms` that the `protos` crate **doesn't define**
+pub trait FragmentPermsExt {
+	/// **Maximum length** of an **encoded** `FragmentPerms`, which is **not** `MaxEncodedLen` in the `protos` crate
+	/// (see the `MaxEncodedLen` implementations of `FragmentDefinition` and `FragmentInstance`)
+	const MAX_ENCODED_LEN: usize;
+}
+
+impl FragmentPermsExt for FragmentPerms {
+	// `FragmentPerms` is encoded as its bits
+	const MAX_ENCODED_LEN: usize = sp_std::mem::size_of::<FragmentPerms>();
+}
+
+/// **Name** of a **Fragment Definition**, it can be at most 128 bytes long
+pub type FragmentName = BoundedVec<u8, ConstU32<128>>;
+
 /// **Struct** of a **Fragment Definition's Metadata**
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
 pub struct FragmentMetadata<TFungibleAsset> {
 	/// **Name** of the **Fragment Definition**
-	pub name: Vec<u8>,
+	pub name: FragmentName,
 	/// **Currency** that the **buyer** of a **Fragment Instance that is created from the Fragment Definition** must **pay in**.
 	/// If this field is `None`, the currency the buyer must pay in is NOVA.
 	pub currency: Option<TFungibleAsset>,
 }
 
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
 pub struct UniqueOptions {
 	pub mutable: bool,
 }
 
+/// **Struct** of the **royalty** that the **creator** of a **Fragment Definition** earns
+/// when a **Fragment Instance** created from it is **resold**
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub struct Royalty<TAccountId> {
+	/// **Account ID** that **receives** the **royalty**
+	pub beneficiary: TAccountId,
+	/// **Share** of the **sale price** that is **paid** to the **beneficiary**
+	pub percentage: Permill,
+}
+
+/// **Struct** of the **renewal terms** of the **Fragment Instances** of a **Fragment Definition** (see the `renew` extrinsic)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub struct Renewal<TBlockNum> {
+	/// **Price** of a **renewal**, in the **currency** of the **Fragment Definition**
+	pub price: Compact<u128>,
+	/// **Number of blocks** that a **renewal** adds to the **expiration** of a **Fragment Instance**
+	pub period: TBlockNum,
+}
+
 /// **Struct** of a **Fragment Definition**
 #[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
 pub struct FragmentDefinition<TFungibleAsset, TAccountId, TBlockNum> {
@@ -96,6 +161,15 @@ pub struct FragmentDefinition<TFungibleAsset, TAccountId, TBlockNum> {
 	pub unique: Option<UniqueOptions>,
 	/// If scarce, the max supply of the Fragment
 	pub max_supply: Option<Compact<Unit>>,
+	/// Whether **burning** the **last copy** of an **edition** gives the **edition back** to the **supply**,
+	/// i.e whether **burned editions** no longer **count** towards `max_supply`
+	pub burns_free_supply: bool,
+	/// **Royalty** that is **paid** on **every secondary sale** of a **Fragment Instance** (*optional*)
+	pub royalty: Option<Royalty<TAccountId>>,
+	/// **Renewal terms** of the **expiring Fragment Instances** (*optional*)
+	pub renewal: Option<Renewal<TBlockNum>>,
+	/// Whether the **owner** of the **Proto-Fragment** can **revoke** any **Fragment Instance** from whoever owns it (see the `revoke` extrinsic)
+	pub revocable: bool,
 	/// The creator of this class
 	pub creator: TAccountId,
 	/// The block number when the item was created
@@ -131,6 +205,39 @@ pub struct FragmentInstance<TBlockNum> {
 	pub amount: Option<Compact<Unit>>,
 }
 
+// `FragmentPerms` is not `MaxEncodedLen`, so neither `MaxEncodedLen` of `FragmentDefinition` and `FragmentInstance` can be derived
+impl<TFungibleAsset, TAccountId, TBlockNum> MaxEncodedLen
+	for FragmentDefinition<TFungibleAsset, TAccountId, TBlockNum>
+where
+	TFungibleAsset: MaxEncodedLen,
+	TAccountId: MaxEncodedLen,
+	TBlockNum: MaxEncodedLen,
+{
+	fn max_encoded_len() -> usize {
+		Hash256::max_encoded_len()
+			.saturating_add(FragmentMetadata::<TFungibleAsset>::max_encoded_len())
+			.saturating_add(FragmentPerms::MAX_ENCODED_LEN)
+			.saturating_add(Option::<UniqueOptions>::max_encoded_len())
+			.saturating_add(Option::<Compact<Unit>>::max_encoded_len())
+			.saturating_add(bool::max_encoded_len())
+			.saturating_add(Option::<Royalty<TAccountId>>::max_encoded_len())
+			.saturating_add(Option::<Renewal<TBlockNum>>::max_encoded_len())
+			.saturating_add(bool::max_encoded_len())
+			.saturating_add(TAccountId::max_encoded_len())
+			.saturating_add(TBlockNum::max_encoded_len())
+	}
+}
+
+impl<TBlockNum: MaxEncodedLen> MaxEncodedLen for FragmentInstance<TBlockNum> {
+	fn max_encoded_len() -> usize {
+		FragmentPerms::MAX_ENCODED_LEN
+			.saturating_add(TBlockNum::max_encoded_len())
+			.saturating_add(Option::<Hash256>::max_encoded_len())
+			.saturating_add(Option::<TBlockNum>::max_encoded_len())
+			.saturating_add(Option::<Compact<Unit>>::max_encoded_len())
+	}
+}
+
 /// Struct **representing** a sale of the **Fragment Definition** .
 ///
 /// Note: When a Fragment Definition is put on sale, users can create Fragment Instances from it for a fee.
@@ -140,17 +247,151 @@ pub struct FragmentInstance<TBlockNum> {
 /// #### Remarks
 ///
 ///`price` is using `u128` and not `T::Balance` because the latter requires a whole lot of traits to be satisfied.. rust headakes.
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
 pub struct PublishingData<TBlockNum> {
 	/// **Fee** that is **needed to be paid** to create a **single Fragment Instance** from the **Fragment Definition**
 	pub price: Compact<u128>,
 	/// **Amount of Fragment Instances** that **can be bought**
 	pub units_left: Option<Compact<Unit>>,
+	/// Block number that the sale starts at (*optional*). If this field is `None`, the sale starts when it is **opened**.
+	pub starts_at: Option<TBlockNum>,
 	/// Block number that the sale ends at (*optional*)
 	pub expiration: Option<TBlockNum>,
 	/// If the Fragment instance represents a **stack of stackable items** (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
 	/// the **number of items** to **top up** in the **stack of stackable items** // EMERICK
 	pub amount: Option<Compact<Unit>>,
+	/// **Accounts** that are **allowed** to **buy** (*optional*). If this field is `None`, **anyone** can **buy**.
+	pub allowlist: Option<Allowlist>,
+	/// **Maximum number of Fragment Instances** that can be **bought for a single account** during the sale (*optional*)
+	pub max_per_account: Option<Compact<Unit>>,
+	/// Block number that the sale was opened at, the **purchases** of an **account** are **counted** from it (see `Purchases`)
+	pub published_at: TBlockNum,
+	/// **Pricing curve** of the sale (*optional*). If this field is `None`, **every Fragment Instance** costs the **price** of the **current phase**.
+	pub curve: Option<PriceCurve<TBlockNum>>,
+	/// **Number of Fragment Instances** that were **sold** so far during the sale
+	pub sold: Compact<Unit>,
+}
+
+/// **Enum** of the **pricing curves** of a **sale** (see `PublishingData` and `get_sale_price`).
+///
+/// The **price** of the **current phase** of the sale is the **starting price** of the curve
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub enum PriceCurve<TBlockNum> {
+	/// **Linear bonding curve**: the **price** **increases** by `increment` with **every Fragment Instance sold** during the sale
+	Linear { increment: Compact<u128> },
+	/// **Exponential bonding curve**: the **price** **increases** by `rate` (**compounded**) with **every Fragment Instance sold** during the sale
+	Exponential { rate: Permill },
+	/// **Dutch auction**: the **price** **falls linearly** to `floor` over the **`duration` blocks** after the **current phase** starts
+	Decay { floor: Compact<u128>, duration: TBlockNum },
+}
+
+/// **Enum** of the **allowlist** of a **sale** (see `PublishingData`)
+#[derive(Encode, Decode, Clone, Copy, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub enum Allowlist {
+	/// **Merkle root** of the **allowed accounts**. The **buyer** **proves** that the **account** is **allowed** with a **Merkle proof**.
+	///
+	/// A **leaf** is the **`blake2_256` hash** of the **SCALE encoded Account ID**, and a **node** is the **`blake2_256` hash**
+	/// of its **two children** **sorted** and **concatenated**
+	MerkleRoot(Hash256),
+	/// **Only** the **accounts** that are in `SaleAllowlists` are **allowed** (see the `set_allowlist` extrinsic)
+	Accounts,
+}
+
+/// **Struct** of a **phase** of a **sale** that comes **after** its **first phase** (e.g a **public phase** after an **allowlist phase**, see the `publish` extrinsic).
+///
+/// The **first phase** of a **sale** uses the `price`, `allowlist` and `max_per_account` of its `PublishingData`,
+/// and **every phase** lasts until the **next one** starts
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub struct SalePhase<TBlockNum> {
+	/// Block number that the phase starts at
+	pub starts_at: TBlockNum,
+	/// **Fee** that is **needed to be paid** to create a **single Fragment Instance** during the phase
+	pub price: Compact<u128>,
+	/// **Accounts** that are **allowed** to **buy** during the phase (*optional*). If this field is `None`, **anyone** can **buy**.
+	pub allowlist: Option<Allowlist>,
+	/// **Maximum number of Fragment Instances** that can be **bought for a single account** (*optional*),
+	/// the **purchases** of the **previous phases** of the **sale** are **counted** too
+	pub max_per_account: Option<Compact<Unit>>,
+}
+
+/// **Struct** of the **terms** under which an **account** (e.g a **game server**) is **allowed** to **mint Fragment Instances** of a **Fragment Definition**
+/// on behalf of the **owner** of its **Proto-Fragment** (see the `set_minter` extrinsic)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
+pub struct MinterTerms<TBlockNum> {
+	/// **Number of Fragment Instances** that the **minter** is **still allowed** to **mint** (*optional*). If this field is `None`, there is **no limit**.
+	pub quota: Option<Compact<Unit>>,
+	/// Block number from which the minter is no longer allowed to mint (*optional*)
+	pub expiration: Option<TBlockNum>,
+}
+
+/// **Struct** of a **listing** of a **Fragment Instance** on the **secondary market** (see the `list_instance` extrinsic)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub struct Listing<TFungibleAsset, TAccountId, TBlockNum> {
+	/// **Account ID** that **listed** the **Fragment Instance** (i.e its **owner**)
+	pub seller: TAccountId,
+	/// **Price** of the **Fragment Instance**
+	pub price: Compact<u128>,
+	/// **Currency** of the **price**. If this field is `None`, the currency is NOVA.
+	pub currency: Option<TFungibleAsset>,
+	/// Block number that the listing ends at (*optional*)
+	pub expiration: Option<TBlockNum>,
+}
+
+/// **Enum** of **what** is **sold** in an **auction** (see the `create_auction` extrinsic)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub enum AuctionItem {
+	/// A **new edition** of the **Fragment Definition**, **minted** to the **winner** when the auction is **settled**.
+	/// The **proceeds** go to the **vault** of the **Fragment Definition**, like the ones of the `buy` extrinsic.
+	///
+	/// If the **Fragment Instance** represents a **stack of stackable items**, `amount` is the **number of items** in the **stack**
+	Edition { amount: Option<Compact<Unit>> },
+	/// An **existing Fragment Instance** of the **seller**, **transferred** to the **winner** when the auction is **settled**.
+	/// The **royalty** of the **Fragment Definition** is **paid** out of the **proceeds**
+	Instance { edition: Unit, copy: Unit },
+}
+
+/// **Enum** of the **kinds of auction**
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub enum AuctionKind<TBlockNum> {
+	/// **Ascending auction**: the **highest bid** wins when the auction **ends**. Bids **lower than** `reserve` are **rejected**,
+	/// and a **bid** placed **less than** `extension` **blocks before** the **end** **pushes** the **end** back to `extension` blocks after the bid
+	English { reserve: Compact<u128>, extension: TBlockNum },
+	/// **Descending auction**: the **price** **falls linearly** from `start_price` to `end_price` over the **duration** of the auction,
+	/// the **first bid** at the **current price** wins **immediately**
+	Dutch { start_price: Compact<u128>, end_price: Compact<u128> },
+}
+
+/// **Struct** of an **auction**
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, MaxEncodedLen)]
+pub struct Auction<TFungibleAsset, TAccountId, TBlockNum> {
+	/// **Fragment Definition ID** of **what** is **sold**
+	pub fragment_hash: Hash128,
+	/// **What** is **sold**
+	pub item: AuctionItem,
+	/// **Account ID** that **created** the **auction**
+	pub seller: TAccountId,
+	/// **Kind** of **auction**
+	pub kind: AuctionKind<TBlockNum>,
+	/// **Currency** of the **bids**. If this field is `None`, the currency is NOVA.
+	pub currency: Option<TFungibleAsset>,
+	/// Block number that the auction started at
+	pub start: TBlockNum,
+	/// Block number that the auction ends at, it is **settled** in the `on_initialize` of this block (see `process_auction_ends`)
+	pub end: TBlockNum,
+	/// **Highest bid** so far, as **(bidder, amount)**. The **amount** is **held** until the **bidder** is **outbid** or the auction is **settled**
+	pub highest_bid: Option<(TAccountId, Compact<u128>)>,
+}
+
+/// **Balances** that can be **withdrawn** from the **vault** of a **Fragment Definition** (see the `withdraw_proceeds` extrinsic)
+///
+/// Note: the **minimum balance** that is **reserved** to keep the vault alive is **not included**
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq)]
+pub struct VaultBalances<TFungibleAsset> {
+	/// **Withdrawable amount** of **NOVA**
+	pub native: u128,
+	/// **Currency** of the **Fragment Definition** and the **withdrawable amount** of it,
+	/// if the **Fragment Definition** is **not sold** in **NOVA**
+	pub asset: Option<(TFungibleAsset, u128)>,
 }
 
 /// **Enum** indicating whether to
@@ -167,32 +408,106 @@ pub enum FragmentBuyOptions {
 	UniqueData(Vec<u8>),
 }
 
+/// **Data Type** used to **Query and Filter for Fragment Definitions** (see `get_definitions`)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GetDefinitionsParams<TAccountId, TString> {
+	/// Whether to **sort** the **Fragment Definitions** from the **newest** to the **oldest**
+	pub desc: bool,
+	/// **Number of Fragment Definitions** to **skip**
+	pub from: u64,
+	/// **Maximum number of Fragment Definitions** to **return**
+	pub limit: u64,
+	/// **Hex encoded Proto-Fragment** that the **Fragment Definitions** were **created from** (*optional*)
+	pub proto_hash: Option<TString>,
+	/// **Creator** of the **Fragment Definitions** (*optional*)
+	pub creator: Option<TAccountId>,
+	/// Whether to **only return** the **Fragment Definitions** that are **currently on sale**
+	pub on_sale: bool,
+	/// **Metadata keys** of the **Fragment Definitions** to **return** (see the `set_definition_metadata` extrinsic)
+	pub metadata_keys: Vec<TString>,
+}
+
+/// **Data Type** used to **Query and Filter for Fragment Instances** (see `get_instances`)
+///
+/// At least one of `definition_hash` and `owner` must be provided
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GetInstancesParams<TAccountId, TString> {
+	/// **Number of Fragment Instances** to **skip**
+	pub from: u64,
+	/// **Maximum number of Fragment Instances** to **return**
+	pub limit: u64,
+	/// **Hex encoded Fragment Definition** of the **Fragment Instances** (*optional*)
+	pub definition_hash: Option<TString>,
+	/// **Owner** of the **Fragment Instances**, i.e the **inventory** of an **account** (*optional*)
+	pub owner: Option<TAccountId>,
+	/// **Metadata keys** of the **Fragment Instances** to **return** (see the `set_instance_metadata` extrinsic)
+	pub metadata_keys: Vec<TString>,
+}
+
+/// **Data Type** used to **Query for the Owners of a Fragment Definition** (see `get_owners`)
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GetOwnersParams<TString> {
+	/// **Number of owners** to **skip**
+	pub from: u64,
+	/// **Maximum number of owners** to **return**
+	pub limit: u64,
+	/// **Hex encoded Fragment Definition**
+	pub definition_hash: TString,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{pallet_prelude::*, Twox64Concat};
 	use frame_system::pallet_prelude::*;
-	use pallet_detach::DetachedHashes;
-	use pallet_protos::{Proto, ProtoOwner, Protos};
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_protos::Config + pallet_assets::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// The overarching call type, the owner of a Fragment Instance can dispatch it as the account of the Fragment Instance (see the `as_fragment` extrinsic)
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+		/// **Maximum number** of **recipients** or **Fragment Instances** in a **single** `mint_to_many` or `give_many` call
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// **Maximum number** of **expired Fragment Instances** that are **removed** in a **single block**,
+		/// the **remaining ones** are **removed** in the **next blocks**
+		#[pallet::constant]
+		type MaxExpirationsPerBlock: Get<u32>;
+		/// **Maximum number** of **ended auctions** that are **settled** in a **single block**,
+		/// the **remaining ones** are **settled** in the **next blocks**
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		/// **Maximum number** of **phases** of a **sale** that come **after** its **first phase** (see `SalePhase`)
+		#[pallet::constant]
+		type MaxSalePhases: Get<u32>;
+		/// **Maximum number** of **Metadata Keys** that a **Fragment Instance** has **metadata** under (see `InstanceMetadata`)
+		#[pallet::constant]
+		type MaxInstanceMetadata: Get<u32>;
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// **StorageMap** that maps a **Proto-Fragment**
-	/// to a
-	/// **list of Fragment Definitions that were created using the aforementioned Proto-Fragment**
+	/// **StorageDoubleMap** that maps a **Proto-Fragment and
+	/// a Fragment Definition that was created using the aforementioned Proto-Fragment**
+	/// to `()`
 	#[pallet::storage]
-	pub type Proto2Fragments<T: Config> = StorageMap<_, Identity, Hash256, Vec<Hash128>>;
+	pub type Proto2Fragments<T: Config> =
+		StorageDoubleMap<_, Identity, Hash256, Identity, Hash128, ()>;
 
 	// fragment-hash to fragment-data
 	/// **StorageMap** that maps a **Fragment Definition ID (which is determinstically computed using its Proto-Fragment hash and its metadata struct `FragmentMetadata`)**
@@ -206,6 +521,36 @@ pub mod pallet {
 		FragmentDefinition<T::AssetId, T::AccountId, T::BlockNumber>,
 	>;
 
+	/// **StorageDoubleMap** that maps a **Fragment Definition ID and the index of a Metadata Key** (see `MetaKeys` of the pallet `protos`)
+	/// to the **hash** of the **metadata** (see the `set_definition_metadata` extrinsic)
+	///
+	/// Footnotes:
+	///
+	///  Unlike `FragmentMetadata`, this metadata is **not part** of the **Fragment Definition ID**, so it can **change**
+	#[pallet::storage]
+	pub type DefinitionMetadata<T: Config> =
+		StorageDoubleMap<_, Identity, Hash128, Twox64Concat, Compact<u64>, Hash256>;
+
+	/// **StorageMap** that maps a **Fragment Definition ID** to the **Account ID** of its **game authority**
+	///
+	/// Footnotes:
+	///
+	///  The **game authority** can **set** the **metadata** of **any Fragment Instance** of the **Fragment Definition** (see the `set_instance_metadata` extrinsic)
+	#[pallet::storage]
+	pub type GameAuthorities<T: Config> = StorageMap<_, Identity, Hash128, T::AccountId>;
+
+	/// **StorageDoubleMap** that maps a **Fragment Definition ID and an Account ID** to the **terms** under which the **account**
+	/// is **allowed** to **mint Fragment Instances** of the **Fragment Definition** (see the `set_minter` extrinsic)
+	#[pallet::storage]
+	pub type Minters<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Hash128,
+		Twox64Concat,
+		T::AccountId,
+		MinterTerms<T::BlockNumber>,
+	>;
+
 	/// **StorageMap** that maps a **Fragment Definition ID (which is determinstically computed using its Proto-Fragment hash and its metadata struct `FragmentMetadata`)**
 	/// to a
 	/// ***PublishingData* struct (of the aforementioned Fragment Definition)**
@@ -213,6 +558,34 @@ pub mod pallet {
 	pub type Publishing<T: Config> =
 		StorageMap<_, Identity, Hash128, PublishingData<T::BlockNumber>>;
 
+	/// **StorageMap** that maps a **Fragment Definition ID**
+	/// to the **phases** of its **sale** that come **after** the **first phase**, **sorted** by **start**
+	#[pallet::storage]
+	pub type SalePhases<T: Config> =
+		StorageMap<_, Identity, Hash128, BoundedVec<SalePhase<T::BlockNumber>, T::MaxSalePhases>>;
+
+	/// **StorageDoubleMap** that maps a **Fragment Definition ID and an Account ID**
+	/// to `()` if the **account** is **allowed** to **buy** when the **sale** has an `Allowlist::Accounts` **allowlist**
+	#[pallet::storage]
+	pub type SaleAllowlists<T: Config> =
+		StorageDoubleMap<_, Identity, Hash128, Twox64Concat, T::AccountId, ()>;
+
+	/// **StorageDoubleMap** that maps a **Fragment Definition ID and an Account ID**
+	/// to the **block number that the sale was opened at** and the **number of Fragment Instances bought for the account** during this sale (over **all** of its **phases**)
+	///
+	/// Footnotes:
+	///
+	///  The count is only **relevant** if the **block number** is the `published_at` of the **current sale**, so it **resets** with every **new sale**
+	#[pallet::storage]
+	pub type Purchases<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Hash128,
+		Twox64Concat,
+		T::AccountId,
+		(T::BlockNumber, Compact<Unit>),
+	>;
+
 	/// **StorageMap** that maps a **Fragment Definition ID**
 	/// to the
 	/// **total number of unique Edition IDs** found in the
@@ -220,6 +593,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type EditionsCount<T: Config> = StorageMap<_, Identity, Hash128, Compact<Unit>>;
 
+	/// **StorageMap** that maps a **Fragment Definition ID**
+	/// to the
+	/// **total number of Edition IDs** whose **every Fragment Instance was burned**
+	#[pallet::storage]
+	pub type BurnedEditionsCount<T: Config> = StorageMap<_, Identity, Hash128, Compact<Unit>>;
+
 	/// **StorageMap** that maps a **tuple that contains a Fragment Definition ID and an Edition ID**
 	/// to the
 	/// **total number of Fragment Instances that have the Fragment Definition ID and the Edition ID**
@@ -252,6 +631,26 @@ pub mod pallet {
 		FragmentInstance<T::BlockNumber>,
 	>;
 
+	/// **StorageNMap** that maps a **Fragment Definition ID, an Edition ID and a Copy ID** to the **metadata** of the **Fragment Instance**,
+	/// which maps the **index of a Metadata Key** (see `MetaKeys` of the pallet `protos`) to the **hash** of the **metadata** (see the `set_instance_metadata` extrinsic)
+	///
+	/// Footnotes:
+	///
+	///  The **metadata** is **removed** along with the **Fragment Instance**, a **Fragment Instance** has **metadata** under **at most** `MaxInstanceMetadata` **Metadata Keys**
+	#[pallet::storage]
+	pub type InstanceMetadata<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		BoundedBTreeMap<Compact<u64>, Hash256, T::MaxInstanceMetadata>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type UniqueData2Edition<T: Config> = StorageDoubleMap<
 		_,
@@ -262,122 +661,432 @@ pub mod pallet {
 		Unit,    // Edition ID
 	>;
 
-	/// StorageDoubleMap that maps a **Fragment Definition and the
-	/// Owner of a Fragment Instance that was created from the aforementioned Fragment Definition**
-	/// to a
-	/// **tuple that contains the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// StorageNMap that maps a **Fragment Definition, the
+	/// Owner of a Fragment Instance that was created from the aforementioned Fragment Definition,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to `()`
 	///
-	/// This storage item stores the exact same thing as `Inventory`, except that the primary key and the secondary key are swapped
+	/// This storage item stores the exact same thing as `Inventory`, except that the first key and the second key are swapped
 	///
 	/// Footnotes:
 	///
-	/// Notice this pulls from memory (and deserializes (scale)) the whole `Vec<_,_>`, this is on purpose as it should not be too big.
+	/// One entry per Fragment Instance, so that owning many Fragment Instances never grows a single value
 	#[pallet::storage]
-	pub type Owners<T: Config> = StorageDoubleMap<
+	pub type Owners<T: Config> = StorageNMap<
 		_,
-		Identity,
-		Hash128,
-		Twox64Concat,
-		T::AccountId,
-		Vec<(Compact<Unit>, Compact<Unit>)>,
+		(
+			storage::Key<Identity, Hash128>,
+			storage::Key<Twox64Concat, T::AccountId>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		(),
 	>;
 
-	/// StorageDoubleMap that maps the **Owner of a Fragment Instance and the Fragment Instance's Fragment Definition**
-	/// to a
-	/// **tuple that contains the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// StorageNMap that maps the **Owner of a Fragment Instance, the Fragment Instance's Fragment Definition,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to `()`
 	///
-	/// This storage item stores the exact same thing as `Owners`, except that the primary key and the secondary key are swapped
+	/// This storage item stores the exact same thing as `Owners`, except that the first key and the second key are swapped
+	#[pallet::storage]
+	pub type Inventory<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Twox64Concat, T::AccountId>,
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		(),
+	>;
+
+	/// StorageNMap that maps the **Block Number that a Fragment Instance expires at,
+	/// the Fragment Instance's Fragment Definition ID, the Fragment Instance's Edition ID and
+	/// the Fragment Instance's Copy ID**
+	/// to `()`
 	///
 	/// Footnotes:
 	///
-	/// Notice this pulls from memory (and deserializes (scale)) the whole `Vec<_,_>`, this is on purpose as it should not be too big.
+	///  Fragment Instances can expire, we process expirations every `on_initialize` (see `process_expirations`)
 	#[pallet::storage]
-	pub type Inventory<T: Config> = StorageDoubleMap<
+	pub type Expirations<T: Config> = StorageNMap<
 		_,
-		Twox64Concat,
-		T::AccountId,
-		Identity,
-		Hash128,
-		Vec<(Compact<Unit>, Compact<Unit>)>,
+		(
+			storage::Key<Twox64Concat, T::BlockNumber>,
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		(),
 	>;
 
-	/// StorageMap that maps the **Block Number that a Fragment Instance expires at**
-	/// to a
-	/// **tuple that contains the Fragment Instance's Fragment Definition ID, the Fragment Instance's Edition ID and
-	/// the Fragment Instance's Copy ID**
+	/// **StorageValue** of the **earliest Block Number** whose **entries** in `Expirations` may **not** have been **processed** yet
+	#[pallet::storage]
+	pub type NextExpiration<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// StorageNMap that maps the **Fragment Definition ID of a Fragment Instance,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to the **Account ID** that **owns** it
 	///
 	/// Footnotes:
 	///
-	///  Fragment Instances can expire, we process expirations every `on_finalize`
+	///  This is the reverse of `Owners`, so the owner of a Fragment Instance can be found without iterating over every owner of its Fragment Definition
 	#[pallet::storage]
-	pub type Expirations<T: Config> =
-		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(Hash128, Compact<Unit>, Compact<Unit>)>>;
+	pub type InstanceOwner<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		T::AccountId,
+	>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// New definition created by account, definition hash
-		DefinitionCreated { fragment_hash: Hash128 },
-		/// Fragment sale has been opened
-		Publishing { fragment_hash: Hash128 },
-		/// Fragment sale has been closed
-		Unpublishing { fragment_hash: Hash128 },
-		/// Inventory item has been added to account
-		InventoryAdded {
-			account_id: T::AccountId,
-			fragment_hash: Hash128,
-			fragment_id: (Unit, Unit),
-		},
-		/// Inventory item has removed added from account
-		InventoryRemoved {
-			account_id: T::AccountId,
-			fragment_hash: Hash128,
-			fragment_id: (Unit, Unit),
-		},
-		/// Inventory has been updated
-		InventoryUpdated {
-			account_id: T::AccountId,
-			fragment_hash: Hash128,
-			fragment_id: (Unit, Unit),
-		},
-		/// Fragment Expiration event
-		Expired { account_id: T::AccountId, fragment_hash: Hash128, fragment_id: (Unit, Unit) },
-	}
+	/// StorageNMap that maps the **Fragment Definition ID of a Fragment Instance,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to its ***Listing* struct** on the **secondary market**
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		Listing<T::AssetId, T::AccountId, T::BlockNumber>,
+	>;
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Proto not found
-		ProtoNotFound,
-		/// Proto owner not found
-		ProtoOwnerNotFound,
-		/// No Permission
-		NoPermission,
-		/// Already detached
-		Detached,
-		/// Already exist
-		AlreadyExist,
-		/// Not found
-		NotFound,
-		/// Sale has expired
-		Expired,
-		/// Insufficient funds
-		InsufficientBalance,
-		/// Fragment sale sold out
-		SoldOut,
-		/// Sale already open
-		SaleAlreadyOpen,
-		/// Max supply reached
-		MaxSupplyReached,
-		/// Published quantity reached
-		PublishedQuantityReached, // Need to think of a better name!
-		/// Params not valid
-		ParamsNotValid,
-		/// This should not really happen
-		SystematicFailure,
-		/// Fragment Instance already uploaded with the same unique data
-		UniqueDataExists,
-	}
+	/// StorageNMap that maps the **Block Number that a listing ends at,
+	/// the listed Fragment Instance's Fragment Definition ID, Edition ID and Copy ID**
+	/// to `()`
+	///
+	/// Footnotes:
+	///
+	///  Listings can expire, we process them every `on_initialize` like `Expirations` (see `process_listing_expirations`)
+	#[pallet::storage]
+	pub type ListingExpirations<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Twox64Concat, T::BlockNumber>,
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		(),
+	>;
+
+	/// **StorageValue** of the **earliest Block Number** whose **entries** in `ListingExpirations` may **not** have been **processed** yet
+	#[pallet::storage]
+	pub type NextListingExpiration<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// **StorageValue** of the **ID** of the **next auction**
+	#[pallet::storage]
+	pub type NextAuctionId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// **StorageMap** that maps an **auction ID** to its ***Auction* struct**
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		Auction<T::AssetId, T::AccountId, T::BlockNumber>,
+	>;
+
+	/// **StorageDoubleMap** that maps the **Block Number that an auction ends at and the auction ID** to `()`
+	///
+	/// Footnotes:
+	///
+	///  Auctions are settled every `on_initialize` like `Expirations` (see `process_auction_ends`)
+	#[pallet::storage]
+	pub type AuctionEnds<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, u64, ()>;
+
+	/// **StorageValue** of the **earliest Block Number** whose **entries** in `AuctionEnds` may **not** have been **processed** yet
+	#[pallet::storage]
+	pub type NextAuctionEnd<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// StorageNMap that maps the **Fragment Definition ID of a Fragment Instance,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to the **ID** of the **auction** that **sells** it
+	#[pallet::storage]
+	pub type InstanceAuctions<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		u64,
+	>;
+
+	/// StorageNMap that maps the **Fragment Definition ID of a Fragment Instance,
+	/// the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to the **Account ID** that is **approved** to **transfer** it on behalf of its owner
+	///
+	/// Footnotes:
+	///
+	///  The approval is cleared whenever the Fragment Instance changes hands or is removed
+	#[pallet::storage]
+	pub type Approvals<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		T::AccountId,
+	>;
+
+	/// StorageNMap that maps a **Fragment Definition ID, an owner's Account ID and an operator's Account ID** to `()`
+	///
+	/// Footnotes:
+	///
+	///  An operator can transfer **every** Fragment Instance of the Fragment Definition that the owner owns
+	#[pallet::storage]
+	pub type Operators<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Owners
+			storage::Key<Twox64Concat, T::AccountId>,
+			// Operators
+			storage::Key<Twox64Concat, T::AccountId>,
+		),
+		(),
+	>;
+
+	/// StorageNMap that maps the **Fragment Definition ID of a Fragment Instance, the Fragment Instance's Edition ID and the Fragment Instance's Copy ID**
+	/// to the **deposit** (in NOVA) that is **reserved** on the **account** of the **Fragment Instance**
+	///
+	/// Footnotes:
+	///
+	///  An entry is **added** when the account is **created** (see the `create_account` extrinsic) and **removed** when it is **closed** (see the `close_account` extrinsic),
+	///  so that **only** the **deposit** is **unreserved** and **not** the **other reserves** of the account (e.g. a bid that was placed with the `as_fragment` extrinsic).
+	///  The **accounts** that were **created before** it existed are **recorded** by the **version 9 migration**
+	#[pallet::storage]
+	pub type AccountDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			storage::Key<Identity, Hash128>,
+			// Editions
+			storage::Key<Identity, Unit>,
+			// Copies
+			storage::Key<Identity, Unit>,
+		),
+		Compact<u128>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New definition created by account, definition hash
+		DefinitionCreated { fragment_hash: Hash128 },
+		/// A Fragment Definition metadata has changed
+		DefinitionMetadataChanged { fragment_hash: Hash128, metadata_key: Vec<u8> },
+		/// Game authority of a Fragment Definition has been set, `authority` is `None` if it has been removed
+		GameAuthorityChanged { fragment_hash: Hash128, authority: Option<T::AccountId> },
+		/// Minter of a Fragment Definition has been added or updated, `terms` is `None` if it has been removed
+		MinterChanged {
+			fragment_hash: Hash128,
+			minter: T::AccountId,
+			terms: Option<MinterTerms<T::BlockNumber>>,
+		},
+		/// Minter of a Fragment Definition has minted `quantity` Fragment Instances on behalf of the owner of its proto
+		MinterUsed { fragment_hash: Hash128, minter: T::AccountId, quantity: Unit },
+		/// Fragment sale has been opened
+		Publishing { fragment_hash: Hash128 },
+		/// Fragment sale has been closed
+		Unpublishing { fragment_hash: Hash128 },
+		/// Allowlist of a Fragment sale has been updated
+		AllowlistUpdated { fragment_hash: Hash128 },
+		/// Inventory item has been added to account
+		InventoryAdded {
+			account_id: T::AccountId,
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+		},
+		/// Inventory item has removed added from account
+		InventoryRemoved {
+			account_id: T::AccountId,
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+		},
+		/// Inventory has been updated
+		InventoryUpdated {
+			account_id: T::AccountId,
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+		},
+		/// Fragment Expiration event
+		Expired { account_id: T::AccountId, fragment_hash: Hash128, fragment_id: (Unit, Unit) },
+		/// Fragment Instance has been renewed, `expiring_at` is the block number it now expires at
+		Renewed { fragment_hash: Hash128, fragment_id: (Unit, Unit), expiring_at: T::BlockNumber },
+		/// Fragment Instance has been burned by its owner
+		Burned { account_id: T::AccountId, fragment_hash: Hash128, fragment_id: (Unit, Unit) },
+		/// Fragment Instance has been revoked from `account_id` by the owner of its proto, `burned` is `false` if it has been given back to them
+		Revoked {
+			account_id: T::AccountId,
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			burned: bool,
+		},
+		/// Unique data of a Fragment Instance has been updated, `cid` is the CID of the new data
+		UniqueDataUpdated { fragment_hash: Hash128, fragment_id: (Unit, Unit), cid: Vec<u8> },
+		/// A Fragment Instance metadata has changed
+		InstanceMetadataChanged {
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			metadata_key: Vec<u8>,
+		},
+		/// Royalty of a Fragment Definition has been paid on a secondary sale, `currency` is `None` for NOVA
+		RoyaltyPaid {
+			fragment_hash: Hash128,
+			beneficiary: T::AccountId,
+			currency: Option<T::AssetId>,
+			amount: u128,
+		},
+		/// Fragment Instance has been listed on the secondary market, `currency` is `None` for NOVA
+		Listed {
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			seller: T::AccountId,
+			price: u128,
+			currency: Option<T::AssetId>,
+			expiration: Option<T::BlockNumber>,
+		},
+		/// Listing of a Fragment Instance has been cancelled, has expired or the Fragment Instance has changed hands
+		Delisted { fragment_hash: Hash128, fragment_id: (Unit, Unit) },
+		/// Listed Fragment Instance has been bought, `currency` is `None` for NOVA
+		InstanceSold {
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: u128,
+			currency: Option<T::AssetId>,
+		},
+		/// Account of a Fragment Instance has been closed, `amount` NOVA has been transferred to its owner
+		AccountClosed {
+			account_id: T::AccountId,
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			amount: u128,
+		},
+		/// Account approved to transfer a Fragment Instance has been set, `spender` is `None` if the approval has been revoked
+		Approval {
+			fragment_hash: Hash128,
+			fragment_id: (Unit, Unit),
+			owner: T::AccountId,
+			spender: Option<T::AccountId>,
+		},
+		/// Operator of all the Fragment Instances of a Fragment Definition owned by `owner` has been approved or revoked
+		ApprovalForAll {
+			fragment_hash: Hash128,
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
+		/// Auction has been created
+		AuctionCreated { auction_id: u64, fragment_hash: Hash128, seller: T::AccountId },
+		/// Bid has been placed in an auction
+		BidPlaced { auction_id: u64, bidder: T::AccountId, amount: u128 },
+		/// Auction has been extended by a late bid, `end` is the new block number it ends at
+		AuctionExtended { auction_id: u64, end: T::BlockNumber },
+		/// Auction has been settled and what was sold has been given to the winner
+		AuctionSettled { auction_id: u64, winner: T::AccountId, price: u128 },
+		/// Auction has ended without a sale (cancelled, no bids or the settlement failed), the highest bid (if any) has been refunded
+		AuctionClosed { auction_id: u64 },
+		/// Proceeds have been withdrawn from the vault of a Fragment Definition, `currency` is `None` for NOVA
+		ProceedsWithdrawn {
+			fragment_hash: Hash128,
+			account_id: T::AccountId,
+			currency: Option<T::AssetId>,
+			amount: u128,
+		},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Proto not found
+		ProtoNotFound,
+		/// Proto owner not found
+		ProtoOwnerNotFound,
+		/// No Permission
+		NoPermission,
+		/// Already detached
+		Detached,
+		/// Already exist
+		AlreadyExist,
+		/// Not found
+		NotFound,
+		/// Sale, auction or minter has expired
+		Expired,
+		/// Insufficient funds
+		InsufficientBalance,
+		/// Fragment sale sold out
+		SoldOut,
+		/// Sale already open
+		SaleAlreadyOpen,
+		/// Max supply reached
+		MaxSupplyReached,
+		/// Published quantity reached
+		PublishedQuantityReached, // Need to think of a better name!
+		/// Params not valid
+		ParamsNotValid,
+		/// This should not really happen
+		SystematicFailure,
+		/// Fragment Instance already uploaded with the same unique data
+		UniqueDataExists,
+		/// Fragment Instance is not a stack of stackable items
+		NotStackable,
+		/// Not enough items in the stack of stackable items
+		InsufficientAmount,
+		/// Fragment Definition's unique data is not mutable
+		ImmutableUniqueData,
+		/// Unique data is shared with other copies of the same edition
+		UniqueDataShared,
+		/// Bid is lower than the reserve, the highest bid or the current price of the auction
+		BidTooLow,
+		/// Auction already has bids
+		AuctionHasBids,
+		/// Too many recipients or Fragment Instances in a single call
+		TooManyItems,
+		/// Target account is too long
+		InvalidTargetAccount,
+		/// Account is not in the allowlist of the sale
+		NotAllowed,
+		/// Account already bought the maximum number of Fragment Instances of the sale
+		MaxPerAccountReached,
+		/// Sale has not started yet
+		NotStarted,
+		/// Minter is not allowed to mint that many Fragment Instances anymore
+		MinterQuotaExceeded,
+		/// Fragment Definition was not created revocable
+		NotRevocable,
+		/// Fragment Instance already has metadata under the maximum number of Metadata Keys
+		TooManyMetadataKeys,
+		/// Fragment Instance is in an auction
+		InAuction,
+		/// Metadata Key is too long
+		MetadataKeyTooLong,
+	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
@@ -405,6 +1114,11 @@ pub mod pallet {
 		/// * `unique` (*optional*) - **Whether** the **Fragment Definiton** is **unique**
 		/// * `max_supply` (*optional*) - **Maximum amount of Fragment instances (where each Fragment instance has a different Edition ID)**
 		/// that **can be created** using the **Fragment Definition**
+		/// * `burns_free_supply` - **Whether** an **edition** whose **every Fragment Instance was burned** stops **counting** towards `max_supply`
+		/// * `royalty` (*optional*) - **Royalty** that is **paid** to its **beneficiary** on **every secondary sale** of a **Fragment Instance**
+		/// * `renewal` (*optional*) - **Price** and **period** of a **renewal** of an **expiring Fragment Instance** (see the `renew` extrinsic)
+		/// * `revocable` - **Whether** the **Fragment Instances** can be **revoked** by the **Proto-Fragment's owner** (see the `revoke` extrinsic).
+		/// It **cannot** be **changed** afterwards.
 		#[pallet::weight(<T as Config>::WeightInfo::create())]
 		pub fn create(
 			origin: OriginFor<T>,
@@ -413,18 +1127,14 @@ pub mod pallet {
 			permissions: FragmentPerms,
 			unique: Option<UniqueOptions>,
 			max_supply: Option<Unit>,
+			burns_free_supply: bool,
+			royalty: Option<Royalty<T::AccountId>>,
+			renewal: Option<Renewal<T::BlockNumber>>,
+			revocable: bool,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let proto: Proto<T::AccountId, T::BlockNumber> =
-				<Protos<T>>::get(proto_hash).ok_or(Error::<T>::ProtoNotFound)?; // Get `Proto` struct from `proto_hash`
 
-			let proto_owner: T::AccountId = match proto.owner {
-				// Get `proto_owner` from `proto.owner`
-				ProtoOwner::User(owner) => Ok(owner),
-				_ => Err(Error::<T>::ProtoOwnerNotFound),
-			}?;
-
-			ensure!(who == proto_owner, Error::<T>::NoPermission); // Only proto owner can create a fragment definition from proto
+			ensure!(who == Self::get_proto_owner(&proto_hash)?, Error::<T>::NoPermission); // Only proto owner can create a fragment definition from proto
 
 			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // proto must not have been detached
 
@@ -435,6 +1145,10 @@ pub mod pallet {
 
 			ensure!(!<Definitions<T>>::contains_key(&hash), Error::<T>::AlreadyExist); // If fragment already exists, throw error
 
+			if let Some(renewal) = &renewal {
+				ensure!(!renewal.period.is_zero(), Error::<T>::ParamsNotValid); // a renewal must extend the expiration
+			}
+
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
 
 			// ! Writing
@@ -451,198 +1165,475 @@ pub mod pallet {
 				permissions,
 				unique,
 				max_supply: max_supply.map(|x| Compact(x)),
+				burns_free_supply,
+				royalty,
+				renewal,
+				revocable,
 				creator: who.clone(),
 				created_at: current_block_number,
 			};
 			<Definitions<T>>::insert(&hash, fragment_data);
 
-			Proto2Fragments::<T>::append(&proto_hash, hash);
+			Proto2Fragments::<T>::insert(&proto_hash, &hash, ());
 
 			Self::deposit_event(Event::DefinitionCreated { fragment_hash: hash });
 			Ok(())
 		}
 
-		/// Put the **Fragment Definition `fragment_hash`** on sale. When a Fragment Definition is put on sale, users can create Fragment Instances from it for a fee.
+		/// **Set** the **metadata** `data` of the **Fragment Definition `fragment_hash`** under the **key `metadata_key`**
+		/// (e.g a **description**, an **image** or **game stats**), **replacing** the **previous one** if any.
 		///
-		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to put the **Fragment** on sale
+		/// Like the **metadata** of a **Proto-Fragment**, the **key** is **interned** in `MetaKeys` of the pallet `protos`,
+		/// only the **hash** of `data` is **stored** and `data` is **indexed** in the **Blockchain's Database**.
+		///
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to set the metadata
 		///
 		/// # Arguments
 		///
 		/// * `origin` - **Origin** of the **extrinsic function**
-		/// * `fragment_hash` - ID of the **Fragment Definition** to put on sale
-		/// * `price` -  **Price** to **buy** a **single Fragment Instance** that is created from the **Fragment Definition*
-		/// * `quantity` (*optional*) - **Maximum amount of Fragment Instances** that **can be bought**
-		/// * `expires` (*optional*) - **Block number** that the sale ends at (*optional*)
-		/// * `amount` (*optional*) - If the Fragment instance represents a **stack of stackable items** (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
-		/// the **number of items** to **top up** in the **stack of stackable items**
-		#[pallet::weight(50_000)]
-		pub fn publish(
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `metadata_key` - **Key** of the **metadata**
+		/// * `data` - **Metadata**, whose **hash** is **stored** under `metadata_key`
+		#[pallet::weight(<T as Config>::WeightInfo::set_definition_metadata(data.len() as u32))]
+		pub fn set_definition_metadata(
 			origin: OriginFor<T>,
 			fragment_hash: Hash128,
-			price: u128,
-			quantity: Option<Unit>,
-			expires: Option<T::BlockNumber>,
-			amount: Option<Unit>,
+			metadata_key: Vec<u8>,
+			// data we want to update last because of the way we store blocks (storage chain)
+			data: Vec<u8>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let proto_hash =
-				<Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?.proto_hash; // Get `proto_hash` from `fragment_hash`
-			let proto: Proto<T::AccountId, T::BlockNumber> =
-				<Protos<T>>::get(proto_hash).ok_or(Error::<T>::ProtoNotFound)?; // Get `proto` from `proto_hash`
-
-			let proto_owner: T::AccountId = match proto.owner {
-				// Get `proto_owner` from `proto`
-				ProtoOwner::User(owner) => Ok(owner),
-				_ => Err(Error::<T>::ProtoOwnerNotFound),
-			}?;
+			let proto_hash = Self::ensure_proto_owner(&who, &fragment_hash)?.proto_hash;
 
-			ensure!(who == proto_owner, Error::<T>::NoPermission); // Ensure `who` is `proto_owner`
-
-			// TO REVIEW
 			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // Ensure `proto_hash` isn't detached
 
-			ensure!(!<Publishing<T>>::contains_key(&fragment_hash), Error::<T>::SaleAlreadyOpen); // Ensure `fragment_hash` isn't already published
+			let bounded_metadata_key: MetadataKey =
+				metadata_key.clone().try_into().map_err(|_| Error::<T>::MetadataKeyTooLong)?;
 
-			let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?; // Get `FragmentDefinition` struct from `fragment_hash`
+			let data_hash = blake2_256(&data);
 
-			if let Some(max_supply) = fragment_data.max_supply {
-				let max: Unit = max_supply.into();
-				let existing: Unit =
-					<EditionsCount<T>>::get(&fragment_hash).unwrap_or(Compact(0)).into();
-				let left = max.saturating_sub(existing); // `left` = `max` - `existing`
-				if let Some(quantity) = quantity {
-					let quantity: Unit = quantity.into();
-					ensure!(quantity <= left, Error::<T>::MaxSupplyReached); // Ensure that the function parameter `quantity` is smaller than or equal to `left`
-				} else {
-					// Ensure that if `fragment_data.max_supply` exists, the function parameter `quantity` must also exist
-					return Err(Error::<T>::ParamsNotValid.into());
-				}
-				if left == 0 {
-					return Err(Error::<T>::MaxSupplyReached.into());
-				}
-			}
+			// we need this to index transactions
+			let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index()
+				.ok_or(Error::<T>::SystematicFailure)?;
 
 			// ! Writing
 
-			<Publishing<T>>::insert(
-				fragment_hash,
-				PublishingData {
-					price: Compact(price),
-					units_left: quantity.map(|x| Compact(x)),
-					expiration: expires,
-					amount: amount.map(|x| Compact(x)),
-				},
-			);
+			let metadata_key_index = Self::intern_metadata_key(&bounded_metadata_key);
 
-			Self::deposit_event(Event::Publishing { fragment_hash });
+			<DefinitionMetadata<T>>::insert(fragment_hash, Compact(metadata_key_index), data_hash);
+
+			// index data
+			transaction_index::index(extrinsic_index, data.len() as u32, data_hash);
+
+			Self::deposit_event(Event::DefinitionMetadataChanged { fragment_hash, metadata_key });
 
 			Ok(())
 		}
 
-		/// Take the **Fragment Definition `fragment_hash`** off sale.
-		/// When a Fragment Definition is put on sale, users can create Fragment Instances from it for a fee.
+		/// Set the **game authority** of the **Fragment Definition `fragment_hash`** to `authority`, or **remove** it if `authority` is `None`.
 		///
-		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to take the Fragment off sale
+		/// The **game authority** (e.g a **game server**) can **set** the **metadata** of **any Fragment Instance** of the **Fragment Definition**,
+		/// **regardless** of **who owns it** and of its **permitted actions** (see the `set_instance_metadata` extrinsic)
+		///
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to set the game authority
 		///
 		/// # Arguments
 		///
 		/// * `origin` - **Origin** of the **extrinsic function**
-		/// * `fragment_hash` - **ID** of the **Fragment Definition** to take off sale
-		#[pallet::weight(50_000)]
-		pub fn unpublish(origin: OriginFor<T>, fragment_hash: Hash128) -> DispatchResult {
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `authority` (*optional*) - **Account ID** of the **game authority**
+		#[pallet::weight(<T as Config>::WeightInfo::set_game_authority())]
+		pub fn set_game_authority(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			authority: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let proto_hash =
-				<Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?.proto_hash; // Get `proto_hash` from `fragment_hash`
-			let proto: Proto<T::AccountId, T::BlockNumber> =
-				<Protos<T>>::get(proto_hash).ok_or(Error::<T>::ProtoNotFound)?;
-
-			let proto_owner: T::AccountId = match proto.owner {
-				// Get `proto_owner` from `proto`
-				ProtoOwner::User(owner) => Ok(owner),
-				_ => Err(Error::<T>::ProtoOwnerNotFound),
-			}?;
-
-			ensure!(who == proto_owner, Error::<T>::NoPermission); // Ensure `who` is `proto_owner`
-
-			// TO REVIEW
-			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // Ensure `proto_hash` isn't detached
+			Self::ensure_proto_owner(&who, &fragment_hash)?;
 
-			ensure!(<Publishing<T>>::contains_key(&fragment_hash), Error::<T>::NotFound); // Ensure `fragment_hash` is currently published
+			let authority = authority.map(T::Lookup::lookup).transpose()?;
 
 			// ! Writing
 
-			<Publishing<T>>::remove(&fragment_hash); // Remove Fragment Definition `fragment_hash` from `Publishing`
+			match &authority {
+				Some(authority) => <GameAuthorities<T>>::insert(fragment_hash, authority),
+				None => <GameAuthorities<T>>::remove(fragment_hash),
+			}
 
-			Self::deposit_event(Event::Unpublishing { fragment_hash });
+			Self::deposit_event(Event::GameAuthorityChanged { fragment_hash, authority });
 
 			Ok(())
 		}
 
-		/// Create **Fragment instance(s)** from the **Fragment Definition `fragment_hash`** and
-		/// **assign their ownership** to **`origin`**
+		/// Allow the **account `minter`** to **mint Fragment Instances** of the **Fragment Definition `fragment_hash`** under the **terms `terms`**
+		/// (see the `mint`, `mint_to` and `mint_to_many` extrinsics), or **disallow** it if `terms` is `None`.
 		///
-		/// Note: **Each created Fragment instance** will have a **different Edition ID** and a **Copy ID of "1"**.
+		/// Calling this again with **new terms** **replaces** the **current terms** of the **minter** (e.g to **top up** its **quota**).
 		///
-		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** is **allowed** to
-		/// create instance(s) of the Fragment in this extrinsic function.
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to set the minters
 		///
 		/// # Arguments
 		///
 		/// * `origin` - **Origin** of the **extrinsic function**
-		/// * `fragment_hash` - **ID* of the **Fragment Definition**
-		/// * `options` - **Enum** indicating whether to
-		/// **create one Fragment Instance with custom data attached to it** or whether to  
-		/// **create multiple Fragment Instances (with no custom data attached)**
-		/// * `amount` (*optional*) - If the Fragment Instance(s) represent a **stack of stackable items**
-		/// (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
-		/// `amount` is the **number of items** to **top up** in the **stack of stackable items**
-		#[pallet::weight(50_000)]
-		pub fn mint(
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `minter` - **Account ID** of the **minter**
+		/// * `terms` (*optional*) - **Quota** and **expiration** of the **minter**
+		#[pallet::weight(<T as Config>::WeightInfo::set_minter())]
+		pub fn set_minter(
 			origin: OriginFor<T>,
 			fragment_hash: Hash128,
-			options: FragmentBuyOptions,
-			amount: Option<Unit>,
+			minter: <T::Lookup as StaticLookup>::Source,
+			terms: Option<MinterTerms<T::BlockNumber>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			Self::ensure_proto_owner(&who, &fragment_hash)?;
 
-			let proto_hash =
-				<Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?.proto_hash; // Get `proto_hash` from `fragment_hash`
-			let proto: Proto<T::AccountId, T::BlockNumber> =
-				<Protos<T>>::get(proto_hash).ok_or(Error::<T>::ProtoNotFound)?; // Get `proto` from `proto_hash`
+			let minter = T::Lookup::lookup(minter)?;
 
-			let proto_owner: T::AccountId = match proto.owner {
-				// Get `proto_owner` from `proto`
-				ProtoOwner::User(owner) => Ok(owner),
-				_ => Err(Error::<T>::ProtoOwnerNotFound),
-			}?;
+			if let Some(terms) = &terms {
+				if let Some(quota) = terms.quota {
+					ensure!(quota > Compact(0), Error::<T>::ParamsNotValid);
+				}
+				if let Some(expiration) = terms.expiration {
+					let current_block_number = <frame_system::Pallet<T>>::block_number();
+					ensure!(current_block_number < expiration, Error::<T>::ParamsNotValid);
+				}
+			}
 
-			ensure!(who == proto_owner, Error::<T>::NoPermission); // Ensure `who` is `proto_owner`
+			// ! Writing
 
-			// TO REVIEW
-			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // Ensure `proto_hash` isn't detached
+			match &terms {
+				Some(terms) => <Minters<T>>::insert(fragment_hash, &minter, terms),
+				None => <Minters<T>>::remove(fragment_hash, &minter),
+			}
 
-			let quantity = match options {
-				// Number of fragment instances to mint
-				FragmentBuyOptions::Quantity(amount) => u64::from(amount),
-				_ => 1u64,
-			};
+			Self::deposit_event(Event::MinterChanged { fragment_hash, minter, terms });
+
+			Ok(())
+		}
+
+		/// Put the **Fragment Definition `fragment_hash`** on sale. When a Fragment Definition is put on sale, users can create Fragment Instances from it for a fee.
+		///
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to put the **Fragment** on sale
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - ID of the **Fragment Definition** to put on sale
+		/// * `price` -  **Price** to **buy** a **single Fragment Instance** that is created from the **Fragment Definition*
+		/// * `quantity` (*optional*) - **Maximum amount of Fragment Instances** that **can be bought**
+		/// * `expires` (*optional*) - **Block number** that the sale ends at (*optional*)
+		/// * `amount` (*optional*) - If the Fragment instance represents a **stack of stackable items** (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
+		/// the **number of items** to **top up** in the **stack of stackable items**
+		/// * `allowlist` (*optional*) - **Accounts** that are **allowed** to **buy**. If `None`, **anyone** can **buy**.
+		/// * `max_per_account` (*optional*) - **Maximum number of Fragment Instances** that can be **bought for a single account** during the sale
+		/// * `starts_at` (*optional*) - **Block number** that the sale starts at. If `None`, the sale starts **now**.
+		/// * `phases` - **Phases** of the sale that come **after** its **first phase**, **sorted** by **start** (at most `MaxSalePhases`).
+		/// `price`, `allowlist` and `max_per_account` are the **terms** of the **first phase**.
+		/// * `curve` (*optional*) - **Pricing curve** of the sale, **starting** from the **price** of the **current phase**
+		#[pallet::weight(<T as Config>::WeightInfo::publish(phases.len() as u32))]
+		pub fn publish(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			price: u128,
+			quantity: Option<Unit>,
+			expires: Option<T::BlockNumber>,
+			amount: Option<Unit>,
+			allowlist: Option<Allowlist>,
+			max_per_account: Option<Unit>,
+			starts_at: Option<T::BlockNumber>,
+			phases: Vec<SalePhase<T::BlockNumber>>,
+			curve: Option<PriceCurve<T::BlockNumber>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// the weight is charged for `phases.len()` phases, which `MaxSalePhases` bounds
+			let phases: BoundedVec<SalePhase<T::BlockNumber>, T::MaxSalePhases> =
+				phases.try_into().map_err(|_| Error::<T>::TooManyItems)?;
+
+			let fragment_data = Self::ensure_proto_owner(&who, &fragment_hash)?;
+
+			// TO REVIEW
+			ensure!(
+				!<DetachedHashes<T>>::contains_key(&fragment_data.proto_hash),
+				Error::<T>::Detached
+			); // Ensure `proto_hash` isn't detached
+
+			ensure!(!<Publishing<T>>::contains_key(&fragment_hash), Error::<T>::SaleAlreadyOpen); // Ensure `fragment_hash` isn't already published
+
+			if let Some(max_supply) = fragment_data.max_supply {
+				let max: Unit = max_supply.into();
+				let existing = Self::editions_in_supply(&fragment_hash, &fragment_data);
+				let left = max.saturating_sub(existing); // `left` = `max` - `existing`
+				if let Some(quantity) = quantity {
+					let quantity: Unit = quantity.into();
+					ensure!(quantity <= left, Error::<T>::MaxSupplyReached); // Ensure that the function parameter `quantity` is smaller than or equal to `left`
+				} else {
+					// Ensure that if `fragment_data.max_supply` exists, the function parameter `quantity` must also exist
+					return Err(Error::<T>::ParamsNotValid.into());
+				}
+				if left == 0 {
+					return Err(Error::<T>::MaxSupplyReached.into());
+				}
+			}
+
+			ensure!(max_per_account != Some(0), Error::<T>::ParamsNotValid); // Ensure `max_per_account` allows to buy something
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			// Ensure every phase starts after the previous one and before the end of the sale
+			let mut previous_start = starts_at.unwrap_or(current_block_number);
+			ensure!(previous_start >= current_block_number, Error::<T>::ParamsNotValid);
+			for phase in phases.iter() {
+				ensure!(phase.starts_at > previous_start, Error::<T>::ParamsNotValid);
+				ensure!(phase.max_per_account != Some(Compact(0)), Error::<T>::ParamsNotValid);
+				previous_start = phase.starts_at;
+			}
+			if let Some(expiration) = expires {
+				ensure!(previous_start < expiration, Error::<T>::ParamsNotValid);
+			}
+
+			// Ensure a Dutch auction ends above the price of every phase
+			if let Some(PriceCurve::Decay { floor, duration }) = curve {
+				ensure!(!duration.is_zero(), Error::<T>::ParamsNotValid);
+				ensure!(
+					floor <= Compact(price) && phases.iter().all(|phase| floor <= phase.price),
+					Error::<T>::ParamsNotValid
+				);
+			}
 
 			// ! Writing
 
-			Self::mint_fragments(
-				&who,
-				&fragment_hash,
-				None, // PublishingData (optional)
-				&options,
-				quantity,
-				current_block_number,
-				None, // Block Number the Fragment(s) expire at (optional)
-				amount.map(|x| Compact(x)),
-			)
+			<Publishing<T>>::insert(
+				fragment_hash,
+				PublishingData {
+					price: Compact(price),
+					units_left: quantity.map(|x| Compact(x)),
+					starts_at,
+					expiration: expires,
+					amount: amount.map(|x| Compact(x)),
+					allowlist,
+					max_per_account: max_per_account.map(|x| Compact(x)),
+					published_at: current_block_number,
+					curve,
+					sold: Compact(0),
+				},
+			);
+			if !phases.is_empty() {
+				<SalePhases<T>>::insert(fragment_hash, phases);
+			}
+
+			Self::deposit_event(Event::Publishing { fragment_hash });
+
+			Ok(())
+		}
+
+		/// Take the **Fragment Definition `fragment_hash`** off sale.
+		/// When a Fragment Definition is put on sale, users can create Fragment Instances from it for a fee.
+		///
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to take the Fragment off sale
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID** of the **Fragment Definition** to take off sale
+		#[pallet::weight(<T as Config>::WeightInfo::unpublish())]
+		pub fn unpublish(origin: OriginFor<T>, fragment_hash: Hash128) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proto_hash = Self::ensure_proto_owner(&who, &fragment_hash)?.proto_hash;
+
+			// TO REVIEW
+			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // Ensure `proto_hash` isn't detached
+
+			ensure!(<Publishing<T>>::contains_key(&fragment_hash), Error::<T>::NotFound); // Ensure `fragment_hash` is currently published
+
+			// ! Writing
+
+			<Publishing<T>>::remove(&fragment_hash); // Remove Fragment Definition `fragment_hash` from `Publishing`
+			<SalePhases<T>>::remove(&fragment_hash);
+
+			Self::deposit_event(Event::Unpublishing { fragment_hash });
+
+			Ok(())
+		}
+
+		/// **Allow** the **accounts `accounts`** to **buy** the **Fragment Definition `fragment_hash`** if `allowed` is `true`,
+		/// or **disallow** them otherwise. The **accounts** are only **checked** when the **sale** has an `Allowlist::Accounts` **allowlist**.
+		///
+		/// The **allowlist** is **kept** between **sales**, so it can be **set** **before** the **sale** is **opened**.
+		///
+		/// Note: **Only** the **Fragment's Proto-Fragment's owner** is **allowed** to set the allowlist
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `accounts` - **Account IDs** to **allow** or **disallow** (at most `MaxBatchSize`)
+		/// * `allowed` - **Whether** to **allow** or **disallow** the **accounts**
+		#[pallet::weight(<T as Config>::WeightInfo::set_allowlist(accounts.len() as u32))]
+		pub fn set_allowlist(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			accounts: Vec<<T::Lookup as StaticLookup>::Source>,
+			allowed: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(accounts.len() <= T::MaxBatchSize::get() as usize, Error::<T>::TooManyItems);
+
+			Self::ensure_proto_owner(&who, &fragment_hash)?;
+
+			let accounts = accounts
+				.into_iter()
+				.map(T::Lookup::lookup)
+				.collect::<Result<Vec<T::AccountId>, _>>()?;
+
+			// ! Writing
+
+			for account in accounts {
+				if allowed {
+					<SaleAllowlists<T>>::insert(fragment_hash, account, ());
+				} else {
+					<SaleAllowlists<T>>::remove(fragment_hash, account);
+				}
+			}
+
+			Self::deposit_event(Event::AllowlistUpdated { fragment_hash });
+
+			Ok(())
+		}
+
+		/// Create **Fragment instance(s)** from the **Fragment Definition `fragment_hash`** and
+		/// **assign their ownership** to **`origin`**
+		///
+		/// Note: **Each created Fragment instance** will have a **different Edition ID** and a **Copy ID of "1"**.
+		///
+		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** and its **minters** (see the `set_minter` extrinsic)
+		/// are **allowed** to create instance(s) of the Fragment in this extrinsic function.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID* of the **Fragment Definition**
+		/// * `options` - **Enum** indicating whether to
+		/// **create one Fragment Instance with custom data attached to it** or whether to  
+		/// **create multiple Fragment Instances (with no custom data attached)**
+		/// * `amount` (*optional*) - If the Fragment Instance(s) represent a **stack of stackable items**
+		/// (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
+		/// `amount` is the **number of items** to **top up** in the **stack of stackable items**
+		#[pallet::weight(match options {
+			FragmentBuyOptions::Quantity(quantity) => <T as Config>::WeightInfo::mint_quantity((*quantity).saturated_into()),
+			FragmentBuyOptions::UniqueData(data) => <T as Config>::WeightInfo::mint_unique(data.len().saturated_into()),
+		})]
+		pub fn mint(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			options: FragmentBuyOptions,
+			amount: Option<Unit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_mint(&who, &who, fragment_hash, options, amount)
+		}
+
+		/// Create **Fragment instance(s)** from the **Fragment Definition `fragment_hash`** and
+		/// **assign their ownership** to **`to`**, exactly like the `mint` extrinsic does.
+		///
+		/// Since the Fragment instance(s) are **never given**, this also works for **Fragment Definitions**
+		/// whose **permissions** do **not** include **FragmentPerms::TRANSFER** (e.g soulbound items).
+		///
+		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** and its **minters** (see the `set_minter` extrinsic)
+		/// are **allowed** to create instance(s) of the Fragment in this extrinsic function.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID* of the **Fragment Definition**
+		/// * `to` - **Account ID** to assign the ownership of the created Fragment instance(s) to
+		/// * `options` - See the `mint` extrinsic
+		/// * `amount` (*optional*) - See the `mint` extrinsic
+		#[pallet::weight(match options {
+			FragmentBuyOptions::Quantity(quantity) => <T as Config>::WeightInfo::mint_quantity((*quantity).saturated_into()),
+			FragmentBuyOptions::UniqueData(data) => <T as Config>::WeightInfo::mint_unique(data.len().saturated_into()),
+		})]
+		pub fn mint_to(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			to: <T::Lookup as StaticLookup>::Source,
+			options: FragmentBuyOptions,
+			amount: Option<Unit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let to = T::Lookup::lookup(to)?;
+
+			Self::do_mint(&who, &to, fragment_hash, options, amount)
+		}
+
+		/// Create **one Fragment Instance** from the **Fragment Definition `fragment_hash`** for **each account** in `recipients`
+		/// and **assign its ownership** to that account (e.g to airdrop rewards to players)
+		///
+		/// Note: **Each created Fragment instance** will have a **different Edition ID** and a **Copy ID of "1"**.
+		///
+		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** and its **minters** (see the `set_minter` extrinsic)
+		/// are **allowed** to create instances of the Fragment in this extrinsic function, and **only** if the **Fragment Definition** is **not unique**.
+		/// **Either all** the Fragment Instances are created **or none** of them.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID* of the **Fragment Definition**
+		/// * `recipients` - **Account IDs** to create a Fragment Instance for (at most `MaxBatchSize`)
+		/// * `amount` (*optional*) - If the Fragment Instances represent a **stack of stackable items**
+		/// (for e.g gold coins or arrows - https://runescape.fandom.com/wiki/Stackable_items),
+		/// `amount` is the **number of items** in **each stack of stackable items**
+		#[pallet::weight(<T as Config>::WeightInfo::mint_to_many(recipients.len().saturated_into()))]
+		#[transactional]
+		pub fn mint_to_many(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			recipients: Vec<<T::Lookup as StaticLookup>::Source>,
+			amount: Option<Unit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				recipients.len() <= T::MaxBatchSize::get() as usize,
+				Error::<T>::TooManyItems
+			);
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			let minter_terms =
+				Self::ensure_can_mint(&who, fragment_hash, recipients.len() as Unit)?;
+
+			let recipients = recipients
+				.into_iter()
+				.map(T::Lookup::lookup)
+				.collect::<Result<Vec<T::AccountId>, _>>()?;
+
+			// ! Writing
+
+			for to in recipients.iter() {
+				Self::mint_fragments(
+					to,
+					&fragment_hash,
+					None, // PublishingData (optional)
+					&FragmentBuyOptions::Quantity(1),
+					1,
+					current_block_number,
+					None, // Block Number the Fragment(s) expire at (optional)
+					amount.map(|x| Compact(x)),
+				)?;
+			}
+
+			Self::record_mint(&who, fragment_hash, minter_terms, recipients.len() as Unit);
+
+			Ok(())
 		}
 
 		/// Allows the Caller Account ID `origin` to create Fragment instance(s) of the Fragment Definition `fragment_hash`,
@@ -663,376 +1654,2981 @@ pub mod pallet {
 		/// * `options` - **Enum** indicating whether to
 		/// **create one Fragment Instance with custom data attached to it** or whether to
 		/// **create multiple Fragment Instances (with no custom data attached)**
-		#[pallet::weight(50_000)]
+		/// * `proof` (*optional*) - **Merkle proof** that the **account** is **allowed** to **buy**,
+		/// **needed** if the **sale** has an `Allowlist::MerkleRoot` **allowlist** (at most `MAX_PROOF_LEN` hashes)
+		#[pallet::weight(match options {
+			FragmentBuyOptions::Quantity(quantity) => <T as Config>::WeightInfo::buy_quantity((*quantity).saturated_into()),
+			FragmentBuyOptions::UniqueData(data) => <T as Config>::WeightInfo::buy_unique(data.len().saturated_into()),
+		})]
 		pub fn buy(
 			origin: OriginFor<T>,
 			fragment_hash: Hash128,
 			options: FragmentBuyOptions,
+			proof: Option<Vec<Hash256>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			Self::do_buy(&who, &who, fragment_hash, options, proof)
+		}
 
-			let sale = <Publishing<T>>::get(&fragment_hash).ok_or(Error::<T>::NotFound)?; // if Fragment Definition `fragment_hash` is not published (i.e on sale), you cannot buy it
-			if let Some(expiration) = sale.expiration {
-				ensure!(current_block_number < expiration, Error::<T>::Expired);
-			}
+		/// Allows the Caller Account ID `origin` to create Fragment instance(s) of the Fragment Definition `fragment_hash`
+		/// for `to` (e.g as a gift), exactly like the `buy` extrinsic does. The fee is paid by the Caller Account ID
+		/// but the ownership of the created Fragment instance(s) is assigned to `to`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `to` - **Account ID** to assign the ownership of the created Fragment instance(s) to
+		/// * `options` - See the `buy` extrinsic
+		/// * `proof` (*optional*) - See the `buy` extrinsic, the **proof** is for **`to`**
+		#[pallet::weight(match options {
+			FragmentBuyOptions::Quantity(quantity) => <T as Config>::WeightInfo::buy_quantity((*quantity).saturated_into()),
+			FragmentBuyOptions::UniqueData(data) => <T as Config>::WeightInfo::buy_unique(data.len().saturated_into()),
+		})]
+		pub fn buy_for(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			to: <T::Lookup as StaticLookup>::Source,
+			options: FragmentBuyOptions,
+			proof: Option<Vec<Hash256>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			if let Some(units_left) = sale.units_left {
-				ensure!(units_left > Compact(0), Error::<T>::SoldOut);
+			let to = T::Lookup::lookup(to)?;
+
+			Self::do_buy(&who, &to, fragment_hash, options, proof)
+		}
+
+		/// Give the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`** to **`to`**.
+		///
+		/// If the **current permitted actions of the Fragment Instance** allows for it to be duplicated (i.e if it has the permission **FragmentPerms::COPY**),
+		/// then it is duplicated and the duplicate's ownership is assigned to `to`.
+		/// Otherwise, its ownernship is transferred from `origin` to `to`.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to give the Fragment Instance
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to give
+		/// * `edition` - Edition ID of the Fragment Insance to give
+		/// * `copy` - Copy ID of the Fragment instance to give
+		/// * `to` - **Account ID** to give the Fragment instance to
+		///
+		/// * `new_permissions` (*optional*) - The permitted set of actions (encapsulated in a `FragmentPerms` bitflag enum)
+		/// that the account that is given the Fragment instance can do with it.
+		///
+		/// Note: `new_permissions` must be a subset of the current `permissions` field of the Fragment Instance;
+		/// therefore, the `new_permissions` can only be more restrictive (than the current `permissions` field of the Fragment Instance),
+		/// never more permissive
+		///
+		/// * `expiration` (*optional*) - Block number that the duplicated Fragment Instance expires at.
+		/// If the Fragment Instance was not duplicated, this parameter is irrelevant.
+		#[pallet::weight(<T as Config>::WeightInfo::give())]
+		pub fn give(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			to: <T::Lookup as StaticLookup>::Source,
+			new_permissions: Option<FragmentPerms>,
+			expiration: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let to = T::Lookup::lookup(to)?;
+
+			Self::do_give(&who, class, edition, copy, to, new_permissions, expiration)
+		}
+
+		/// Give **each Fragment Instance** in `items` to the **account paired with it**, exactly like the `give` extrinsic does
+		/// (without changing its permissions or its expiration).
+		///
+		/// Note: **Only** the **owner** of **all** the **Fragment Instances** is **allowed** to give them.
+		/// **Either all** the Fragment Instances are given **or none** of them.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `items` - **List** of **(Fragment Definition ID, Edition ID, Copy ID, Account ID to give the Fragment Instance to)** (at most `MaxBatchSize`)
+		#[pallet::weight(<T as Config>::WeightInfo::give_many(items.len().saturated_into()))]
+		#[transactional]
+		pub fn give_many(
+			origin: OriginFor<T>,
+			items: Vec<(Hash128, Unit, Unit, <T::Lookup as StaticLookup>::Source)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(items.len() <= T::MaxBatchSize::get() as usize, Error::<T>::TooManyItems);
+
+			for (class, edition, copy, to) in items {
+				let to = T::Lookup::lookup(to)?;
+
+				Self::do_give(&who, class, edition, copy, to, None, None)?;
+			}
+
+			Ok(())
+		}
+
+		/// Approve `spender` to **transfer** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// on behalf of `origin` (see the `transfer_from` extrinsic). If `spender` is `None`, the **current approval** is **revoked**.
+		///
+		/// The **approval** is **cleared** when the **Fragment Instance changes hands**.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to approve a spender,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::TRANSFER**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance
+		/// * `edition` - Edition ID of the Fragment Instance
+		/// * `copy` - Copy ID of the Fragment Instance
+		/// * `spender` (*optional*) - **Account ID** to **approve**
+		#[pallet::weight(<T as Config>::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			spender: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let spender = spender.map(T::Lookup::lookup).transpose()?;
+
+			let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+				Error::<T>::NoPermission
+			);
+
+			// ! Writing
+
+			if let Some(spender) = &spender {
+				<Approvals<T>>::insert((class, edition, copy), spender);
+			} else {
+				<Approvals<T>>::remove((class, edition, copy));
+			}
+
+			Self::deposit_event(Event::Approval {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				owner: who,
+				spender,
+			});
+
+			Ok(())
+		}
+
+		/// Approve or revoke `operator` as an **operator** of **all** the **Fragment Instances of the Fragment Definition `class`** owned by `origin`.
+		/// An **operator** can **transfer** any of these **Fragment Instances** on behalf of `origin` (see the `transfer_from` extrinsic),
+		/// including the ones that `origin` **acquires later**.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - **Fragment Definition ID**
+		/// * `operator` - **Account ID** of the **operator**
+		/// * `approved` - Whether to **approve** or **revoke** the **operator**
+		#[pallet::weight(<T as Config>::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			class: Hash128,
+			operator: <T::Lookup as StaticLookup>::Source,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let operator = T::Lookup::lookup(operator)?;
+
+			ensure!(<Definitions<T>>::contains_key(class), Error::<T>::NotFound);
+
+			// ! Writing
+
+			if approved {
+				<Operators<T>>::insert((class, who.clone(), operator.clone()), ());
+			} else {
+				<Operators<T>>::remove((class, who.clone(), operator.clone()));
+			}
+
+			Self::deposit_event(Event::ApprovalForAll {
+				fragment_hash: class,
+				owner: who,
+				operator,
+				approved,
+			});
+
+			Ok(())
+		}
+
+		/// Give the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// of `from` to **`to`**, exactly like the `give` extrinsic does.
+		///
+		/// Note: **Only** the **account approved** for the **Fragment Instance** (see `approve`)
+		/// or an **operator** of `from` for the **Fragment Definition** (see `set_approval_for_all`) is **allowed** to call this extrinsic.
+		/// The **approval** is **used up** by the **transfer**, **even if** the **Fragment Instance** holds **FragmentPerms::COPY**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to give
+		/// * `edition` - Edition ID of the Fragment Insance to give
+		/// * `copy` - Copy ID of the Fragment instance to give
+		/// * `from` - **Account ID** that **owns** the Fragment Instance
+		/// * `to` - **Account ID** to give the Fragment instance to
+		/// * `new_permissions` (*optional*) - See the `give` extrinsic
+		/// * `expiration` (*optional*) - See the `give` extrinsic
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+			new_permissions: Option<FragmentPerms>,
+			expiration: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+
+			let approved = <Approvals<T>>::get((class, edition, copy)).as_ref() == Some(&who);
+			ensure!(
+				approved || <Operators<T>>::contains_key((class, from.clone(), who)),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_give(&from, class, edition, copy, to, new_permissions, expiration)?;
+
+			// ! Writing
+
+			// an approval is good for a single transfer, even if it gave away a copy and `from` still owns the Fragment Instance
+			if approved {
+				<Approvals<T>>::remove((class, edition, copy));
+			}
+
+			Ok(())
+		}
+
+		/// Create the **Account ID** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition`** and whose Copy ID is `copy`**  
+		///
+		/// The **existential deposit** of the account is **paid** by `origin` and **reserved** on the account until it is **closed**
+		/// (see the `close_account` extrinsic). The **owner** of the **Fragment Instance** can **act** as the account with the `as_fragment` extrinsic.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to create its account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - **Fragment Definition 	ID** of the **Fragment Instance**
+		/// * `edition` - **Edition ID** of the **Fragment Instance**
+		/// * `copy` - **Copy ID** of the **Fragment Instance**
+		#[pallet::weight(<T as Config>::WeightInfo::create_account())]
+		pub fn create_account(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				!<AccountDeposits<T>>::contains_key((class, edition, copy)),
+				Error::<T>::AlreadyExist
+			);
+
+			// create an account for a specific fragment
+			// we need an existential amount deposit to be able to create the vault account
+			let frag_account = Self::get_fragment_account_id(class, edition, copy);
+			let min_balance =
+				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::minimum_balance();
+
+			// ! Writing
+
+			// the owner pays for the deposit
+			<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+				&who,
+				&frag_account,
+				min_balance,
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(
+				&frag_account,
+				min_balance,
+			)?;
+
+			<AccountDeposits<T>>::insert(
+				(class, edition, copy),
+				Compact(min_balance.saturated_into::<u128>()),
+			);
+
+			Ok(())
+		}
+
+		/// Dispatch `call` as the **Account ID** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`** (see `get_fragment_account_id`).
+		///
+		/// The **Fragment Instance's account** has **no keys**: whoever **owns** the **Fragment Instance** controls it,
+		/// so **control** of the account **follows** the **Fragment Instance** when it **changes hands**.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to dispatch calls as its account
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - **Fragment Definition ID** of the **Fragment Instance**
+		/// * `edition` - **Edition ID** of the **Fragment Instance**
+		/// * `copy` - **Copy ID** of the **Fragment Instance**
+		/// * `call` - **Call** to **dispatch**
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::as_fragment().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn as_fragment(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			let frag_account = Self::get_fragment_account_id(class, edition, copy);
+
+			// ! Writing
+
+			let mut origin = origin;
+			origin.set_caller_from(frame_system::RawOrigin::Signed(frag_account));
+			let info = call.dispatch(origin);
+
+			let base_weight = <T as Config>::WeightInfo::as_fragment();
+			info.map(|mut post_info| {
+				post_info.actual_weight =
+					post_info.actual_weight.map(|weight| weight.saturating_add(base_weight));
+				post_info
+			})
+			.map_err(|mut err| {
+				err.post_info.actual_weight =
+					err.post_info.actual_weight.map(|weight| weight.saturating_add(base_weight));
+				err
+			})
+		}
+
+		/// Close the **account** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`**: its **deposit** is **unreserved**
+		/// and **all** its **NOVA** and **all** its **balance** of **each asset** in `assets` are **transferred** to `origin`.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to close its account,
+		/// and **only if** the account was **created** with the `create_account` extrinsic
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - **Fragment Definition ID** of the **Fragment Instance**
+		/// * `edition` - **Edition ID** of the **Fragment Instance**
+		/// * `copy` - **Copy ID** of the **Fragment Instance**
+		/// * `assets` - **Asset IDs** of the **assets** (in `pallet_assets`) to **sweep** from the account (at most `MaxBatchSize`)
+		#[pallet::weight(<T as Config>::WeightInfo::close_account(assets.len() as u32))]
+		pub fn close_account(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			assets: Vec<T::AssetId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(assets.len() <= T::MaxBatchSize::get() as usize, Error::<T>::TooManyItems);
+
+			Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			let deposit =
+				<AccountDeposits<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?.0;
+
+			let frag_account = Self::get_fragment_account_id(class, edition, copy);
+
+			// ! Writing
+
+			<AccountDeposits<T>>::remove((class, edition, copy));
+
+			// only the deposit, any other reserve of the account (e.g. a bid) stays reserved
+			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::unreserve(
+				&frag_account,
+				deposit.saturated_into(),
+			);
+
+			for asset in assets {
+				let balance =
+					<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::reducible_balance(
+						asset,
+						&frag_account,
+						false,
+					);
+				if balance.is_zero() {
+					continue
+				}
+				<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+					asset,
+					&frag_account,
+					&who,
+					balance,
+					false,
+				)?;
+			}
+
+			let amount =
+				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::free_balance(&frag_account);
+			<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+				&frag_account,
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::AccountClosed {
+				account_id: who,
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				amount: amount.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Burn the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to burn the Fragment Instance,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::BURN**
+		/// (which is **chosen** by the **Fragment Definition** when it is **created**, see the `create` extrinsic)
+		///
+		/// If the **Fragment Instance** was the **last one** of its **edition**, the **edition** is **recorded** as **burned**
+		/// (see `BurnedEditionsCount`)
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to burn
+		/// * `edition` - Edition ID of the Fragment Instance to burn
+		/// * `copy` - Copy ID of the Fragment Instance to burn
+		#[pallet::weight(<T as Config>::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Only the owner of this fragment can burn it
+			let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::BURN) == FragmentPerms::BURN,
+				Error::<T>::NoPermission
+			);
+
+			// ! Writing
+
+			Self::burn_fragment(&who, class, edition, copy, item_data.expiring_at);
+
+			Self::deposit_event(Event::Burned {
+				account_id: who,
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+
+			Ok(())
+		}
+
+		/// Revoke the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// from **whoever owns it** (e.g a **license**, a **membership** or **gear** revoked by an **anti-cheat**).
+		/// The **Fragment Instance** is **burned** if `burn` is `true`, or **given back** to the **creator** of the **Fragment Definition** otherwise.
+		///
+		/// Unlike the `burn` and `give` extrinsics, the **permitted actions of the Fragment Instance** are **not checked**.
+		/// The **listing** of the **Fragment Instance** is **removed** and its **auction** is **closed** (the **highest bid** is **refunded**).
+		///
+		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** is **allowed** to revoke Fragment Instances,
+		/// and **only if** the **Fragment Definition** was **created revocable** (see the `create` extrinsic)
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to revoke
+		/// * `edition` - Edition ID of the Fragment Instance to revoke
+		/// * `copy` - Copy ID of the Fragment Instance to revoke
+		/// * `burn` - **Whether** to **burn** the **Fragment Instance** or to **give** it **back** to the **creator** of the **Fragment Definition**
+		#[pallet::weight(<T as Config>::WeightInfo::revoke())]
+		pub fn revoke(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			burn: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let fragment_data = Self::ensure_proto_owner(&who, &class)?;
+
+			ensure!(fragment_data.revocable, Error::<T>::NotRevocable);
+
+			let item_data = Self::get_live_fragment(class, edition, copy)?;
+			let holder =
+				<InstanceOwner<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+
+			// detached Fragment Instances are frozen
+			ensure!(
+				!<DetachedInstances<T>>::contains_key((class, edition, copy)),
+				Error::<T>::Detached
+			);
+
+			// there is nothing to revoke from the creator
+			ensure!(burn || holder != fragment_data.creator, Error::<T>::ParamsNotValid);
+
+			// ! Writing
+
+			// the winner of the auction would not receive it
+			Self::cancel_instance_auction(class, edition, copy);
+
+			if burn {
+				Self::burn_fragment(&holder, class, edition, copy, item_data.expiring_at);
+			} else {
+				Self::transfer_fragment(&holder, &fragment_data.creator, class, edition, copy);
+			}
+
+			Self::deposit_event(Event::Revoked {
+				account_id: holder,
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				burned: burn,
+			});
+
+			Ok(())
+		}
+
+		/// Split `amount` items off the **stack of stackable items** that is the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`**, into a **new copy** of the **same edition** owned by `origin`.
+		///
+		/// The **new copy** has the **same permissions** and the **same expiration** as the **Fragment Instance** it was split from.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to split the Fragment Instance,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::EDIT**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to split
+		/// * `edition` - Edition ID of the Fragment Instance to split
+		/// * `copy` - Copy ID of the Fragment Instance to split
+		/// * `amount` - **Number of items** to **move** to the **new copy**
+		#[pallet::weight(<T as Config>::WeightInfo::split())]
+		pub fn split(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			amount: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT,
+				Error::<T>::NoPermission
+			);
+
+			let stack: Unit = item_data.amount.ok_or(Error::<T>::NotStackable)?.into();
+			ensure!(amount > 0, Error::<T>::ParamsNotValid);
+			ensure!(amount < stack, Error::<T>::InsufficientAmount); // both stacks must keep at least one item
+
+			let new_copy: Unit =
+				<CopiesCount<T>>::get((class, edition)).ok_or(Error::<T>::NotFound)?.into();
+			let new_copy = new_copy + 1;
+
+			// ! Writing
+
+			<CopiesCount<T>>::insert((class, edition), Compact(new_copy));
+
+			// the listed stack is not the same anymore
+			Self::remove_listing(class, edition, copy);
+
+			item_data.amount = Some(Compact(stack - amount));
+			<Fragments<T>>::insert((class, edition, copy), item_data.clone());
+
+			let expiring_at = item_data.expiring_at;
+			item_data.amount = Some(Compact(amount));
+			<Fragments<T>>::insert((class, edition, new_copy), item_data);
+
+			<Owners<T>>::insert((class, who.clone(), edition, new_copy), ());
+			<Inventory<T>>::insert((who.clone(), class, edition, new_copy), ());
+			<InstanceOwner<T>>::insert((class, edition, new_copy), who.clone());
+
+			if let Some(expiring_at) = expiring_at {
+				<Expirations<T>>::insert((expiring_at, class, edition, new_copy), ());
+			}
+
+			Self::deposit_event(Event::InventoryUpdated {
+				account_id: who.clone(),
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+			Self::deposit_event(Event::InventoryAdded {
+				account_id: who,
+				fragment_hash: class,
+				fragment_id: (edition, new_copy),
+			});
+
+			Ok(())
+		}
+
+		/// Merge the **stack of stackable items** that is the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`** into the **stack of stackable items**
+		/// that is the **Fragment Instance of the same Fragment Definition whose Edition ID is `into_edition` and whose Copy ID is `into_copy`**.
+		///
+		/// The **first Fragment Instance** is **burned** (see the `burn` extrinsic) and its **items** are **added** to the **second one**.
+		///
+		/// Note: **Only** the **owner** of **both Fragment Instances** is **allowed** to merge them,
+		/// and **only if** the **current permitted actions of both Fragment Instances** include **FragmentPerms::EDIT**.
+		/// To **not escape** the **restrictions** of the **merged items**, the **second Fragment Instance** can **neither** be **more permissive**
+		/// **nor expire later** than the **first one**, and **both** must have the **same custom data**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of both Fragment Instances
+		/// * `edition` - Edition ID of the Fragment Instance to merge
+		/// * `copy` - Copy ID of the Fragment Instance to merge
+		/// * `into_edition` - Edition ID of the Fragment Instance to merge into
+		/// * `into_copy` - Copy ID of the Fragment Instance to merge into
+		#[pallet::weight(<T as Config>::WeightInfo::merge())]
+		pub fn merge(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			into_edition: Unit,
+			into_copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!((edition, copy) != (into_edition, into_copy), Error::<T>::ParamsNotValid);
+
+			let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+			let mut into_data = Self::get_owned_fragment(&who, class, into_edition, into_copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT &&
+					(into_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT,
+				Error::<T>::NoPermission
+			);
+			// the merged items must not gain permissions
+			ensure!(item_data.permissions.contains(into_data.permissions), Error::<T>::NoPermission);
+			// the merged items must not live longer
+			if let Some(item_expiration) = item_data.expiring_at {
+				ensure!(
+					into_data.expiring_at.map_or(false, |into_expiration| into_expiration <= item_expiration),
+					Error::<T>::ParamsNotValid
+				);
+			}
+			ensure!(item_data.custom_data == into_data.custom_data, Error::<T>::ParamsNotValid);
+
+			let stack: Unit = item_data.amount.ok_or(Error::<T>::NotStackable)?.into();
+			let into_stack: Unit = into_data.amount.ok_or(Error::<T>::NotStackable)?.into();
+
+			// ! Writing
+
+			into_data.amount = Some(Compact(into_stack.saturating_add(stack)));
+			<Fragments<T>>::insert((class, into_edition, into_copy), into_data);
+
+			// the listed stack is not the same anymore
+			Self::remove_listing(class, into_edition, into_copy);
+
+			// the emptied stack is burned, like a stack that is consumed entirely
+			Self::burn_fragment(&who, class, edition, copy, item_data.expiring_at);
+
+			Self::deposit_event(Event::InventoryRemoved {
+				account_id: who.clone(),
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+			Self::deposit_event(Event::InventoryUpdated {
+				account_id: who,
+				fragment_hash: class,
+				fragment_id: (into_edition, into_copy),
+			});
+
+			Ok(())
+		}
+
+		/// Consume `amount` items of the **stack of stackable items** that is the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`**.
+		///
+		/// If **no items** are **left** in the **stack**, the **Fragment Instance** is **burned** (see the `burn` extrinsic).
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to consume the Fragment Instance,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::EDIT**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to consume
+		/// * `edition` - Edition ID of the Fragment Instance to consume
+		/// * `copy` - Copy ID of the Fragment Instance to consume
+		/// * `amount` - **Number of items** to **consume**
+		#[pallet::weight(<T as Config>::WeightInfo::consume())]
+		pub fn consume(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			amount: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT,
+				Error::<T>::NoPermission
+			);
+
+			let stack: Unit = item_data.amount.ok_or(Error::<T>::NotStackable)?.into();
+			ensure!(amount > 0, Error::<T>::ParamsNotValid);
+			ensure!(amount <= stack, Error::<T>::InsufficientAmount);
+
+			// ! Writing
+
+			let left = stack - amount;
+			if left == 0 {
+				Self::burn_fragment(&who, class, edition, copy, item_data.expiring_at);
+			} else {
+				// the listed stack is not the same anymore
+				Self::remove_listing(class, edition, copy);
+
+				item_data.amount = Some(Compact(left));
+				<Fragments<T>>::insert((class, edition, copy), item_data);
+			}
+
+			Self::deposit_event(Event::InventoryUpdated {
+				account_id: who.clone(),
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+
+			if left == 0 {
+				Self::deposit_event(Event::Burned {
+					account_id: who,
+					fragment_hash: class,
+					fragment_id: (edition, copy),
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Replace the **unique data** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`** with `data`.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to update its unique data,
+		/// **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::EDIT**
+		/// and **only if** its **Fragment Definition** was **created** with **mutable unique data** (see `UniqueOptions`).
+		///
+		/// Since the **unique data** belongs to the **edition**, it can **only** be **updated** while the **Fragment Instance**
+		/// is the **only copy** of its **edition**. The **new data** must **not** be **used** by **any other edition** of the **Fragment Definition**.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance
+		/// * `edition` - Edition ID of the Fragment Instance
+		/// * `copy` - Copy ID of the Fragment Instance
+		/// * `data` - **New unique data** of the **Fragment Instance**
+		#[pallet::weight(<T as Config>::WeightInfo::update_unique_data(data.len().saturated_into()))]
+		pub fn update_unique_data(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT,
+				Error::<T>::NoPermission
+			);
+
+			let fragment_data = <Definitions<T>>::get(class).ok_or(Error::<T>::NotFound)?;
+			ensure!(
+				matches!(fragment_data.unique, Some(UniqueOptions { mutable: true })),
+				Error::<T>::ImmutableUniqueData
+			);
+
+			let old_data_hash = item_data.custom_data.ok_or(Error::<T>::NotFound)?;
+
+			// other copies of the edition would still point to the old data
+			ensure!(
+				<Fragments<T>>::iter_prefix((class, edition)).nth(1).is_none(),
+				Error::<T>::UniqueDataShared
+			);
+
+			let data_hash = blake2_256(&data);
+
+			ensure!(
+				!<UniqueData2Edition<T>>::contains_key(class, data_hash),
+				Error::<T>::UniqueDataExists
+			);
+
+			// we need this to index transactions
+			let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index()
+				.ok_or(Error::<T>::SystematicFailure)?;
+
+			// ! Writing
+
+			<UniqueData2Edition<T>>::remove(class, old_data_hash);
+			<UniqueData2Edition<T>>::insert(class, data_hash, edition);
+
+			item_data.custom_data = Some(data_hash);
+			<Fragments<T>>::insert((class, edition, copy), item_data);
+
+			// the listed data is not the same anymore
+			Self::remove_listing(class, edition, copy);
+
+			// index mutable data for IPFS discovery as well
+			transaction_index::index(extrinsic_index, data.len() as u32, data_hash);
+
+			// also emit event
+			let cid = [&CID_PREFIX[..], &data_hash[..]].concat();
+			let cid = cid.to_base58();
+			let cid = [&b"z"[..], cid.as_bytes()].concat();
+
+			Self::deposit_event(Event::UniqueDataUpdated {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				cid,
+			});
+
+			Ok(())
+		}
+
+		/// Set the **metadata** of the **Fragment Instance whose Fragment Definition ID is `class`,
+		/// whose Edition ID is `edition` and whose Copy ID is `copy`** under the **key `metadata_key`** (e.g a level, a durability or a skin).
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to set its metadata,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::EDIT**.
+		/// The **game authority** of the **Fragment Definition** (see the `set_game_authority` extrinsic) is **always allowed** to.
+		/// A **Fragment Instance** has **metadata** under **at most** `MaxInstanceMetadata` **Metadata Keys**.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance
+		/// * `edition` - Edition ID of the Fragment Instance
+		/// * `copy` - Copy ID of the Fragment Instance
+		/// * `metadata_key` - **Key** of the **metadata**
+		/// * `data` - **Metadata**, whose **hash** is **stored** under `metadata_key`
+		#[pallet::weight(<T as Config>::WeightInfo::set_instance_metadata(data.len() as u32))]
+		pub fn set_instance_metadata(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			metadata_key: Vec<u8>,
+			// data we want to update last because of the way we store blocks (storage chain)
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if <GameAuthorities<T>>::get(class).as_ref() == Some(&who) {
+				// the game authority doesn't need to own the Fragment Instance
+				Self::get_live_fragment(class, edition, copy)?;
+
+				// detached Fragment Instances are frozen
+				ensure!(
+					!<DetachedInstances<T>>::contains_key((class, edition, copy)),
+					Error::<T>::Detached
+				);
+			} else {
+				let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+				ensure!(
+					(item_data.permissions & FragmentPerms::EDIT) == FragmentPerms::EDIT,
+					Error::<T>::NoPermission
+				);
+			}
+
+			let bounded_metadata_key: MetadataKey =
+				metadata_key.clone().try_into().map_err(|_| Error::<T>::MetadataKeyTooLong)?;
+
+			let mut metadata = <InstanceMetadata<T>>::get((class, edition, copy));
+			let is_set = <MetaKeys<T>>::get(&bounded_metadata_key)
+				.map_or(false, |index| metadata.contains_key(&Compact(index)));
+			ensure!(
+				is_set || metadata.len() < T::MaxInstanceMetadata::get() as usize,
+				Error::<T>::TooManyMetadataKeys
+			);
+
+			let data_hash = blake2_256(&data);
+
+			// we need this to index transactions
+			let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index()
+				.ok_or(Error::<T>::SystematicFailure)?;
+
+			// ! Writing
+
+			let metadata_key_index = Self::intern_metadata_key(&bounded_metadata_key);
+
+			metadata
+				.try_insert(Compact(metadata_key_index), data_hash)
+				.map_err(|_| Error::<T>::TooManyMetadataKeys)?;
+			<InstanceMetadata<T>>::insert((class, edition, copy), metadata);
+
+			// index data
+			transaction_index::index(extrinsic_index, data.len() as u32, data_hash);
+
+			Self::deposit_event(Event::InstanceMetadataChanged {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				metadata_key,
+			});
+
+			Ok(())
+		}
+
+		/// Renew the **expiring Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**.
+		///
+		/// The **renewal price** of the **Fragment Definition** is **paid** from `origin` to the **vault** of the **Fragment Definition**,
+		/// and the **renewal period** is **added** to the **block number** that the **Fragment Instance expires at**.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to renew it, **before** it expires,
+		/// and **only if** the **Fragment Definition** has **renewal terms**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance
+		/// * `edition` - Edition ID of the Fragment Instance
+		/// * `copy` - Copy ID of the Fragment Instance
+		#[pallet::weight(<T as Config>::WeightInfo::renew())]
+		pub fn renew(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			let old_expiration = item_data.expiring_at.ok_or(Error::<T>::ParamsNotValid)?; // only expiring Fragment Instances can be renewed
+
+			let fragment_data = <Definitions<T>>::get(class).ok_or(Error::<T>::NotFound)?;
+			let renewal = fragment_data.renewal.ok_or(Error::<T>::NoPermission)?;
+
+			let expiring_at = old_expiration.saturating_add(renewal.period);
+
+			// ! Writing
+
+			Self::transfer_currency(
+				fragment_data.metadata.currency,
+				&who,
+				&Self::get_vault_id(class),
+				renewal.price.into(),
+			)?;
+
+			<Expirations<T>>::remove((old_expiration, class, edition, copy));
+			<Expirations<T>>::insert((expiring_at, class, edition, copy), ());
+
+			item_data.expiring_at = Some(expiring_at);
+			<Fragments<T>>::insert((class, edition, copy), item_data);
+
+			Self::deposit_event(Event::Renewed {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				expiring_at,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw `amount` units of `currency` from the **vault** of the **Fragment Definition `fragment_hash`** to `origin`.
+		/// The **vault** receives the **payments** of the **Fragment Instances** that are **bought** (see the `buy` extrinsic).
+		///
+		/// Note: **Only** the **creator** of the **Fragment Definition** or the **current owner** of its **Proto-Fragment**
+		/// is **allowed** to withdraw the proceeds. The **minimum balance** that is **reserved** to keep the vault alive **cannot** be withdrawn.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `amount` - **Amount** to **withdraw**
+		/// * `currency` (*optional*) - **Fungible asset** to **withdraw**. If this parameter is `None`, NOVA is withdrawn
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_proceeds())]
+		pub fn withdraw_proceeds(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			amount: u128,
+			currency: Option<T::AssetId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
+			let proto: Proto<T::AccountId, T::BlockNumber> =
+				<Protos<T>>::get(fragment_data.proto_hash).ok_or(Error::<T>::ProtoNotFound)?;
+
+			let is_proto_owner = matches!(proto.owner, ProtoOwner::User(ref owner) if *owner == who);
+			ensure!(who == fragment_data.creator || is_proto_owner, Error::<T>::NoPermission);
+
+			let vault = Self::get_vault_id(fragment_hash);
+
+			// ! Writing
+
+			// the reserved minimum balance stays in `vault`
+			Self::transfer_currency(currency, &vault, &who, amount)?;
+
+			Self::deposit_event(Event::ProceedsWithdrawn {
+				fragment_hash,
+				account_id: who,
+				currency,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// List the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// on the **secondary market** for `price` units of `currency`. Listing an **already listed Fragment Instance** **replaces** its **listing**.
+		///
+		/// The **Fragment Instance** stays in the **inventory** of `origin` until it is **bought** (see the `buy_instance` extrinsic).
+		/// The **listing** is **removed** when the **Fragment Instance changes hands**, **expires** or when the **listing ends**.
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to list the Fragment Instance,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::TRANSFER**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance to list
+		/// * `edition` - Edition ID of the Fragment Instance to list
+		/// * `copy` - Copy ID of the Fragment Instance to list
+		/// * `price` - **Price** of the **Fragment Instance**
+		/// * `currency` (*optional*) - **Currency** of the **price**. If this parameter is `None`, the currency is NOVA
+		/// * `expiration` (*optional*) - **Block number** that the **listing ends at**
+		#[pallet::weight(<T as Config>::WeightInfo::list_instance())]
+		pub fn list_instance(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			price: u128,
+			currency: Option<T::AssetId>,
+			expiration: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+				Error::<T>::NoPermission
+			);
+
+			if let Some(expiration) = expiration {
+				ensure!(current_block_number < expiration, Error::<T>::ParamsNotValid);
+			}
+
+			// ! Writing
+
+			if let Some(old_listing) = <Listings<T>>::get((class, edition, copy)) {
+				if let Some(old_expiration) = old_listing.expiration {
+					<ListingExpirations<T>>::remove((old_expiration, class, edition, copy));
+				}
+			}
+
+			<Listings<T>>::insert(
+				(class, edition, copy),
+				Listing { seller: who.clone(), price: Compact(price), currency, expiration },
+			);
+
+			if let Some(expiration) = expiration {
+				<ListingExpirations<T>>::insert((expiration, class, edition, copy), ());
+			}
+
+			Self::deposit_event(Event::Listed {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				seller: who,
+				price,
+				currency,
+				expiration,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel the **listing** of the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		///
+		/// Note: **Only** the **seller** is **allowed** to cancel the listing
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the listed Fragment Instance
+		/// * `edition` - Edition ID of the listed Fragment Instance
+		/// * `copy` - Copy ID of the listed Fragment Instance
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let listing = <Listings<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+			ensure!(listing.seller == who, Error::<T>::NoPermission);
+
+			// ! Writing
+
+			Self::remove_listing(class, edition, copy);
+
+			Ok(())
+		}
+
+		/// Buy the **listed Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// at its **listed price**.
+		///
+		/// The **price** is **paid** from `origin` to the **seller** in the **currency** of the **listing**,
+		/// minus the **royalty** of the **Fragment Definition** that is **paid** to its **beneficiary** (see `pay_secondary_sale`),
+		/// and the **ownership** of the **Fragment Instance** is **transferred** to `origin` in the **same extrinsic**.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the listed Fragment Instance
+		/// * `edition` - Edition ID of the listed Fragment Instance
+		/// * `copy` - Copy ID of the listed Fragment Instance
+		#[pallet::weight(<T as Config>::WeightInfo::buy_instance())]
+		pub fn buy_instance(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			let listing = <Listings<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+			if let Some(expiration) = listing.expiration {
+				ensure!(current_block_number < expiration, Error::<T>::Expired);
+			}
+
+			ensure!(who != listing.seller, Error::<T>::ParamsNotValid);
+
+			// the seller must still own the Fragment Instance and be allowed to transfer it
+			let item_data = Self::get_owned_fragment(&listing.seller, class, edition, copy)?;
+			ensure!(
+				(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+				Error::<T>::NoPermission
+			);
+
+			let price: u128 = listing.price.into();
+
+			// ! Writing
+
+			Self::pay_secondary_sale(class, &who, &listing.seller, price, listing.currency)?;
+
+			<Listings<T>>::remove((class, edition, copy));
+			if let Some(expiration) = listing.expiration {
+				<ListingExpirations<T>>::remove((expiration, class, edition, copy));
+			}
+
+			Self::transfer_fragment(&listing.seller, &who, class, edition, copy);
+
+			Self::deposit_event(Event::InstanceSold {
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+				seller: listing.seller,
+				buyer: who,
+				price,
+				currency: listing.currency,
+			});
+
+			Ok(())
+		}
+
+		/// Create an **auction** of a **new edition** of the **Fragment Definition `fragment_hash`**
+		/// or of an **existing Fragment Instance** of it, that **ends** in `duration` blocks.
+		///
+		/// **Bids** are **held** until the **bidder** is **outbid** or the auction is **settled**: NOVA bids are **reserved**,
+		/// bids in a **fungible asset** are **transferred** to the **account** of the **auction** (see `get_auction_account_id`).
+		/// **English auctions** are **settled** in the `on_initialize` of the **block** they **end at** (see `process_auction_ends`),
+		/// **Dutch auctions** are **settled** by the **first bid**.
+		///
+		/// Note: **Only** the **Fragment Definition's Proto-Fragment's owner** is **allowed** to auction a **new edition**,
+		/// which **must not** hold **unique data** and must be **sold** in the **currency** of the **Fragment Definition**.
+		/// **Only** the **Fragment Instance's owner** is **allowed** to auction a **Fragment Instance**,
+		/// and **only if** the **current permitted actions of the Fragment Instance** include **FragmentPerms::TRANSFER**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `fragment_hash` - **ID** of the **Fragment Definition**
+		/// * `item` - **What** is **sold**
+		/// * `kind` - **Kind** of **auction**
+		/// * `currency` (*optional*) - **Currency** of the **bids**. If this parameter is `None`, the currency is NOVA
+		/// * `duration` - **Number of blocks** that the **auction lasts**
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			fragment_hash: Hash128,
+			item: AuctionItem,
+			kind: AuctionKind<T::BlockNumber>,
+			currency: Option<T::AssetId>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
+
+			match item {
+				AuctionItem::Edition { .. } => {
+					ensure!(
+						who == Self::get_proto_owner(&fragment_data.proto_hash)?,
+						Error::<T>::NoPermission
+					);
+
+					ensure!(
+						!<DetachedHashes<T>>::contains_key(&fragment_data.proto_hash),
+						Error::<T>::Detached
+					);
+
+					ensure!(fragment_data.unique.is_none(), Error::<T>::ParamsNotValid);
+					ensure!(currency == fragment_data.metadata.currency, Error::<T>::ParamsNotValid);
+
+					if let Some(max_supply) = fragment_data.max_supply {
+						let max: Unit = max_supply.into();
+						ensure!(
+							Self::editions_in_supply(&fragment_hash, &fragment_data) < max,
+							Error::<T>::MaxSupplyReached
+						);
+					}
+				},
+				AuctionItem::Instance { edition, copy } => {
+					// also fails if the Fragment Instance is already in an auction
+					let item_data = Self::get_owned_fragment(&who, fragment_hash, edition, copy)?;
+
+					ensure!(
+						(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+						Error::<T>::NoPermission
+					);
+				},
+			}
+
+			match kind {
+				AuctionKind::English { .. } => {},
+				AuctionKind::Dutch { start_price, end_price } => {
+					let start_price: u128 = start_price.into();
+					let end_price: u128 = end_price.into();
+					ensure!(start_price >= end_price, Error::<T>::ParamsNotValid);
+				},
+			}
+
+			ensure!(!duration.is_zero(), Error::<T>::ParamsNotValid);
+			let end = current_block_number.saturating_add(duration);
+
+			let auction_id = <NextAuctionId<T>>::get();
+
+			// ! Writing
+
+			<NextAuctionId<T>>::put(auction_id.saturating_add(1));
+
+			if let AuctionItem::Instance { edition, copy } = item {
+				<InstanceAuctions<T>>::insert((fragment_hash, edition, copy), auction_id);
+				// it can no longer be bought from its listing
+				Self::remove_listing(fragment_hash, edition, copy);
+			}
+
+			<Auctions<T>>::insert(
+				auction_id,
+				Auction {
+					fragment_hash,
+					item,
+					seller: who.clone(),
+					kind,
+					currency,
+					start: current_block_number,
+					end,
+					highest_bid: None,
+				},
+			);
+			<AuctionEnds<T>>::insert(end, auction_id, ());
+
+			Self::deposit_event(Event::AuctionCreated { auction_id, fragment_hash, seller: who });
+
+			Ok(())
+		}
+
+		/// Bid `amount` in the **auction `auction_id`**.
+		///
+		/// In an **English auction**, `amount` is **held** and the **previous highest bid** is **refunded**.
+		/// In a **Dutch auction**, `amount` is the **maximum price** `origin` is **willing to pay**: if it is **not lower**
+		/// than the **current price**, the auction is **settled immediately** at the **current price**.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `auction_id` - **ID** of the **auction**
+		/// * `amount` - **Amount** to **bid**
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		pub fn bid(origin: OriginFor<T>, auction_id: u64, amount: u128) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+			let mut auction = <Auctions<T>>::get(auction_id).ok_or(Error::<T>::NotFound)?;
+			ensure!(current_block_number < auction.end, Error::<T>::Expired);
+			ensure!(who != auction.seller, Error::<T>::ParamsNotValid);
+
+			match auction.kind {
+				AuctionKind::English { reserve, extension } => {
+					let reserve: u128 = reserve.into();
+					ensure!(amount >= reserve, Error::<T>::BidTooLow);
+					if let Some((_, highest)) = &auction.highest_bid {
+						let highest: u128 = (*highest).into();
+						ensure!(amount > highest, Error::<T>::BidTooLow);
+					}
+
+					// ! Writing
+
+					Self::hold_bid(auction_id, auction.currency, &who, amount)?;
+					if let Some((bidder, highest)) = auction.highest_bid.take() {
+						Self::refund_bid(auction_id, auction.currency, &bidder, highest.into())?;
+					}
+					auction.highest_bid = Some((who.clone(), Compact(amount)));
+
+					Self::deposit_event(Event::BidPlaced { auction_id, bidder: who, amount });
+
+					if auction.end.saturating_sub(current_block_number) < extension {
+						<AuctionEnds<T>>::remove(auction.end, auction_id);
+						auction.end = current_block_number.saturating_add(extension);
+						<AuctionEnds<T>>::insert(auction.end, auction_id, ());
+
+						Self::deposit_event(Event::AuctionExtended { auction_id, end: auction.end });
+					}
+
+					<Auctions<T>>::insert(auction_id, auction);
+				},
+				AuctionKind::Dutch { .. } => {
+					let price = Self::get_auction_price(&auction);
+					ensure!(amount >= price, Error::<T>::BidTooLow);
+
+					// ! Writing
+
+					Self::hold_bid(auction_id, auction.currency, &who, price)?;
+					auction.highest_bid = Some((who.clone(), Compact(price)));
+
+					Self::deposit_event(Event::BidPlaced { auction_id, bidder: who, amount: price });
+
+					Self::remove_auction(auction_id, &auction);
+					Self::settle_auction(auction_id, &auction)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Cancel the **auction `auction_id`**, which **must not** have **bids**
+		///
+		/// Note: **Only** the **seller** is **allowed** to cancel the auction
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `auction_id` - **ID** of the **auction**
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, auction_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let auction = <Auctions<T>>::get(auction_id).ok_or(Error::<T>::NotFound)?;
+			ensure!(auction.seller == who, Error::<T>::NoPermission);
+			ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+			// ! Writing
+
+			Self::remove_auction(auction_id, &auction);
+
+			Self::deposit_event(Event::AuctionClosed { auction_id });
+
+			Ok(())
+		}
+
+		/// **Detach** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+		/// from **this blockchain** to an **external blockchain**, as an **ERC-1155 token** (or an **ERC-721 token** if it is not a stack).
+		/// The **offchain worker** of the `detach` pallet then **emits** an **event** that **includes a signature**,
+		/// which the **owner** uses to **attach** the **Fragment Instance** on the **external blockchain**.
+		///
+		/// From now on, the **Fragment Instance** is **frozen**: it can no longer be **given**, **sold**, **burned** or **edited** (see `DetachedInstances`).
+		///
+		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to detach it, **only if** the **current permitted actions of the Fragment Instance**
+		/// include **FragmentPerms::TRANSFER** and **only if** it **never expires** and is **not** in an **auction**
+		///
+		/// # Arguments
+		///
+		/// * `origin` - **Origin** of the **extrinsic function**
+		/// * `class` - Fragment Definition ID of the Fragment Instance
+		/// * `edition` - Edition ID of the Fragment Instance
+		/// * `copy` - Copy ID of the Fragment Instance
+		/// * `target_chain` - **External Blockchain** to **attach** the **Fragment Instance** into
+		/// * `target_account` - **Public account address** on the **blockchain `target_chain`** that will **own** the **Fragment Instance**
+		#[pallet::weight(<T as Config>::WeightInfo::detach())]
+		pub fn detach(
+			origin: OriginFor<T>,
+			class: Hash128,
+			edition: Unit,
+			copy: Unit,
+			target_chain: SupportedChains,
+			target_account: Vec<u8>, // an eth address or so
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// also fails if the Fragment Instance is already detached or is in an auction
+			let item_data = Self::get_owned_fragment(&who, class, edition, copy)?;
+
+			ensure!(
+				(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+				Error::<T>::NoPermission
+			);
+
+			// it would expire here while it lives on the external blockchain
+			ensure!(item_data.expiring_at.is_none(), Error::<T>::ParamsNotValid);
+
+			let target_account: TargetAccount =
+				target_account.try_into().map_err(|_| Error::<T>::InvalidTargetAccount)?;
+
+			// ! Writing
+
+			pallet_detach::Pallet::<T>::add_detach_request(DetachRequest {
+				hash: DetachHash::Instance(
+					class,
+					edition,
+					copy,
+					item_data.amount.map_or(1, Unit::from),
+				),
+				target_chain,
+				target_account,
+			})?;
+
+			Self::remove_listing(class, edition, copy);
+			<Approvals<T>>::remove((class, edition, copy));
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// During the block initialization phase,
+		/// clear all the *Fragment instance*-related Storage Items of any information regarding
+		/// Fragment instances that have already expired (see `process_expirations`)
+		/// and remove the listings and settle the auctions that have already ended
+		/// (see `process_listing_expirations` and `process_auction_ends`)
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			Self::process_expirations(n)
+				.saturating_add(Self::process_listing_expirations(n))
+				.saturating_add(Self::process_auction_ends(n))
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// **Get** the **Account ID** of the Fragment Definition `class_hash`**
+	///
+	/// This Account ID is determinstically computed using the Fragment Definition `class_hash`
+	pub fn get_vault_id(class_hash: Hash128) -> T::AccountId {
+		let hash = blake2_256(&[&b"fragments-vault"[..], &class_hash].concat());
+		T::AccountId::decode(&mut &hash[..]).expect("T::AccountId should decode")
+	}
+
+	/// Get the **Account ID** of the **Fragment Instance whose Fragment Definition ID is `class_hash`,
+	/// whose Edition ID is `edition`** and whose Copy ID is `copy`**
+	///
+	/// This Account ID is determinstically computed using the Fragment Definition ID `class_hash`, the Edition ID `edition` and the Copy ID `copy`
+	pub fn get_fragment_account_id(class_hash: Hash128, edition: Unit, copy: Unit) -> T::AccountId {
+		let hash = blake2_256(
+			&[&b"fragments-account"[..], &class_hash, &edition.encode(), &copy.encode()].concat(),
+		);
+		T::AccountId::decode(&mut &hash[..]).expect("T::AccountId should decode")
+	}
+
+	/// **Get** the **balances** that can be **withdrawn** from the **vault** of the **Fragment Definition `fragment_hash`**,
+	/// or `None` if the **Fragment Definition** does **not exist**
+	pub fn get_vault_balances(fragment_hash: Hash128) -> Option<VaultBalances<T::AssetId>> {
+		let fragment_data = <Definitions<T>>::get(fragment_hash)?;
+		let vault = Self::get_vault_id(fragment_hash);
+
+		// the reserved minimum balance is not part of the free balance
+		let native = <pallet_balances::Pallet<T> as Currency<T::AccountId>>::free_balance(&vault)
+			.saturated_into();
+
+		let asset = fragment_data.metadata.currency.map(|currency| {
+			let balance =
+				<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::reducible_balance(
+					currency, &vault, true,
+				);
+			(currency, balance.saturated_into())
+		});
+
+		Some(VaultBalances { native, asset })
+	}
+
+	/// **Get** the **royalty** owed when a **Fragment Instance** of the **Fragment Definition `fragment_hash`** is **resold** for `price`,
+	/// as the **beneficiary** and the **amount** to **pay** them, or `None` if the **Fragment Definition** has **no royalty**
+	pub fn get_royalty(fragment_hash: Hash128, price: u128) -> Option<(T::AccountId, u128)> {
+		let royalty = <Definitions<T>>::get(fragment_hash)?.royalty?;
+		Some((royalty.beneficiary, royalty.percentage.mul_floor(price)))
+	}
+
+	/// **Transfer** `amount` units of `currency` (NOVA if `currency` is `None`) from `from` to `to`, keeping `from` alive
+	pub fn transfer_currency(
+		currency: Option<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if let Some(currency) = currency {
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				currency,
+				from,
+				to,
+				amount.saturated_into(),
+				true,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		} else {
+			<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+				from,
+				to,
+				amount.saturated_into(),
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		}
+		Ok(())
+	}
+
+	/// **Split** `price` between `seller` and the **beneficiary** of the **royalty** of the **Fragment Definition `fragment_hash`** (if any),
+	/// **paying** each of them with `pay`
+	fn pay_with_royalty(
+		fragment_hash: Hash128,
+		seller: &T::AccountId,
+		price: u128,
+		currency: Option<T::AssetId>,
+		mut pay: impl FnMut(&T::AccountId, u128) -> DispatchResult,
+	) -> DispatchResult {
+		let royalty = Self::get_royalty(fragment_hash, price);
+		let royalty_amount = royalty.as_ref().map_or(0, |(_, amount)| *amount);
+
+		pay(seller, price.saturating_sub(royalty_amount))?;
+
+		if let Some((beneficiary, amount)) = royalty {
+			if amount > 0 {
+				pay(&beneficiary, amount)?;
+
+				Self::deposit_event(Event::RoyaltyPaid { fragment_hash, beneficiary, currency, amount });
+			}
+		}
+
+		Ok(())
+	}
+
+	/// **Pay** `price` units of `currency` (NOVA if `currency` is `None`) from `buyer` to `seller` for a **secondary sale**
+	/// of a **Fragment Instance** of the **Fragment Definition `fragment_hash`**.
+	///
+	/// The **royalty** of the **Fragment Definition** (if any) is **deducted** from what `seller` receives and **paid** to its **beneficiary**.
+	///
+	/// Every **secondary-sale path** of this pallet **must pay** through this function
+	pub fn pay_secondary_sale(
+		fragment_hash: Hash128,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: u128,
+		currency: Option<T::AssetId>,
+	) -> DispatchResult {
+		Self::pay_with_royalty(fragment_hash, seller, price, currency, |to, amount| {
+			Self::transfer_currency(currency, buyer, to, amount)
+		})
+	}
+
+	/// Get the **Account ID** that **holds** the **bids** in a **fungible asset** of the **auction `auction_id`**
+	///
+	/// This Account ID is determinstically computed using the auction ID `auction_id`
+	pub fn get_auction_account_id(auction_id: u64) -> T::AccountId {
+		let hash = blake2_256(&[&b"fragments-auction"[..], &auction_id.encode()].concat());
+		T::AccountId::decode(&mut &hash[..]).expect("T::AccountId should decode")
+	}
+
+	/// **Get** the **price** of the **auction** `auction` in the **current block**: the **current price** of a **Dutch auction**,
+	/// or the **highest bid** (the **reserve** if there are none) of an **English auction**
+	pub fn get_auction_price(auction: &Auction<T::AssetId, T::AccountId, T::BlockNumber>) -> u128 {
+		match auction.kind {
+			AuctionKind::English { reserve, .. } =>
+				auction.highest_bid.as_ref().map_or(reserve, |(_, amount)| *amount).into(),
+			AuctionKind::Dutch { start_price, end_price } => {
+				let start_price: u128 = start_price.into();
+				let end_price: u128 = end_price.into();
+
+				let current_block_number = <frame_system::Pallet<T>>::block_number();
+				let elapsed: u64 = current_block_number.saturating_sub(auction.start).saturated_into();
+				let duration: u64 = auction.end.saturating_sub(auction.start).saturated_into();
+
+				let discount = Perquintill::from_rational(elapsed.min(duration), duration.max(1))
+					.mul_floor(start_price.saturating_sub(end_price));
+				start_price.saturating_sub(discount)
+			},
+		}
+	}
+
+	/// **Hold** the **bid** of `amount` units of `currency` (NOVA if `currency` is `None`) of `bidder` in the **auction `auction_id`**
+	fn hold_bid(
+		auction_id: u64,
+		currency: Option<T::AssetId>,
+		bidder: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if let Some(currency) = currency {
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				currency,
+				bidder,
+				&Self::get_auction_account_id(auction_id),
+				amount.saturated_into(),
+				true,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		} else {
+			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(
+				bidder,
+				amount.saturated_into(),
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		}
+		Ok(())
+	}
+
+	/// **Refund** the **bid** of `amount` units of `currency` (NOVA if `currency` is `None`) of `bidder` in the **auction `auction_id`**
+	fn refund_bid(
+		auction_id: u64,
+		currency: Option<T::AssetId>,
+		bidder: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if let Some(currency) = currency {
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				currency,
+				&Self::get_auction_account_id(auction_id),
+				bidder,
+				amount.saturated_into(),
+				false,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		} else {
+			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::unreserve(
+				bidder,
+				amount.saturated_into(),
+			);
+		}
+		Ok(())
+	}
+
+	/// **Pay** `amount` units of `currency` (NOVA if `currency` is `None`) to `to` out of the **held bid** of `bidder` in the **auction `auction_id`**
+	fn pay_from_bid(
+		auction_id: u64,
+		currency: Option<T::AssetId>,
+		bidder: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if let Some(currency) = currency {
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				currency,
+				&Self::get_auction_account_id(auction_id),
+				to,
+				amount.saturated_into(),
+				false,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		} else {
+			let missing =
+				<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::repatriate_reserved(
+					bidder,
+					to,
+					amount.saturated_into(),
+					BalanceStatus::Free,
+				)?;
+			frame_support::ensure!(missing.is_zero(), Error::<T>::InsufficientBalance);
+		}
+		Ok(())
+	}
+
+	/// **Remove** the **auction `auction_id`** from **every storage item** it is **referenced in**
+	fn remove_auction(auction_id: u64, auction: &Auction<T::AssetId, T::AccountId, T::BlockNumber>) {
+		<Auctions<T>>::remove(auction_id);
+		<AuctionEnds<T>>::remove(auction.end, auction_id);
+
+		if let AuctionItem::Instance { edition, copy } = auction.item {
+			<InstanceAuctions<T>>::remove((auction.fragment_hash, edition, copy));
+		}
+	}
+
+	/// **Cancel** the **auction** of the **Fragment Instance whose Fragment Definition ID is `class`,
+	/// whose Edition ID is `edition` and whose Copy ID is `copy`** (if any) and **refund** its **highest bid**
+	fn cancel_instance_auction(class: Hash128, edition: Unit, copy: Unit) {
+		let auction_id = match <InstanceAuctions<T>>::get((class, edition, copy)) {
+			Some(auction_id) => auction_id,
+			None => return,
+		};
+
+		if let Some(auction) = <Auctions<T>>::get(auction_id) {
+			Self::remove_auction(auction_id, &auction);
+
+			if let Some((bidder, amount)) = &auction.highest_bid {
+				if let Err(e) =
+					Self::refund_bid(auction_id, auction.currency, bidder, (*amount).into())
+				{
+					log::error!("fragments: could not refund auction {}: {:?}", auction_id, e);
+				}
+			}
+
+			Self::deposit_event(Event::AuctionClosed { auction_id });
+		} else {
+			<InstanceAuctions<T>>::remove((class, edition, copy));
+		}
+	}
+
+	/// **Settle** the **ended auction `auction_id`** if it has a **bid**, otherwise (or if the **settlement fails**)
+	/// **refund** the **bid** and **close** it without a sale
+	fn close_auction(auction_id: u64, auction: Auction<T::AssetId, T::AccountId, T::BlockNumber>) {
+		if let Some((bidder, amount)) = &auction.highest_bid {
+			match Self::settle_auction(auction_id, &auction) {
+				Ok(()) => return,
+				Err(e) => {
+					log::error!("fragments: could not settle auction {}: {:?}", auction_id, e);
+					if let Err(e) =
+						Self::refund_bid(auction_id, auction.currency, bidder, (*amount).into())
+					{
+						log::error!("fragments: could not refund auction {}: {:?}", auction_id, e);
+					}
+				},
+			}
+		}
+
+		Self::deposit_event(Event::AuctionClosed { auction_id });
+	}
+
+	/// **Give** what is **sold** in the **auction `auction_id`** to its **highest bidder** and **pay** the **seller** out of the **highest bid**.
+	///
+	/// Nothing is **written** if the **settlement fails**
+	#[transactional]
+	fn settle_auction(
+		auction_id: u64,
+		auction: &Auction<T::AssetId, T::AccountId, T::BlockNumber>,
+	) -> DispatchResult {
+		use frame_support::ensure;
+
+		let (winner, price) = auction.highest_bid.clone().ok_or(Error::<T>::NotFound)?;
+		let price: u128 = price.into();
+		let class = auction.fragment_hash;
+
+		match auction.item {
+			AuctionItem::Edition { amount } => {
+				Self::mint_fragments(
+					&winner,
+					&class,
+					None, // PublishingData (optional)
+					&FragmentBuyOptions::Quantity(1),
+					1,
+					<frame_system::Pallet<T>>::block_number(),
+					None, // Block Number that the Fragment Instance will expire at (optional)
+					amount,
+				)?;
+
+				Self::pay_from_bid(auction_id, auction.currency, &winner, &Self::get_vault_id(class), price)?;
+			},
+			AuctionItem::Instance { edition, copy } => {
+				// the seller must still own the Fragment Instance and be allowed to transfer it
+				let item_data = Self::get_owned_fragment(&auction.seller, class, edition, copy)?;
+				ensure!(
+					(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+					Error::<T>::NoPermission
+				);
+
+				Self::pay_with_royalty(class, &auction.seller, price, auction.currency, |to, amount| {
+					Self::pay_from_bid(auction_id, auction.currency, &winner, to, amount)
+				})?;
+
+				Self::transfer_fragment(&auction.seller, &winner, class, edition, copy);
+			},
+		}
+
+		Self::deposit_event(Event::AuctionSettled { auction_id, winner, price });
+
+		Ok(())
+	}
+
+	/// **Number of editions** of the **Fragment Definition `fragment_hash`** that **count** towards its `max_supply`
+	///
+	/// If the **Fragment Definition** has `burns_free_supply` set, the **burned editions** are **not counted**
+	pub fn editions_in_supply(
+		fragment_hash: &Hash128,
+		fragment_data: &FragmentDefinition<T::AssetId, T::AccountId, T::BlockNumber>,
+	) -> Unit {
+		let existing: Unit = <EditionsCount<T>>::get(fragment_hash).unwrap_or(Compact(0)).into();
+		if fragment_data.burns_free_supply {
+			let burned: Unit =
+				<BurnedEditionsCount<T>>::get(fragment_hash).unwrap_or(Compact(0)).into();
+			existing.saturating_sub(burned)
+		} else {
+			existing
+		}
+	}
+
+	/// **Get** the **owner** of the **Proto-Fragment `proto_hash`**
+	pub fn get_proto_owner(proto_hash: &Hash256) -> Result<T::AccountId, DispatchError> {
+		let proto: Proto<T::AccountId, T::BlockNumber> =
+			<Protos<T>>::get(proto_hash).ok_or(Error::<T>::ProtoNotFound)?;
+
+		match proto.owner {
+			ProtoOwner::User(owner) => Ok(owner),
+			_ => Err(Error::<T>::ProtoOwnerNotFound.into()),
+		}
+	}
+
+	/// **Get** the **Fragment Definition `fragment_hash`**, **ensuring** that `who` **owns** its **Proto-Fragment**
+	pub fn ensure_proto_owner(
+		who: &T::AccountId,
+		fragment_hash: &Hash128,
+	) -> Result<FragmentDefinition<T::AssetId, T::AccountId, T::BlockNumber>, DispatchError> {
+		use frame_support::ensure;
+
+		let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(
+			*who == Self::get_proto_owner(&fragment_data.proto_hash)?,
+			Error::<T>::NoPermission
+		);
+
+		Ok(fragment_data)
+	}
+
+	/// **Get** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**,
+	/// **ensuring** that it is **owned** by `who`, that it **does not expire** in the **current block**, that it is **not detached**
+	/// and that it is **not** in an **auction**
+	pub fn get_owned_fragment(
+		who: &T::AccountId,
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+	) -> Result<FragmentInstance<T::BlockNumber>, DispatchError> {
+		use frame_support::ensure;
+
+		let item_data = Self::get_live_fragment(class, edition, copy)?;
+
+		ensure!(
+			<Inventory<T>>::contains_key((who.clone(), class, edition, copy)),
+			Error::<T>::NoPermission
+		);
+
+		// detached Fragment Instances are frozen
+		ensure!(
+			!<DetachedInstances<T>>::contains_key((class, edition, copy)),
+			Error::<T>::Detached
+		);
+
+		// so are the Fragment Instances in an auction, the winner expects to receive them
+		ensure!(
+			!<InstanceAuctions<T>>::contains_key((class, edition, copy)),
+			Error::<T>::InAuction
+		);
+
+		Ok(item_data)
+	}
+
+	/// **Get** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**,
+	/// **only if** it **hasn't expired**
+	pub fn get_live_fragment(
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+	) -> Result<FragmentInstance<T::BlockNumber>, DispatchError> {
+		use frame_support::ensure;
+
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let item_data = <Fragments<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+
+		// no go if will expire this block
+		if let Some(item_expiration) = item_data.expiring_at {
+			ensure!(current_block_number < item_expiration, Error::<T>::NotFound);
+		}
+
+		Ok(item_data)
+	}
+
+	/// **Get** the **index** of the **Metadata Key `metadata_key`** (see `MetaKeys` of the pallet `protos`),
+	/// **adding** the **Metadata Key** if it **doesn't exist yet**
+	fn intern_metadata_key(metadata_key: &MetadataKey) -> u64 {
+		match <MetaKeys<T>>::get(metadata_key) {
+			Some(index) => index,
+			None => {
+				let next_index = <MetaKeysIndex<T>>::get() + 1;
+				<MetaKeys<T>>::insert(metadata_key, next_index);
+				<MetaKeysIndex<T>>::put(next_index);
+				next_index
+			},
+		}
+	}
+
+	/// Create **Fragment instance(s)** from the **Fragment Definition `fragment_hash`** on behalf of `who` and
+	/// **assign their ownership** to **`to`** (see the `mint` and `mint_to` extrinsics)
+	pub fn do_mint(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		fragment_hash: Hash128,
+		options: FragmentBuyOptions,
+		amount: Option<Unit>,
+	) -> DispatchResult {
+		use frame_support::ensure;
+
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let quantity = match options {
+			// Number of fragment instances to mint
+			FragmentBuyOptions::Quantity(amount) => u64::from(amount),
+			_ => 1u64,
+		};
+
+		let minter_terms = Self::ensure_can_mint(who, fragment_hash, quantity)?;
+
+		// ! Writing
+
+		Self::mint_fragments(
+			to,
+			&fragment_hash,
+			None, // PublishingData (optional)
+			&options,
+			quantity,
+			current_block_number,
+			None, // Block Number the Fragment(s) expire at (optional)
+			amount.map(|x| Compact(x)),
+		)?;
+
+		Self::record_mint(who, fragment_hash, minter_terms, quantity);
+
+		Ok(())
+	}
+
+	/// **Ensure** that `who` is **allowed** to **mint** `quantity` **Fragment Instances** of the **Fragment Definition `fragment_hash`**,
+	/// i.e that `who` is the **owner** of its **Proto-Fragment** or one of its **minters** whose **terms** allow it (see `Minters`)
+	///
+	/// Returns the **terms** of the **minter** **after** the **mint**, or `None` if `who` is the **owner** of the **Proto-Fragment**
+	fn ensure_can_mint(
+		who: &T::AccountId,
+		fragment_hash: Hash128,
+		quantity: Unit,
+	) -> Result<Option<MinterTerms<T::BlockNumber>>, DispatchError> {
+		use frame_support::ensure;
+
+		let proto_hash =
+			<Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?.proto_hash; // Get `proto_hash` from `fragment_hash`
+
+		let minter_terms = if *who == Self::get_proto_owner(&proto_hash)? {
+			None
+		} else {
+			let mut terms =
+				<Minters<T>>::get(fragment_hash, who).ok_or(Error::<T>::NoPermission)?;
+
+			if let Some(expiration) = terms.expiration {
+				let current_block_number = <frame_system::Pallet<T>>::block_number();
+				ensure!(current_block_number < expiration, Error::<T>::Expired);
+			}
+
+			if let Some(quota) = terms.quota {
+				let quota = Unit::from(quota);
+				ensure!(quantity <= quota, Error::<T>::MinterQuotaExceeded);
+				terms.quota = Some(Compact(quota - quantity));
+			}
+
+			Some(terms)
+		};
+
+		// TO REVIEW
+		ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached); // Ensure `proto_hash` isn't detached
+
+		Ok(minter_terms)
+	}
+
+	/// **Store** the **terms** `minter_terms` of the **minter** `who` after it **minted** `quantity` **Fragment Instances**
+	/// of the **Fragment Definition `fragment_hash`** (see `ensure_can_mint`). Nothing happens if `who` is the **owner** of the **Proto-Fragment**.
+	fn record_mint(
+		who: &T::AccountId,
+		fragment_hash: Hash128,
+		minter_terms: Option<MinterTerms<T::BlockNumber>>,
+		quantity: Unit,
+	) {
+		if let Some(terms) = minter_terms {
+			<Minters<T>>::insert(fragment_hash, who, terms);
+
+			Self::deposit_event(Event::MinterUsed { fragment_hash, minter: who.clone(), quantity });
+		}
+	}
+
+	/// Create **Fragment instance(s)** from the **Fragment Definition `fragment_hash`** that is **on sale**, for a fee paid by `who`, and
+	/// **assign their ownership** to **`to`** (see the `buy` and `buy_for` extrinsics)
+	///
+	/// The **allowlist** and the **maximum number of Fragment Instances per account** of the **sale** apply to **`to`**
+	pub fn do_buy(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		fragment_hash: Hash128,
+		options: FragmentBuyOptions,
+		proof: Option<Vec<Hash256>>,
+	) -> DispatchResult {
+		use frame_support::ensure;
+
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let sale = <Publishing<T>>::get(&fragment_hash).ok_or(Error::<T>::NotFound)?; // if Fragment Definition `fragment_hash` is not published (i.e on sale), you cannot buy it
+		if let Some(expiration) = sale.expiration {
+			ensure!(current_block_number < expiration, Error::<T>::Expired);
+		}
+
+		if let Some(units_left) = sale.units_left {
+			ensure!(units_left > Compact(0), Error::<T>::SoldOut);
+		}
+
+		let (_, phase) =
+			Self::get_sale_phase(fragment_hash, &sale).ok_or(Error::<T>::NotStarted)?;
+
+		match phase.allowlist {
+			Some(Allowlist::MerkleRoot(root)) => {
+				let proof = proof.ok_or(Error::<T>::NotAllowed)?;
+				ensure!(proof.len() <= MAX_PROOF_LEN, Error::<T>::ParamsNotValid);
+				ensure!(Self::verify_allowlist_proof(root, to, &proof), Error::<T>::NotAllowed);
+			},
+			Some(Allowlist::Accounts) => ensure!(
+				<SaleAllowlists<T>>::contains_key(fragment_hash, to),
+				Error::<T>::NotAllowed
+			),
+			None => (),
+		}
+
+		let quantity = match options {
+			FragmentBuyOptions::Quantity(amount) => u64::from(amount),
+			_ => 1u64,
+		};
+
+		// purchases of a previous sale are not counted
+		let purchased = <Purchases<T>>::get(fragment_hash, to)
+			.filter(|(published_at, _)| *published_at == sale.published_at)
+			.map_or(0, |(_, purchased)| Unit::from(purchased));
+		let purchased = purchased.checked_add(quantity).ok_or(Error::<T>::ParamsNotValid)?;
+		if let Some(max_per_account) = phase.max_per_account {
+			ensure!(purchased <= max_per_account.into(), Error::<T>::MaxPerAccountReached);
+		}
+
+		let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
+
+		let vault = &Self::get_vault_id(fragment_hash); // Get the Vault Account ID of `fragment_hash`
+
+		let price = Self::get_sale_price(&sale, &phase, quantity); // sum of the prices of the `quantity` next Fragment Instances
+
+		if let Some(currency) = fragment_data.metadata.currency {
+			let minimum_balance_needed_to_exist =
+				<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(currency);
+			let price_balance: <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::Balance =
+				price.saturated_into();
+
+			ensure!(
+				<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::balance(currency, who) >=
+					price_balance.saturating_add(minimum_balance_needed_to_exist),
+				Error::<T>::InsufficientBalance
+			);
+		} else {
+			let minimum_balance_needed_to_exist =
+				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::minimum_balance();
+			let price_balance: <pallet_balances::Pallet<T> as Currency<T::AccountId>>::Balance =
+				price.saturated_into();
+
+			ensure!(
+				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::free_balance(who) >=
+					price_balance.saturating_add(minimum_balance_needed_to_exist),
+				Error::<T>::InsufficientBalance
+			);
+		}
+
+		// ! Writing
+
+		Self::mint_fragments(
+			to,
+			&fragment_hash,
+			Some(&sale), // PublishingData (optional)
+			&options,
+			quantity,
+			current_block_number,
+			None, // Block Number that the Fragment Instance will expire at (optional)
+			sale.amount,
+		)?;
+
+		// a later phase of the sale may have a `max_per_account`, so purchases are always counted
+		<Purchases<T>>::insert(fragment_hash, to, (sale.published_at, Compact(purchased)));
+
+		if let Some(currency) = fragment_data.metadata.currency {
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				// transfer `price` units of `currency` from `who` to `vault`
+				currency,
+				who,
+				&vault,
+				price.saturated_into(),
+				true, // investigate ???
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		} else {
+			<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+				// transfer `price` units of NOVA from `who` to `vault`
+				who,
+				&vault,
+				price.saturated_into(),
+				ExistenceRequirement::KeepAlive, // investigate ???
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+		}
+
+		Ok(())
+	}
+
+	/// Give the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+	/// of `owner` to `to` (see the `give` extrinsic)
+	pub fn do_give(
+		owner: &T::AccountId,
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+		to: T::AccountId,
+		new_permissions: Option<FragmentPerms>,
+		expiration: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		use frame_support::ensure;
+
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let mut item_data =
+			<Fragments<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+
+		// no go if will expire this block
+		if let Some(item_expiration) = item_data.expiring_at {
+			ensure!(current_block_number < item_expiration, Error::<T>::NotFound);
+		}
+
+		if let Some(expiration) = expiration {
+			ensure!(current_block_number < expiration, Error::<T>::ParamsNotValid);
+		}
+
+		// Only the owner of this fragment can transfer it (or an approved account on their behalf, see `transfer_from`)
+		ensure!(
+			<Inventory<T>>::contains_key((owner.clone(), class, edition, copy)),
+			Error::<T>::NoPermission
+		);
+
+		// detached Fragment Instances are frozen
+		ensure!(
+			!<DetachedInstances<T>>::contains_key((class, edition, copy)),
+			Error::<T>::Detached
+		);
+
+		// so are the Fragment Instances in an auction
+		ensure!(
+			!<InstanceAuctions<T>>::contains_key((class, edition, copy)),
+			Error::<T>::InAuction
+		);
+
+		// first of all make sure the item can be transferred
+		ensure!(
+			(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
+			Error::<T>::NoPermission
+		);
+
+		let perms = if let Some(new_perms) = new_permissions {
+			// ensure we only allow more restrictive permissions
+			if (item_data.permissions & FragmentPerms::EDIT) != FragmentPerms::EDIT {
+				ensure!(
+					(new_perms & FragmentPerms::EDIT) != FragmentPerms::EDIT,
+					Error::<T>::NoPermission
+				);
+			}
+			if (item_data.permissions & FragmentPerms::COPY) != FragmentPerms::COPY {
+				ensure!(
+					(new_perms & FragmentPerms::COPY) != FragmentPerms::COPY,
+					Error::<T>::NoPermission
+				);
+			}
+			if (item_data.permissions & FragmentPerms::TRANSFER) != FragmentPerms::TRANSFER {
+				ensure!(
+					(new_perms & FragmentPerms::TRANSFER) != FragmentPerms::TRANSFER,
+					Error::<T>::NoPermission
+				);
+			}
+			if (item_data.permissions & FragmentPerms::BURN) != FragmentPerms::BURN {
+				ensure!(
+					(new_perms & FragmentPerms::BURN) != FragmentPerms::BURN,
+					Error::<T>::NoPermission
+				);
+			}
+			new_perms
+		} else {
+			item_data.permissions
+		};
+
+		// now we take two different paths if item can be copied or not
+		if (item_data.permissions & FragmentPerms::COPY) == FragmentPerms::COPY {
+			// we will copy the item to the new account
+			item_data.permissions = perms;
+
+			let copy: u64 =
+				<CopiesCount<T>>::get((class, edition)).ok_or(Error::<T>::NotFound)?.into();
+
+			let copy = copy + 1;
+
+			<CopiesCount<T>>::insert((class, edition), Compact(copy));
+
+			<Owners<T>>::insert((class, to.clone(), edition, copy), ());
+
+			<Inventory<T>>::insert((to.clone(), class, edition, copy), ());
+
+			<InstanceOwner<T>>::insert((class, edition, copy), to.clone());
+
+			// handle expiration
+			if let Some(expiring_at) = item_data.expiring_at {
+				let expiration = if let Some(expiration) = expiration {
+					if expiration < expiring_at {
+						item_data.expiring_at = Some(expiration);
+						expiration
+					} else {
+						expiring_at
+					}
+				} else {
+					expiring_at
+				};
+				<Expirations<T>>::insert((expiration, class, edition, copy), ());
+			} else if let Some(expiration) = expiration {
+				item_data.expiring_at = Some(expiration);
+				<Expirations<T>>::insert((expiration, class, edition, copy), ());
 			}
 
-			let price: u128 = sale.price.into();
+			<Fragments<T>>::insert((class, edition, copy), item_data);
 
-			let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
+			Self::deposit_event(Event::InventoryAdded {
+				account_id: to,
+				fragment_hash: class,
+				fragment_id: (edition, copy),
+			});
+		} else {
+			// we will remove from this account to give to new account
+			Self::transfer_fragment(owner, &to, class, edition, copy);
 
-			let vault = &Self::get_vault_id(fragment_hash); // Get the Vault Account ID of `fragment_hash`
+			// finally fix permissions that might have changed
+			<Fragments<T>>::mutate((class, edition, copy), |item_data| {
+				if let Some(item_data) = item_data {
+					item_data.permissions = perms;
+				}
+			});
+		}
 
-			let quantity = match options {
-				FragmentBuyOptions::Quantity(amount) => u64::from(amount),
-				_ => 1u64,
-			};
+		Ok(())
+	}
 
-			let price = price.saturating_mul(quantity as u128); // `price` = `price` * `quantity`
+	/// **Transfer** the **ownership** of the **Fragment Instance whose Fragment Definition ID is `class`,
+	/// whose Edition ID is `edition` and whose Copy ID is `copy`** from `from` to `to`.
+	///
+	/// The **listing** and the **approval** of the **Fragment Instance** (if any) are **removed**
+	pub fn transfer_fragment(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+	) {
+		<Owners<T>>::remove((class, from.clone(), edition, copy));
+		<Inventory<T>>::remove((from.clone(), class, edition, copy));
+		<Approvals<T>>::remove((class, edition, copy));
+
+		Self::remove_listing(class, edition, copy);
+
+		Self::deposit_event(Event::InventoryRemoved {
+			account_id: from.clone(),
+			fragment_hash: class,
+			fragment_id: (edition, copy),
+		});
 
-			if let Some(currency) = fragment_data.metadata.currency {
-				let minimum_balance_needed_to_exist =
-					<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(currency);
-				let price_balance: <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::Balance =
-					price.saturated_into();
+		<Owners<T>>::insert((class, to.clone(), edition, copy), ());
+		<Inventory<T>>::insert((to.clone(), class, edition, copy), ());
+		<InstanceOwner<T>>::insert((class, edition, copy), to.clone());
 
-				ensure!(
-					<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::balance(currency, &who)
-						>= price_balance + minimum_balance_needed_to_exist,
-					Error::<T>::InsufficientBalance
-				);
-			} else {
-				let minimum_balance_needed_to_exist =
-					<pallet_balances::Pallet<T> as Currency<T::AccountId>>::minimum_balance();
-				let price_balance: <pallet_balances::Pallet<T> as Currency<T::AccountId>>::Balance =
-					price.saturated_into();
+		Self::deposit_event(Event::InventoryAdded {
+			account_id: to.clone(),
+			fragment_hash: class,
+			fragment_id: (edition, copy),
+		});
+	}
 
-				ensure!(
-					<pallet_balances::Pallet<T> as Currency<T::AccountId>>::free_balance(&who)
-						>= price_balance + minimum_balance_needed_to_exist,
-					Error::<T>::InsufficientBalance
-				);
+	/// **Remove** the **listing** of the **Fragment Instance whose Fragment Definition ID is `class`,
+	/// whose Edition ID is `edition` and whose Copy ID is `copy`** (if any) and **emit** `Delisted`
+	pub fn remove_listing(class: Hash128, edition: Unit, copy: Unit) {
+		if let Some(listing) = <Listings<T>>::take((class, edition, copy)) {
+			if let Some(expiration) = listing.expiration {
+				<ListingExpirations<T>>::remove((expiration, class, edition, copy));
 			}
 
-			// ! Writing
+			Self::deposit_event(Event::Delisted { fragment_hash: class, fragment_id: (edition, copy) });
+		}
+	}
 
-			Self::mint_fragments(
-				&who,
-				&fragment_hash,
-				Some(&sale), // PublishingData (optional)
-				&options,
-				quantity,
-				current_block_number,
-				None, // Block Number that the Fragment Instance will expire at (optional)
-				sale.amount,
-			)?;
+	/// **Get** the **listings** of the **Fragment Instances** of the **Fragment Definition `fragment_hash`**
+	/// that are **currently on sale**, as **(Edition ID, Copy ID, Listing)**
+	pub fn get_listings(
+		fragment_hash: Hash128,
+	) -> Vec<(Unit, Unit, Listing<T::AssetId, T::AccountId, T::BlockNumber>)> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		<Listings<T>>::iter_prefix((fragment_hash,))
+			.filter(|(_, listing)| {
+				listing.expiration.map_or(true, |expiration| current_block_number < expiration)
+			})
+			.map(|((edition, copy), listing)| (edition, copy, listing))
+			.collect()
+	}
 
-			if let Some(currency) = fragment_data.metadata.currency {
-				<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
-					// transfer `price` units of `currency` from `who` to `vault`
-					currency,
-					&who,
-					&vault,
-					price.saturated_into(),
-					true, // investigate ???
-				)
-				.map_err(|_| Error::<T>::InsufficientBalance)?;
+	/// **Decode** the **hex encoded** `data` (the **field** `name` of the **query params**) into an **array of `N` bytes**
+	fn decode_hex<const N: usize>(data: &[u8], name: &str) -> Result<[u8; N], Vec<u8>> {
+		let bytes = hex::decode(data)
+			.map_err(|_| -> Vec<u8> { format!("Failed to decode {}", name).into() })?;
+		bytes.try_into().map_err(|_| format!("Invalid length of {}", name).into())
+	}
+
+	/// **Whether** `proof` **proves** that `account` is a **leaf** of the **Merkle tree** whose **root** is `root` (see `Allowlist::MerkleRoot`)
+	pub fn verify_allowlist_proof(
+		root: Hash256,
+		account: &T::AccountId,
+		proof: &[Hash256],
+	) -> bool {
+		let leaf = blake2_256(&account.encode());
+		let computed = proof.iter().fold(leaf, |node, sibling| {
+			if node <= *sibling {
+				blake2_256(&[node, *sibling].concat())
 			} else {
-				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
-					// transfer `price` units of NOVA from `who` to `vault`
-					&who,
-					&vault,
-					price.saturated_into(),
-					ExistenceRequirement::KeepAlive, // investigate ???
-				)
-				.map_err(|_| Error::<T>::InsufficientBalance)?;
+				blake2_256(&[*sibling, node].concat())
 			}
+		});
+		computed == root
+	}
 
-			Ok(())
+	/// **Get** the **current phase** of the **sale `sale`** of the **Fragment Definition `fragment_hash`** as **(index, phase)**,
+	/// where **index 0** is the **first phase** (whose **terms** are the ones of `sale`) and **index `i`** is the **`i`-th phase** in `SalePhases`.
+	///
+	/// Returns `None` if the sale has **not started** yet
+	pub fn get_sale_phase(
+		fragment_hash: Hash128,
+		sale: &PublishingData<T::BlockNumber>,
+	) -> Option<(u32, SalePhase<T::BlockNumber>)> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let starts_at = sale.starts_at.unwrap_or(sale.published_at);
+		if current_block_number < starts_at {
+			return None
 		}
 
-		/// Give the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`** to **`to`**.
-		///
-		/// If the **current permitted actions of the Fragment Instance** allows for it to be duplicated (i.e if it has the permission **FragmentPerms::COPY**),
-		/// then it is duplicated and the duplicate's ownership is assigned to `to`.
-		/// Otherwise, its ownernship is transferred from `origin` to `to`.
-		///
-		/// Note: **Only** the **Fragment Instance's owner** is **allowed** to give the Fragment Instance
-		///
-		/// # Arguments
-		///
-		/// * `origin` - **Origin** of the **extrinsic function**
-		/// * `class` - Fragment Definition ID of the Fragment Instance to give
-		/// * `edition` - Edition ID of the Fragment Insance to give
-		/// * `copy` - Copy ID of the Fragment instance to give
-		/// * `to` - **Account ID** to give the Fragment instance to
-		///
-		/// * `new_permissions` (*optional*) - The permitted set of actions (encapsulated in a `FragmentPerms` bitflag enum)
-		/// that the account that is given the Fragment instance can do with it.
-		///
-		/// Note: `new_permissions` must be a subset of the current `permissions` field of the Fragment Instance;
-		/// therefore, the `new_permissions` can only be more restrictive (than the current `permissions` field of the Fragment Instance),
-		/// never more permissive
-		///
-		/// * `expiration` (*optional*) - Block number that the duplicated Fragment Instance expires at.
-		/// If the Fragment Instance was not duplicated, this parameter is irrelevant.
-		#[pallet::weight(50_000)]
-		pub fn give(
-			origin: OriginFor<T>,
-			class: Hash128,
-			edition: Unit,
-			copy: Unit,
-			to: <T::Lookup as StaticLookup>::Source,
-			new_permissions: Option<FragmentPerms>,
-			expiration: Option<T::BlockNumber>,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		let first_phase = SalePhase {
+			starts_at,
+			price: sale.price,
+			allowlist: sale.allowlist,
+			max_per_account: sale.max_per_account,
+		};
 
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let current_phase = <SalePhases<T>>::get(fragment_hash)
+			.unwrap_or_default()
+			.into_iter()
+			.zip(1u32..)
+			.take_while(|(phase, _)| phase.starts_at <= current_block_number)
+			.last()
+			.map_or((0, first_phase), |(phase, index)| (index, phase));
 
-			let mut item_data =
-				<Fragments<T>>::get((class, edition, copy)).ok_or(Error::<T>::NotFound)?;
+		Some(current_phase)
+	}
 
-			// no go if will expire this block
-			if let Some(item_expiration) = item_data.expiring_at {
-				ensure!(current_block_number < item_expiration, Error::<T>::NotFound);
-			}
+	/// **Get** the **total price** of the **next `quantity` Fragment Instances** sold by the **sale `sale`** in the **current block**,
+	/// during its **phase `phase`**. Every Fragment Instance is **priced** on its **own** under the **pricing curve** of the sale,
+	/// so **buying** them **one by one** costs the **same** as **buying** them **at once**
+	pub fn get_sale_price(
+		sale: &PublishingData<T::BlockNumber>,
+		phase: &SalePhase<T::BlockNumber>,
+		quantity: Unit,
+	) -> u128 {
+		let price: u128 = phase.price.into();
+		let sold: Unit = sale.sold.into();
+
+		match sale.curve {
+			None => price.saturating_mul(quantity as u128), // `price` * `quantity`
+			Some(PriceCurve::Linear { increment }) => {
+				// sum of `price` + (`sold` + i) * `increment` for i in 0..`quantity`
+				let increment: u128 = increment.into();
+				let (sold, quantity) = (sold as u128, quantity as u128);
+				let steps = quantity
+					.saturating_mul(sold)
+					.saturating_add(quantity.saturating_mul(quantity.saturating_sub(1)) / 2);
+				price.saturating_mul(quantity).saturating_add(increment.saturating_mul(steps))
+			},
+			Some(PriceCurve::Exponential { rate }) => {
+				// sum of `price` * `growth`^(`sold` + i) for i in 0..`quantity`, where `growth` is 1 + `rate`,
+				// i.e the geometric series `price` * `growth`^`sold` * (`growth`^`quantity` - 1) / (`growth` - 1)
+				let rate =
+					FixedU128::saturating_from_rational(rate.deconstruct(), Permill::ACCURACY);
+				if rate.is_zero() {
+					return price.saturating_mul(quantity as u128)
+				}
+				let growth = FixedU128::one().saturating_add(rate);
+				let series = growth
+					.saturating_pow(quantity.saturated_into())
+					.saturating_sub(FixedU128::one())
+					.checked_div(&rate)
+					.unwrap_or_else(FixedU128::max_value);
+				growth
+					.saturating_pow(sold.saturated_into())
+					.saturating_mul(series)
+					.saturating_mul_int(price)
+			},
+			Some(PriceCurve::Decay { floor, duration }) => {
+				let floor: u128 = floor.into();
 
-			if let Some(expiration) = expiration {
-				ensure!(current_block_number < expiration, Error::<T>::ParamsNotValid);
-			}
+				let current_block_number = <frame_system::Pallet<T>>::block_number();
+				let elapsed: u64 =
+					current_block_number.saturating_sub(phase.starts_at).saturated_into();
+				let duration: u64 = duration.saturated_into();
 
-			// Only the owner of this fragment can transfer it
-			let ids = <Inventory<T>>::get(who.clone(), class).ok_or(Error::<T>::NotFound)?;
+				let discount = Perquintill::from_rational(elapsed.min(duration), duration.max(1))
+					.mul_floor(price.saturating_sub(floor));
+				price.saturating_sub(discount).saturating_mul(quantity as u128)
+			},
+		}
+	}
 
-			ensure!(ids.contains(&(Compact(edition), Compact(copy))), Error::<T>::NoPermission);
+	/// **Quote** the **total price** of **`quantity` Fragment Instances** of the **Fragment Definition `fragment_hash`**
+	/// if they were **bought** in the **current block** (see `get_sale_price`).
+	///
+	/// Returns `None` if the sale is **not open**, has **not started** yet or has **less than `quantity` Fragment Instances left**
+	pub fn quote_price(fragment_hash: Hash128, quantity: Unit) -> Option<u128> {
+		let sale = Self::get_open_sale(fragment_hash)?;
+		if sale.units_left.map_or(false, |units_left| quantity > units_left.into()) {
+			return None
+		}
+		let (_, phase) = Self::get_sale_phase(fragment_hash, &sale)?;
 
-			// first of all make sure the item can be transferred
-			ensure!(
-				(item_data.permissions & FragmentPerms::TRANSFER) == FragmentPerms::TRANSFER,
-				Error::<T>::NoPermission
-			);
+		Some(Self::get_sale_price(&sale, &phase, quantity))
+	}
 
-			let perms = if let Some(new_perms) = new_permissions {
-				// ensure we only allow more restrictive permissions
-				if (item_data.permissions & FragmentPerms::EDIT) != FragmentPerms::EDIT {
-					ensure!(
-						(new_perms & FragmentPerms::EDIT) != FragmentPerms::EDIT,
-						Error::<T>::NoPermission
-					);
-				}
-				if (item_data.permissions & FragmentPerms::COPY) != FragmentPerms::COPY {
-					ensure!(
-						(new_perms & FragmentPerms::COPY) != FragmentPerms::COPY,
-						Error::<T>::NoPermission
-					);
-				}
-				if (item_data.permissions & FragmentPerms::TRANSFER) != FragmentPerms::TRANSFER {
-					ensure!(
-						(new_perms & FragmentPerms::TRANSFER) != FragmentPerms::TRANSFER,
-						Error::<T>::NoPermission
-					);
-				}
-				new_perms
-			} else {
-				item_data.permissions
-			};
+	/// **Get** the **sale** of the **Fragment Definition `fragment_hash`** if it is **open**,
+	/// i.e if it has **not expired** and is **not sold out**. The sale may **not** have **started** yet (see `get_sale_phase`)
+	pub fn get_open_sale(fragment_hash: Hash128) -> Option<PublishingData<T::BlockNumber>> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
 
-			let to = T::Lookup::lookup(to)?;
+		<Publishing<T>>::get(fragment_hash).filter(|sale| {
+			sale.expiration.map_or(true, |expiration| current_block_number < expiration) &&
+				sale.units_left.map_or(true, |units_left| units_left > Compact(0))
+		})
+	}
+
+	/// **Metadata** under the **keys `metadata_keys`** as a **JSON object** that maps **every key** to the **hex encoded hash** of its **metadata**,
+	/// or `null` if it has **none**. `get_data_hash` **gets** the **hash** of the **metadata** from the **index** of its **Metadata Key**
+	fn metadata_json(
+		metadata_keys: &[Vec<u8>],
+		get_data_hash: impl Fn(Compact<u64>) -> Option<Hash256>,
+	) -> Value {
+		let metadata = metadata_keys
+			.iter()
+			.filter_map(|metadata_key| {
+				let key = String::from_utf8(metadata_key.clone()).ok()?;
+				let value = MetadataKey::try_from(metadata_key.clone())
+					.ok()
+					.and_then(<MetaKeys<T>>::get)
+					.and_then(|index| get_data_hash(Compact(index)))
+					.map_or(Value::Null, |data_hash| Value::String(hex::encode(data_hash)));
+				Some((key, value))
+			})
+			.collect();
+
+		Value::Object(metadata)
+	}
+
+	/// **Allowlist** `allowlist` of a **sale** as **JSON**: `null`, `"accounts"` or `{"merkle_root": <hex>}`
+	fn allowlist_json(allowlist: Option<Allowlist>) -> Value {
+		match allowlist {
+			Some(Allowlist::MerkleRoot(root)) => json!({ "merkle_root": hex::encode(root) }),
+			Some(Allowlist::Accounts) => json!("accounts"),
+			None => Value::Null,
+		}
+	}
 
-			// now we take two different paths if item can be copied or not
-			if (item_data.permissions & FragmentPerms::COPY) == FragmentPerms::COPY {
-				// we will copy the item to the new account
-				item_data.permissions = perms;
+	/// **Query** and **Return** **Fragment Definition(s)** based on **`params`**. The **return type** is a **JSON string**
+	/// of an **array** (so that the **order** is **kept**).
+	///
+	/// Account IDs and currencies are **hex encoded SCALE**, and prices are **strings** since they do not fit in a JSON number.
+	///
+	/// # Arguments
+	///
+	/// * `params` - A ***GetDefinitionsParams* struct**
+	pub fn get_definitions(
+		params: GetDefinitionsParams<T::AccountId, Vec<u8>>,
+	) -> Result<Vec<u8>, Vec<u8>> {
+		let definition_hashes: Vec<Hash128> = if let Some(proto_hash) = params.proto_hash {
+			let proto_hash: Hash256 = Self::decode_hex(&proto_hash, "proto_hash")?;
+			<Proto2Fragments<T>>::iter_key_prefix(proto_hash).collect()
+		} else {
+			// Notice this wastes time and memory and needs a better implementation
+			<Definitions<T>>::iter_keys().collect()
+		};
 
-				let copy: u64 =
-					<CopiesCount<T>>::get((class, edition)).ok_or(Error::<T>::NotFound)?.into();
+		let mut definitions: Vec<(
+			Hash128,
+			FragmentDefinition<T::AssetId, T::AccountId, T::BlockNumber>,
+		)> = definition_hashes
+			.into_iter()
+			.filter_map(|definition_hash| {
+				<Definitions<T>>::get(definition_hash)
+					.map(|definition| (definition_hash, definition))
+			})
+			.filter(|(_, definition)| {
+				params.creator.as_ref().map_or(true, |creator| &definition.creator == creator)
+			})
+			.collect();
+		definitions
+			.sort_by_key(|(definition_hash, definition)| (definition.created_at, *definition_hash));
+		if params.desc {
+			definitions.reverse();
+		}
 
-				let copy = copy + 1;
+		let list: Vec<Value> = definitions
+			.into_iter()
+			.filter_map(|(definition_hash, definition)| {
+				let sale = Self::get_open_sale(definition_hash);
+				if params.on_sale && sale.is_none() {
+					return None
+				}
 
-				<CopiesCount<T>>::insert((class, edition), Compact(copy));
+				let sale = sale.map(|sale| {
+					let phases: Vec<Value> = <SalePhases<T>>::get(definition_hash)
+						.unwrap_or_default()
+						.iter()
+						.map(|phase| {
+							json!({
+								"starts_at": phase.starts_at.saturated_into::<u64>(),
+								"price": u128::from(phase.price).to_string(),
+								"allowlist": Self::allowlist_json(phase.allowlist),
+								"max_per_account": phase.max_per_account.map(Unit::from),
+							})
+						})
+						.collect();
+
+					json!({
+						"price": u128::from(sale.price).to_string(),
+						"units_left": sale.units_left.map(Unit::from),
+						"starts_at": sale.starts_at.unwrap_or(sale.published_at).saturated_into::<u64>(),
+						"expiration": sale.expiration.map(|expiration| expiration.saturated_into::<u64>()),
+						"amount": sale.amount.map(Unit::from),
+						"allowlist": Self::allowlist_json(sale.allowlist),
+						"max_per_account": sale.max_per_account.map(Unit::from),
+						"phases": phases,
+						"current_phase": Self::get_sale_phase(definition_hash, &sale).map(|(index, _)| index),
+						"curve": sale.curve.as_ref().map(|curve| match curve {
+							PriceCurve::Linear { increment } =>
+								json!({ "linear": { "increment": u128::from(*increment).to_string() } }),
+							PriceCurve::Exponential { rate } =>
+								json!({ "exponential": { "rate": rate.deconstruct() } }),
+							PriceCurve::Decay { floor, duration } => json!({
+								"decay": {
+									"floor": u128::from(*floor).to_string(),
+									"duration": (*duration).saturated_into::<u64>(),
+								}
+							}),
+						}),
+						"sold": Unit::from(sale.sold),
+					})
+				});
 
-				<Owners<T>>::append(class, to.clone(), (Compact(edition), Compact(copy)));
+				Some(json!({
+					"id": hex::encode(definition_hash),
+					"proto_hash": hex::encode(definition.proto_hash),
+					"name": String::from_utf8_lossy(&definition.metadata.name),
+					"currency": definition.metadata.currency.map(|currency| hex::encode(currency.encode())),
+					"creator": hex::encode(definition.creator.encode()),
+					"created_at": definition.created_at.saturated_into::<u64>(),
+					"max_supply": definition.max_supply.map(Unit::from),
+					"editions": <EditionsCount<T>>::get(definition_hash).map_or(0, Unit::from),
+					"revocable": definition.revocable,
+					"sale": sale,
+					"metadata": Self::metadata_json(&params.metadata_keys, |metadata_key_index| {
+						<DefinitionMetadata<T>>::get(definition_hash, metadata_key_index)
+					}),
+				}))
+			})
+			.skip(params.from as usize)
+			.take(params.limit as usize)
+			.collect();
+
+		Ok(Value::Array(list).to_string().into_bytes())
+	}
 
-				<Inventory<T>>::append(to.clone(), class, (Compact(edition), Compact(copy)));
+	/// **Query** and **Return** **Fragment Instance(s)** based on **`params`**, either the ones of a **Fragment Definition**,
+	/// the **inventory** of an **account** or both. The **return type** is a **JSON string** of an **array**.
+	///
+	/// **Expired Fragment Instances** are **not returned**, even if they were not removed yet.
+	///
+	/// # Arguments
+	///
+	/// * `params` - A ***GetInstancesParams* struct**
+	pub fn get_instances(
+		params: GetInstancesParams<T::AccountId, Vec<u8>>,
+	) -> Result<Vec<u8>, Vec<u8>> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let definition_hash: Option<Hash128> = params
+			.definition_hash
+			.map(|definition_hash| Self::decode_hex(&definition_hash, "definition_hash"))
+			.transpose()?;
+
+		let instances: Box<dyn Iterator<Item = (Hash128, Unit, Unit)>> =
+			match (params.owner, definition_hash) {
+				(Some(owner), Some(class)) => Box::new(
+					<Inventory<T>>::iter_key_prefix((owner, class))
+						.map(move |(edition, copy)| (class, edition, copy)),
+				),
+				(Some(owner), None) => Box::new(<Inventory<T>>::iter_key_prefix((owner,))),
+				(None, Some(class)) => Box::new(
+					<Fragments<T>>::iter_key_prefix((class,))
+						.map(move |(edition, copy)| (class, edition, copy)),
+				),
+				(None, None) =>
+					return Err("Either owner or definition_hash must be provided".into()),
+			};
 
-				// handle expiration
-				if let Some(expiring_at) = item_data.expiring_at {
-					let expiration = if let Some(expiration) = expiration {
-						if expiration < expiring_at {
-							item_data.expiring_at = Some(expiration);
-							expiration
-						} else {
-							expiring_at
-						}
-					} else {
-						expiring_at
-					};
-					<Expirations<T>>::append(expiration, (class, Compact(edition), Compact(copy)));
-				} else if let Some(expiration) = expiration {
-					item_data.expiring_at = Some(expiration);
-					<Expirations<T>>::append(expiration, (class, Compact(edition), Compact(copy)));
+		let list: Vec<Value> = instances
+			.filter_map(|(class, edition, copy)| {
+				let instance = <Fragments<T>>::get((class, edition, copy))?;
+				if instance
+					.expiring_at
+					.map_or(false, |expiring_at| expiring_at <= current_block_number)
+				{
+					return None
 				}
 
-				<Fragments<T>>::insert((class, edition, copy), item_data);
+				let owner = <InstanceOwner<T>>::get((class, edition, copy));
+				let metadata = <InstanceMetadata<T>>::get((class, edition, copy));
+
+				Some(json!({
+					"definition_hash": hex::encode(class),
+					"edition": edition,
+					"copy": copy,
+					"owner": owner.map(|owner| hex::encode(owner.encode())),
+					"permissions": instance.permissions.bits(),
+					"created_at": instance.created_at.saturated_into::<u64>(),
+					"custom_data": instance.custom_data.map(hex::encode),
+					"expiring_at": instance.expiring_at.map(|expiring_at| expiring_at.saturated_into::<u64>()),
+					"amount": instance.amount.map(Unit::from),
+					"metadata": Self::metadata_json(&params.metadata_keys, |metadata_key_index| {
+						metadata.get(&metadata_key_index).copied()
+					}),
+				}))
+			})
+			.skip(params.from as usize)
+			.take(params.limit as usize)
+			.collect();
+
+		Ok(Value::Array(list).to_string().into_bytes())
+	}
 
-				Self::deposit_event(Event::InventoryAdded {
-					account_id: to,
-					fragment_hash: class,
-					fragment_id: (edition, copy),
-				});
-			} else {
-				// we will remove from this account to give to new account
-				<Owners<T>>::mutate(class, who.clone(), |ids| {
-					if let Some(ids) = ids {
-						ids.retain(|cid| *cid != (Compact(edition), Compact(copy)))
-					}
-				});
+	/// **Query** and **Return** the **owners** of the **Fragment Instances** of a **Fragment Definition**,
+	/// each with the **(Edition ID, Copy ID)** of the **Fragment Instances** they own. The **return type** is a **JSON string** of an **array**.
+	///
+	/// `from` and `limit` **count owners**, not Fragment Instances.
+	///
+	/// # Arguments
+	///
+	/// * `params` - A ***GetOwnersParams* struct**
+	pub fn get_owners(params: GetOwnersParams<Vec<u8>>) -> Result<Vec<u8>, Vec<u8>> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let class: Hash128 = Self::decode_hex(&params.definition_hash, "definition_hash")?;
+		let end = params.from.saturating_add(params.limit);
+
+		let mut owners: Vec<(T::AccountId, Vec<Value>)> = Vec::new();
+		// the Fragment Instances of an owner are next to each other, since the owner is the second key of `Owners`
+		for (owner, edition, copy) in <Owners<T>>::iter_key_prefix((class,)) {
+			let expired = <Fragments<T>>::get((class, edition, copy)).map_or(true, |instance| {
+				instance
+					.expiring_at
+					.map_or(false, |expiring_at| expiring_at <= current_block_number)
+			});
+			if expired {
+				continue
+			}
 
-				<Inventory<T>>::mutate(who.clone(), class, |ids| {
-					if let Some(ids) = ids {
-						ids.retain(|cid| *cid != (Compact(edition), Compact(copy)))
+			match owners.last_mut() {
+				Some((last, instances)) if *last == owner => instances.push(json!([edition, copy])),
+				_ => {
+					if owners.len() as u64 >= end {
+						break
 					}
-				});
+					owners.push((owner, vec![json!([edition, copy])]));
+				},
+			}
+		}
 
-				Self::deposit_event(Event::InventoryRemoved {
-					account_id: who.clone(),
-					fragment_hash: class,
-					fragment_id: (edition, copy),
-				});
+		let list: Vec<Value> = owners
+			.into_iter()
+			.skip(params.from as usize)
+			.map(|(owner, instances)| {
+				json!({
+					"owner": hex::encode(owner.encode()),
+					"instances": instances,
+				})
+			})
+			.collect();
+
+		Ok(Value::Array(list).to_string().into_bytes())
+	}
 
-				<Owners<T>>::append(class, to.clone(), (Compact(edition), Compact(copy)));
+	/// **Remove** the **Fragment Instances** that **expire at or before** the **block `now`** from **every storage item** they are **referenced in**,
+	/// and **emit** `Expired` for each of them.
+	///
+	/// At most `MaxExpirationsPerBlock` **Fragment Instances** and `MaxExpirationsPerBlock` **blocks** of `Expirations` are **processed**,
+	/// `NextExpiration` **remembers** where to **resume** so that the **remaining ones** are **removed** in the **next blocks**.
+	///
+	/// Returns the **weight** that was **consumed**
+	pub fn process_expirations(now: T::BlockNumber) -> Weight {
+		let max = T::MaxExpirationsPerBlock::get();
 
-				<Inventory<T>>::append(to.clone(), class, (Compact(edition), Compact(copy)));
+		let mut block = <NextExpiration<T>>::get();
+		let mut expired: u32 = 0;
+		let mut visited: u32 = 0;
 
-				Self::deposit_event(Event::InventoryAdded {
-					account_id: to,
-					fragment_hash: class,
-					fragment_id: (edition, copy),
-				});
+		while block <= now && expired < max && visited < max {
+			visited += 1;
 
-				// finally fix permissions that might have changed
-				<Fragments<T>>::mutate((class, edition, copy), |item_data| {
-					if let Some(item_data) = item_data {
-						item_data.permissions = perms;
-					}
-				});
+			let remaining = max - expired;
+			let expiring: Vec<(Hash128, Unit, Unit)> =
+				<Expirations<T>>::iter_key_prefix((block,)).take(remaining as usize).collect();
+			let exhausted = (expiring.len() as u32) < remaining;
+
+			for (class, edition, copy) in expiring {
+				expired += 1;
+
+				<Expirations<T>>::remove((block, class, edition, copy));
+
+				if let Some(owner) = <InstanceOwner<T>>::get((class, edition, copy)) {
+					Self::remove_fragment(&owner, class, edition, copy, None);
+
+					Self::deposit_event(Event::Expired {
+						account_id: owner,
+						fragment_hash: class,
+						fragment_id: (edition, copy),
+					});
+				} else {
+					<Fragments<T>>::remove((class, edition, copy));
+					<InstanceMetadata<T>>::remove((class, edition, copy));
+				}
 			}
 
-			Ok(())
+			if !exhausted {
+				// there might be more Fragment Instances that expire at `block`, resume from it
+				break
+			}
+
+			block += One::one();
 		}
 
-		/// Create the **Account ID** of the **Fragment Instance whose Fragment Definition ID is `class`,
-		/// whose Edition ID is `edition`** and whose Copy ID is `copy`**  
-		///
-		/// # Arguments
-		///
-		/// * `origin` - **Origin** of the **extrinsic function**
-		/// * `class` - **Fragment Definition 	ID** of the **Fragment Instance**
-		/// * `edition` - **Edition ID** of the **Fragment Instance**
-		/// * `copy` - **Copy ID** of the **Fragment Instance**
-		#[pallet::weight(50_000)]
-		pub fn create_account(
-			origin: OriginFor<T>,
-			class: Hash128,
-			edition: Unit,
-			copy: Unit,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		<NextExpiration<T>>::put(block);
 
-			// Only the owner of this fragment can transfer it
-			let ids = <Inventory<T>>::get(who.clone(), class).ok_or(Error::<T>::NotFound)?;
+		// every block of `Expirations` that was visited costs a read
+		<T as Config>::WeightInfo::process_expirations(expired)
+			.saturating_add(T::DbWeight::get().reads_writes(visited.into(), 1))
+	}
 
-			ensure!(ids.contains(&(Compact(edition), Compact(copy))), Error::<T>::NoPermission);
+	/// **Remove** the **listings** that **end at or before** the **block `now`** (see `remove_listing`).
+	///
+	/// At most `MaxExpirationsPerBlock` **listings** and `MaxExpirationsPerBlock` **blocks** of `ListingExpirations` are **processed**,
+	/// `NextListingExpiration` **remembers** where to **resume** so that the **remaining ones** are **removed** in the **next blocks**.
+	///
+	/// Returns the **weight** that was **consumed**
+	pub fn process_listing_expirations(now: T::BlockNumber) -> Weight {
+		let max = T::MaxExpirationsPerBlock::get();
 
-			// create an account for a specific fragment
-			// we need an existential amount deposit to be able to create the vault account
-			let frag_account = Self::get_fragment_account_id(class, edition, copy);
-			let min_balance =
-				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::minimum_balance();
-			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(
-				&frag_account,
-				min_balance,
-			)?;
+		let mut block = <NextListingExpiration<T>>::get();
+		let mut ended: u32 = 0;
+		let mut visited: u32 = 0;
 
-			// TODO Make owner pay for deposit actually!
-			// TODO setup proxy
+		while block <= now && ended < max && visited < max {
+			visited += 1;
 
-			Ok(())
+			let remaining = max - ended;
+			let ending: Vec<(Hash128, Unit, Unit)> =
+				<ListingExpirations<T>>::iter_key_prefix((block,))
+					.take(remaining as usize)
+					.collect();
+			let exhausted = (ending.len() as u32) < remaining;
+
+			for (class, edition, copy) in ending {
+				ended += 1;
+
+				<ListingExpirations<T>>::remove((block, class, edition, copy));
+				Self::remove_listing(class, edition, copy);
+			}
+
+			if !exhausted {
+				// there might be more listings that end at `block`, resume from it
+				break
+			}
+
+			block += One::one();
 		}
+
+		<NextListingExpiration<T>>::put(block);
+
+		// every block of `ListingExpirations` that was visited costs a read
+		<T as Config>::WeightInfo::process_listing_expirations(ended)
+			.saturating_add(T::DbWeight::get().reads_writes(visited.into(), 1))
 	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// During the block finalization phase,
-		/// clear all the *Fragment instance*-related Storage Items of any information regarding
-		/// Fragment instances that have already expired
-		fn on_finalize(n: T::BlockNumber) {
-			let expiring = <Expirations<T>>::take(n);
-			if let Some(expiring) = expiring {
-				for item in expiring {
-					// remove from Fragments
-					<Fragments<T>>::remove((item.0, u64::from(item.1), u64::from(item.2)));
-					for (owner, items) in <Owners<T>>::iter_prefix(item.0) {
-						let index = items.iter().position(|x| x == &(item.1, item.2));
-						if let Some(index) = index {
-							// remove from Owners
-							<Owners<T>>::mutate(item.0, owner.clone(), |x| {
-								if let Some(x) = x {
-									x.remove(index);
-								}
-							});
-
-							// remove from Inventory
-							<Inventory<T>>::mutate(owner.clone(), item.0, |x| {
-								if let Some(x) = x {
-									let index = x.iter().position(|y| y == &(item.1, item.2));
-									if let Some(index) = index {
-										x.remove(index);
-									}
-								}
-							});
-
-							// trigger an Event
-							Self::deposit_event(Event::Expired {
-								account_id: owner,
-								fragment_hash: item.0,
-								fragment_id: (item.1.into(), item.2.into()),
-							});
-
-							// fragments are unique so we are done here
-							break;
-						}
-					}
+	/// **Settle** the **auctions** that **end at or before** the **block `now`** (see `close_auction`).
+	///
+	/// At most `MaxAuctionsPerBlock` **auctions** and `MaxAuctionsPerBlock` **blocks** of `AuctionEnds` are **processed**,
+	/// `NextAuctionEnd` **remembers** where to **resume** so that the **remaining ones** are **settled** in the **next blocks**.
+	///
+	/// Returns the **weight** that was **consumed**
+	pub fn process_auction_ends(now: T::BlockNumber) -> Weight {
+		let max = T::MaxAuctionsPerBlock::get();
+
+		let mut block = <NextAuctionEnd<T>>::get();
+		let mut settled: u32 = 0;
+		let mut visited: u32 = 0;
+
+		while block <= now && settled < max && visited < max {
+			visited += 1;
+
+			let remaining = max - settled;
+			let ending: Vec<u64> =
+				<AuctionEnds<T>>::iter_key_prefix(block).take(remaining as usize).collect();
+			let exhausted = (ending.len() as u32) < remaining;
+
+			for auction_id in ending {
+				settled += 1;
+
+				<AuctionEnds<T>>::remove(block, auction_id);
+
+				if let Some(auction) = <Auctions<T>>::get(auction_id) {
+					Self::remove_auction(auction_id, &auction);
+					Self::close_auction(auction_id, auction);
 				}
 			}
+
+			if !exhausted {
+				// there might be more auctions that end at `block`, resume from it
+				break
+			}
+
+			block += One::one();
 		}
+
+		<NextAuctionEnd<T>>::put(block);
+
+		// every block of `AuctionEnds` that was visited costs a read
+		<T as Config>::WeightInfo::process_auction_ends(settled)
+			.saturating_add(T::DbWeight::get().reads_writes(visited.into(), 1))
 	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// **Get** the **Account ID** of the Fragment Definition `class_hash`**
+	/// **Remove** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+	/// from **every storage item** it is **referenced in**
 	///
-	/// This Account ID is determinstically computed using the Fragment Definition `class_hash`
-	pub fn get_vault_id(class_hash: Hash128) -> T::AccountId {
-		let hash = blake2_256(&[&b"fragments-vault"[..], &class_hash].concat());
-		T::AccountId::decode(&mut &hash[..]).expect("T::AccountId should decode")
+	/// # Arguments
+	///
+	/// * `owner` - **Account ID** that **owns** the **Fragment Instance**
+	/// * `class` - Fragment Definition ID of the Fragment Instance
+	/// * `edition` - Edition ID of the Fragment Instance
+	/// * `copy` - Copy ID of the Fragment Instance
+	/// * `expiring_at` (*optional*) - **Block Number** that the **Fragment Instance** was going to **expire at**
+	pub fn remove_fragment(
+		owner: &T::AccountId,
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+		expiring_at: Option<T::BlockNumber>,
+	) {
+		<Fragments<T>>::remove((class, edition, copy));
+		<InstanceMetadata<T>>::remove((class, edition, copy));
+		<Owners<T>>::remove((class, owner.clone(), edition, copy));
+		<Inventory<T>>::remove((owner.clone(), class, edition, copy));
+		<InstanceOwner<T>>::remove((class, edition, copy));
+		<Approvals<T>>::remove((class, edition, copy));
+
+		Self::remove_listing(class, edition, copy);
+		Self::cancel_instance_auction(class, edition, copy);
+
+		if let Some(expiring_at) = expiring_at {
+			<Expirations<T>>::remove((expiring_at, class, edition, copy));
+		}
 	}
 
-	/// Get the **Account ID** of the **Fragment Instance whose Fragment Definition ID is `class_hash`,
-	/// whose Edition ID is `edition`** and whose Copy ID is `copy`**
+	/// **Remove** the **Fragment Instance whose Fragment Definition ID is `class`, whose Edition ID is `edition` and whose Copy ID is `copy`**
+	/// from **every storage item** it is **referenced in** and **record** its **edition** as **burned** if it was the **last one** of its edition
 	///
-	/// This Account ID is determinstically computed using the Fragment Definition ID `class_hash`, the Edition ID `edition` and the Copy ID `copy`
-	pub fn get_fragment_account_id(class_hash: Hash128, edition: Unit, copy: Unit) -> T::AccountId {
-		let hash = blake2_256(
-			&[&b"fragments-account"[..], &class_hash, &edition.encode(), &copy.encode()].concat(),
-		);
-		T::AccountId::decode(&mut &hash[..]).expect("T::AccountId should decode")
+	/// # Arguments
+	///
+	/// * `owner` - **Account ID** that **owns** the **Fragment Instance**
+	/// * `class` - Fragment Definition ID of the Fragment Instance
+	/// * `edition` - Edition ID of the Fragment Instance
+	/// * `copy` - Copy ID of the Fragment Instance
+	/// * `expiring_at` (*optional*) - **Block Number** that the **Fragment Instance** was going to **expire at**
+	pub fn burn_fragment(
+		owner: &T::AccountId,
+		class: Hash128,
+		edition: Unit,
+		copy: Unit,
+		expiring_at: Option<T::BlockNumber>,
+	) {
+		Self::remove_fragment(owner, class, edition, copy, expiring_at);
+
+		if <Fragments<T>>::iter_prefix((class, edition)).next().is_none() {
+			<BurnedEditionsCount<T>>::mutate(class, |burned| {
+				let count: Unit = burned.unwrap_or(Compact(0)).into();
+				*burned = Some(Compact(count.saturating_add(1)));
+			});
+		}
 	}
 
 	/// Create `quantity` number of Fragment Instances from the Fragment Definition `fragment_hash` and assigns their ownership to `to`
@@ -1069,16 +4665,17 @@ impl<T: Config> Pallet<T> {
 
 		let fragment_data = <Definitions<T>>::get(fragment_hash).ok_or(Error::<T>::NotFound)?;
 
-		// we need this to index transactions
-		let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index() // `<frame_system::Pallet<T>>::extrinsic_index()` is defined as: "Gets the index of extrinsic that is currently executing." (https://paritytech.github.io/substrate/master/frame_system/pallet/struct.Pallet.html#method.extrinsic_index)
-			.ok_or(Error::<T>::SystematicFailure)?;
-
 		let (data_hash, data_len) = match options {
 			FragmentBuyOptions::UniqueData(data) => {
 				if fragment_data.unique.is_none() || quantity != 1 {
 					return Err(Error::<T>::ParamsNotValid.into());
 				}
 
+				// we need this to index transactions, only unique data is indexed so
+				// Fragment Instances without it can also be minted outside of an extrinsic (e.g when settling an auction)
+				let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index() // `<frame_system::Pallet<T>>::extrinsic_index()` is defined as: "Gets the index of extrinsic that is currently executing." (https://paritytech.github.io/substrate/master/frame_system/pallet/struct.Pallet.html#method.extrinsic_index)
+					.ok_or(Error::<T>::SystematicFailure)?;
+
 				let data_hash = blake2_256(&data);
 
 				ensure!(
@@ -1086,7 +4683,7 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::UniqueDataExists
 				);
 
-				(Some(data_hash), Some(data.len()))
+				(Some(data_hash), Some((data.len(), extrinsic_index)))
 			},
 			FragmentBuyOptions::Quantity(_) => {
 				if fragment_data.unique.is_some() {
@@ -1104,20 +4701,23 @@ impl<T: Config> Pallet<T> {
 			if let Some(units_left) = sale.units_left {
 				if quantity > units_left.into() {
 					return Err(Error::<T>::PublishedQuantityReached.into());
-				} else {
-					<Publishing<T>>::mutate(&*fragment_hash, |sale| {
-						if let Some(sale) = sale {
-							let left: Unit = units_left.into();
-							sale.units_left = Some(Compact(left - quantity));
-						}
-					});
 				}
 			}
+			<Publishing<T>>::mutate(&*fragment_hash, |sale| {
+				if let Some(sale) = sale {
+					if let Some(units_left) = sale.units_left {
+						let left: Unit = units_left.into();
+						sale.units_left = Some(Compact(left - quantity));
+					}
+					let sold: Unit = sale.sold.into();
+					sale.sold = Compact(sold.saturating_add(quantity)); // the pricing curve moves on
+				}
+			});
 		} else {
 			// We still don't wanna go over supply limit
 			if let Some(max_supply) = fragment_data.max_supply {
 				let max: Unit = max_supply.into();
-				let left = max.saturating_sub(existing); // `left` = `max` - `existing`
+				let left = max.saturating_sub(Self::editions_in_supply(fragment_hash, &fragment_data)); // `left` = `max` - `editions in supply`
 				if quantity > left {
 					// Ensure the function parameter `quantity` is smaller than or equal to `left`
 					return Err(Error::<T>::MaxSupplyReached.into());
@@ -1132,7 +4732,6 @@ impl<T: Config> Pallet<T> {
 			if let Some(fragment) = fragment {
 				for id in existing..(existing + quantity) {
 					let id = id + 1u64;
-					let cid = Compact(id); // `cid` stands for "compact id"
 
 					<Fragments<T>>::insert(
 						(fragment_hash, id, 1),
@@ -1147,12 +4746,14 @@ impl<T: Config> Pallet<T> {
 
 					<CopiesCount<T>>::insert((fragment_hash, id), Compact(1));
 
-					<Inventory<T>>::append(to.clone(), fragment_hash, (cid, Compact(1))); // **Add** the **Fragment Intstance whose Fragment Definition is `fragment_hash`, Edition ID is `cid` and Copy ID is 1**  to the **inventory of `to`**
+					<Inventory<T>>::insert((to.clone(), *fragment_hash, id, 1u64), ()); // **Add** the **Fragment Intstance whose Fragment Definition is `fragment_hash`, Edition ID is `id` and Copy ID is 1**  to the **inventory of `to`**
+
+					<Owners<T>>::insert((*fragment_hash, to.clone(), id, 1u64), ());
 
-					<Owners<T>>::append(fragment_hash, to.clone(), (cid, Compact(1)));
+					<InstanceOwner<T>>::insert((*fragment_hash, id, 1u64), to.clone());
 
 					if let Some(expiring_at) = expiring_at {
-						<Expirations<T>>::append(expiring_at, (*fragment_hash, cid, Compact(1)));
+						<Expirations<T>>::insert((expiring_at, *fragment_hash, id, 1u64), ());
 					}
 					Self::deposit_event(Event::InventoryAdded {
 						account_id: to.clone(),
@@ -1161,8 +4762,8 @@ impl<T: Config> Pallet<T> {
 					});
 				}
 
-				if let (Some(data_hash), Some(data_len)) = (data_hash, data_len) {
-					<UniqueData2Edition<T>>::insert(fragment_hash, data_hash, existing); // if `data` exists, `quantity` is ensured to be 1
+				if let (Some(data_hash), Some((data_len, extrinsic_index))) = (data_hash, data_len) {
+					<UniqueData2Edition<T>>::insert(fragment_hash, data_hash, existing + 1); // if `data` exists, `quantity` is ensured to be 1, so the only Edition ID created is `existing + 1`
 
 					// index immutable data for IPFS discovery
 					transaction_index::index(extrinsic_index, data_len as u32, data_hash);
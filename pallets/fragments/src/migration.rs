@@ -0,0 +1,935 @@
+//! Storage migrations for the pallet `fragments`
+
+use crate::*;
+
+use frame_support::{
+	storage::{
+		migration::{move_prefix, storage_iter, storage_key_iter},
+		storage_prefix, unhashed,
+	},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+	Identity, Twox64Concat,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, traits::OnRuntimeUpgradeHelpersExt};
+
+/// Version 1 moves the `Vec`s of `Proto2Fragments`, `Owners`, `Inventory` and `Expirations`
+/// to one storage entry per Fragment Definition or Fragment Instance
+pub mod v1 {
+	use super::*;
+
+	/// Old value type of `Owners` and `Inventory`: **list of (Edition ID, Copy ID)**
+	type OldIds = Vec<(Compact<Unit>, Compact<Unit>)>;
+
+	/// Length of a `Twox64Concat` hash prefix
+	const TWOX64_LEN: usize = 8;
+
+	/// **Move** the **old entries** of the **storage item `item`** under the **storage item `stash`**, so that they can be **drained**
+	/// one at a time without **meeting** the **new entries** (which are written under the **same prefix**)
+	fn stash(pallet: &[u8], item: &[u8], stash: &[u8]) {
+		move_prefix(&storage_prefix(pallet, item), &storage_prefix(pallet, stash));
+	}
+
+	/// **Count** the **Fragment Instances** listed under the **old storage item `item`** (`Owners` or `Inventory`),
+	/// i.e. the **number of entries** it has **after** this migration
+	#[cfg(feature = "try-runtime")]
+	pub(super) fn count_old_ids(pallet: &[u8], item: &[u8]) -> u32 {
+		storage_iter::<OldIds>(pallet, item).map(|(_, ids)| ids.len() as u32).sum()
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 0** to **version 1**
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(
+					"fragments: skipping v1 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 0;
+
+			stash(pallet, b"Proto2Fragments", b"OldProto2Fragments");
+			stash(pallet, b"Owners", b"OldOwners");
+			stash(pallet, b"Inventory", b"OldInventory");
+			stash(pallet, b"Expirations", b"OldExpirations");
+
+			// every old entry is moved and then drained
+			for (proto_hash, fragment_hashes) in
+				storage_key_iter::<Hash256, Vec<Hash128>, Identity>(pallet, b"OldProto2Fragments")
+					.drain()
+			{
+				reads += 2;
+				writes += 2;
+				for fragment_hash in fragment_hashes {
+					<Proto2Fragments<T>>::insert(proto_hash, fragment_hash, ());
+					writes += 1;
+				}
+			}
+
+			// Key: [16 bytes of Fragment class hash]+[Twox64Concat(Account ID)]
+			for (key, ids) in storage_iter::<OldIds>(pallet, b"OldOwners").drain() {
+				reads += 2;
+				writes += 2;
+				let mut key = &key[..];
+				let decoded = Hash128::decode(&mut key).ok().and_then(|class| {
+					key.get(TWOX64_LEN..)
+						.and_then(|mut key| T::AccountId::decode(&mut key).ok())
+						.map(|owner| (class, owner))
+				});
+				if let Some((class, owner)) = decoded {
+					for (edition, copy) in ids {
+						<Owners<T>>::insert((class, owner.clone(), edition.0, copy.0), ());
+						writes += 1;
+					}
+				} else {
+					log::error!("fragments: could not decode an `Owners` key, dropping it");
+				}
+			}
+
+			// Key: [Twox64Concat(Account ID)]+[16 bytes of Fragment class hash]
+			for (key, ids) in storage_iter::<OldIds>(pallet, b"OldInventory").drain() {
+				reads += 2;
+				writes += 2;
+				let decoded = key.get(TWOX64_LEN..).and_then(|mut key| {
+					let owner = T::AccountId::decode(&mut key).ok()?;
+					let class = Hash128::decode(&mut key).ok()?;
+					Some((owner, class))
+				});
+				if let Some((owner, class)) = decoded {
+					for (edition, copy) in ids {
+						<Inventory<T>>::insert((owner.clone(), class, edition.0, copy.0), ());
+						writes += 1;
+					}
+				} else {
+					log::error!("fragments: could not decode an `Inventory` key, dropping it");
+				}
+			}
+
+			for (block_number, items) in storage_key_iter::<
+				T::BlockNumber,
+				Vec<(Hash128, Compact<Unit>, Compact<Unit>)>,
+				Twox64Concat,
+			>(pallet, b"OldExpirations")
+			.drain()
+			{
+				reads += 2;
+				writes += 2;
+				for (class, edition, copy) in items {
+					<Expirations<T>>::insert((block_number, class, edition.0, copy.0), ());
+					writes += 1;
+				}
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 1;
+
+			log::info!("fragments: migrated to storage version 1");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// **Record** the **number of entries** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 1,
+				"fragments: v1 migration was already applied"
+			);
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+
+			let proto2fragments: u32 =
+				storage_key_iter::<Hash256, Vec<Hash128>, Identity>(pallet, b"Proto2Fragments")
+					.map(|(_, fragment_hashes)| fragment_hashes.len() as u32)
+					.sum();
+			let owners = count_old_ids(pallet, b"Owners");
+			let inventory = count_old_ids(pallet, b"Inventory");
+			let expirations: u32 = storage_key_iter::<
+				T::BlockNumber,
+				Vec<(Hash128, Compact<Unit>, Compact<Unit>)>,
+				Twox64Concat,
+			>(pallet, b"Expirations")
+			.map(|(_, items)| items.len() as u32)
+			.sum();
+
+			Self::set_temp_storage(proto2fragments, "fragments_v1_proto2fragments");
+			Self::set_temp_storage(owners, "fragments_v1_owners");
+			Self::set_temp_storage(inventory, "fragments_v1_inventory");
+			Self::set_temp_storage(expirations, "fragments_v1_expirations");
+
+			Ok(())
+		}
+
+		/// **Check** that **every entry** was **migrated** and that **every Fragment Definition and Fragment Instance still decodes**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"fragments: storage version was not updated to 1"
+			);
+
+			let proto2fragments: u32 = Self::get_temp_storage("fragments_v1_proto2fragments")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			let owners: u32 = Self::get_temp_storage("fragments_v1_owners")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			let inventory: u32 = Self::get_temp_storage("fragments_v1_inventory")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			let expirations: u32 = Self::get_temp_storage("fragments_v1_expirations")
+				.ok_or("fragments: missing pre_upgrade data")?;
+
+			ensure!(
+				<Proto2Fragments<T>>::iter_keys().count() as u32 == proto2fragments,
+				"fragments: `Proto2Fragments` entries were lost"
+			);
+			ensure!(
+				<Owners<T>>::iter_keys().count() as u32 == owners,
+				"fragments: `Owners` entries were lost"
+			);
+			ensure!(
+				<Inventory<T>>::iter_keys().count() as u32 == inventory,
+				"fragments: `Inventory` entries were lost"
+			);
+			ensure!(
+				<Expirations<T>>::iter_keys().count() as u32 == expirations,
+				"fragments: `Expirations` entries were lost"
+			);
+			ensure!(
+				<Definitions<T>>::iter_keys().count() == <Definitions<T>>::iter_values().count(),
+				"fragments: some `Definitions` entries do not decode"
+			);
+			ensure!(
+				<Fragments<T>>::iter_keys().count() == <Fragments<T>>::iter().count(),
+				"fragments: some `Fragments` entries do not decode"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 2 adds `burns_free_supply`, `royalty` and `renewal` to every `FragmentDefinition`
+pub mod v2 {
+	use super::*;
+
+	/// `FragmentDefinition` as it was stored in version 1
+	#[derive(Decode)]
+	struct OldFragmentDefinition<TFungibleAsset, TAccountId, TBlockNum> {
+		proto_hash: Hash256,
+		metadata: FragmentMetadata<TFungibleAsset>,
+		permissions: FragmentPerms,
+		unique: Option<UniqueOptions>,
+		max_supply: Option<Compact<Unit>>,
+		creator: TAccountId,
+		created_at: TBlockNum,
+	}
+
+	/// `FragmentDefinition` as it is stored in version 2
+	#[derive(Encode, Decode)]
+	pub(super) struct FragmentDefinitionV2<TFungibleAsset, TAccountId, TBlockNum> {
+		pub proto_hash: Hash256,
+		pub metadata: FragmentMetadata<TFungibleAsset>,
+		pub permissions: FragmentPerms,
+		pub unique: Option<UniqueOptions>,
+		pub max_supply: Option<Compact<Unit>>,
+		pub burns_free_supply: bool,
+		pub royalty: Option<Royalty<TAccountId>>,
+		pub renewal: Option<Renewal<TBlockNum>>,
+		pub creator: TAccountId,
+		pub created_at: TBlockNum,
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 1** to **version 2**
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 2 {
+				log::info!(
+					"fragments: skipping v2 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+
+			// `FragmentDefinition` changes again in version 7, so the version 2 layout is written as is
+			let pallet = <Pallet<T>>::name().as_bytes();
+			// overwriting an entry doesn't change the keys that are left to iterate
+			for (fragment_hash, old) in storage_key_iter::<
+				Hash128,
+				OldFragmentDefinition<T::AssetId, T::AccountId, T::BlockNumber>,
+				Identity,
+			>(pallet, b"Definitions")
+			{
+				translated += 1;
+				unhashed::put(
+					&<Definitions<T>>::hashed_key_for(fragment_hash),
+					&FragmentDefinitionV2 {
+						proto_hash: old.proto_hash,
+						metadata: old.metadata,
+						permissions: old.permissions,
+						unique: old.unique,
+						max_supply: old.max_supply,
+						// burned editions always counted towards `max_supply` before version 2
+						burns_free_supply: false,
+						royalty: None,
+						renewal: None,
+						creator: old.creator,
+						created_at: old.created_at,
+					},
+				);
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 2");
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		/// **Record** the **number of Fragment Definitions** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 2,
+				"fragments: v2 migration was already applied"
+			);
+
+			Self::set_temp_storage(
+				<Definitions<T>>::iter_keys().count() as u32,
+				"fragments_v2_definitions",
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every Fragment Definition** was **translated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"fragments: storage version was not updated to 2"
+			);
+
+			let definitions: u32 = Self::get_temp_storage("fragments_v2_definitions")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<Definitions<T>>::iter_keys().count() as u32 == definitions,
+				"fragments: some `Definitions` entries were lost"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 3 adds `InstanceOwner` and processes `Expirations` in `on_initialize` from `NextExpiration`
+pub mod v3 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 2** to **version 3**
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 3 {
+				log::info!(
+					"fragments: skipping v3 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut owners: Weight = 0;
+
+			for (class, owner, edition, copy) in <Owners<T>>::iter_keys() {
+				owners += 1;
+				<InstanceOwner<T>>::insert((class, edition, copy), owner);
+			}
+
+			// expirations of the previous blocks were already processed by `on_finalize`
+			<NextExpiration<T>>::put(<frame_system::Pallet<T>>::block_number());
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 3");
+
+			T::DbWeight::get().reads_writes(owners + 2, owners + 2)
+		}
+
+		/// **Record** the **number of Fragment Instance owners** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 3,
+				"fragments: v3 migration was already applied"
+			);
+
+			// the migrations are not applied yet, `Owners` may still have the layout of version 0
+			let owners = if Pallet::<T>::on_chain_storage_version() < 1 {
+				v1::count_old_ids(<Pallet<T>>::name().as_bytes(), b"Owners")
+			} else {
+				<Owners<T>>::iter_keys().count() as u32
+			};
+
+			Self::set_temp_storage(owners, "fragments_v3_owners");
+
+			Ok(())
+		}
+
+		/// **Check** that **every Fragment Instance owner** was **indexed**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 3,
+				"fragments: storage version was not updated to 3"
+			);
+
+			let owners: u32 = Self::get_temp_storage("fragments_v3_owners")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<InstanceOwner<T>>::iter_keys().count() as u32 == owners,
+				"fragments: some `Owners` entries were not indexed in `InstanceOwner`"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 4 adds `allowlist`, `max_per_account` and `published_at` to every `PublishingData`
+pub mod v4 {
+	use super::*;
+
+	/// `PublishingData` as it was stored in version 3
+	#[derive(Decode)]
+	struct OldPublishingData<TBlockNum> {
+		price: Compact<u128>,
+		units_left: Option<Compact<Unit>>,
+		expiration: Option<TBlockNum>,
+		amount: Option<Compact<Unit>>,
+	}
+
+	/// `PublishingData` as it is stored in version 4
+	#[derive(Encode, Decode)]
+	pub(super) struct PublishingDataV4<TBlockNum> {
+		pub price: Compact<u128>,
+		pub units_left: Option<Compact<Unit>>,
+		pub expiration: Option<TBlockNum>,
+		pub amount: Option<Compact<Unit>>,
+		pub allowlist: Option<Allowlist>,
+		pub max_per_account: Option<Compact<Unit>>,
+		pub published_at: TBlockNum,
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 3** to **version 4**
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 4 {
+				log::info!(
+					"fragments: skipping v4 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			// purchases were not counted before version 4, so the open sales start counting now
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let mut translated: Weight = 0;
+
+			// `PublishingData` changes again in version 5, so the version 4 layout is written as is
+			let pallet = <Pallet<T>>::name().as_bytes();
+			for (fragment_hash, old) in storage_key_iter::<
+				Hash128,
+				OldPublishingData<T::BlockNumber>,
+				Identity,
+			>(pallet, b"Publishing")
+			{
+				translated += 1;
+				unhashed::put(
+					&<Publishing<T>>::hashed_key_for(fragment_hash),
+					&PublishingDataV4 {
+						price: old.price,
+						units_left: old.units_left,
+						expiration: old.expiration,
+						amount: old.amount,
+						allowlist: None,
+						max_per_account: None,
+						published_at: current_block_number,
+					},
+				);
+			}
+
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 4");
+
+			T::DbWeight::get().reads_writes(translated + 2, translated + 1)
+		}
+
+		/// **Record** the **number of sales** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 4,
+				"fragments: v4 migration was already applied"
+			);
+
+			Self::set_temp_storage(
+				<Publishing<T>>::iter_keys().count() as u32,
+				"fragments_v4_sales",
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every sale** was **translated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 4,
+				"fragments: storage version was not updated to 4"
+			);
+
+			let sales: u32 = Self::get_temp_storage("fragments_v4_sales")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<Publishing<T>>::iter_keys().count() as u32 == sales,
+				"fragments: some `Publishing` entries were lost"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 5 adds `starts_at` to every `PublishingData` and the later phases of a sale in `SalePhases`
+pub mod v5 {
+	use super::*;
+
+	/// `PublishingData` as it is stored in version 5
+	#[derive(Encode, Decode)]
+	pub(super) struct PublishingDataV5<TBlockNum> {
+		pub price: Compact<u128>,
+		pub units_left: Option<Compact<Unit>>,
+		pub starts_at: Option<TBlockNum>,
+		pub expiration: Option<TBlockNum>,
+		pub amount: Option<Compact<Unit>>,
+		pub allowlist: Option<Allowlist>,
+		pub max_per_account: Option<Compact<Unit>>,
+		pub published_at: TBlockNum,
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 4** to **version 5**
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 5 {
+				log::info!(
+					"fragments: skipping v5 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+
+			// the open sales already started and have a single phase.
+			// `PublishingData` changes again in version 6, so the version 5 layout is written as is
+			let pallet = <Pallet<T>>::name().as_bytes();
+			for (fragment_hash, old) in storage_key_iter::<
+				Hash128,
+				v4::PublishingDataV4<T::BlockNumber>,
+				Identity,
+			>(pallet, b"Publishing")
+			{
+				translated += 1;
+				unhashed::put(
+					&<Publishing<T>>::hashed_key_for(fragment_hash),
+					&PublishingDataV5 {
+						price: old.price,
+						units_left: old.units_left,
+						starts_at: None,
+						expiration: old.expiration,
+						amount: old.amount,
+						allowlist: old.allowlist,
+						max_per_account: old.max_per_account,
+						published_at: old.published_at,
+					},
+				);
+			}
+
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 5");
+
+			T::DbWeight::get().reads_writes(translated + 2, translated + 1)
+		}
+
+		/// **Record** the **number of sales** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 5,
+				"fragments: v5 migration was already applied"
+			);
+
+			Self::set_temp_storage(
+				<Publishing<T>>::iter_keys().count() as u32,
+				"fragments_v5_sales",
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every sale** was **translated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 5,
+				"fragments: storage version was not updated to 5"
+			);
+
+			let sales: u32 = Self::get_temp_storage("fragments_v5_sales")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<Publishing<T>>::iter_keys().count() as u32 == sales,
+				"fragments: some `Publishing` entries were lost"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 6 adds `curve` and `sold` to every `PublishingData`
+pub mod v6 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 5** to **version 6**
+	pub struct MigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 6 {
+				log::info!(
+					"fragments: skipping v6 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+
+			// the open sales have a flat price, so what they sold so far does not matter
+			<Publishing<T>>::translate::<v5::PublishingDataV5<T::BlockNumber>, _>(|_, old| {
+				translated += 1;
+				Some(PublishingData {
+					price: old.price,
+					units_left: old.units_left,
+					starts_at: old.starts_at,
+					expiration: old.expiration,
+					amount: old.amount,
+					allowlist: old.allowlist,
+					max_per_account: old.max_per_account,
+					published_at: old.published_at,
+					curve: None,
+					sold: Compact(0),
+				})
+			});
+
+			StorageVersion::new(6).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 6");
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		/// **Record** the **number of sales** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 6,
+				"fragments: v6 migration was already applied"
+			);
+
+			Self::set_temp_storage(
+				<Publishing<T>>::iter_keys().count() as u32,
+				"fragments_v6_sales",
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every sale** was **translated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 6,
+				"fragments: storage version was not updated to 6"
+			);
+
+			let sales: u32 = Self::get_temp_storage("fragments_v6_sales")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<Publishing<T>>::iter_values().count() as u32 == sales,
+				"fragments: some `Publishing` entries were not translated"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 7 adds `revocable` to every `FragmentDefinition`
+pub mod v7 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 6** to **version 7**
+	pub struct MigrateToV7<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 7 {
+				log::info!(
+					"fragments: skipping v7 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+
+			// the existing Fragment Instances were issued without the possibility of being revoked
+			<Definitions<T>>::translate::<
+				v2::FragmentDefinitionV2<T::AssetId, T::AccountId, T::BlockNumber>,
+				_,
+			>(|_, old| {
+				translated += 1;
+				Some(FragmentDefinition {
+					proto_hash: old.proto_hash,
+					metadata: old.metadata,
+					permissions: old.permissions,
+					unique: old.unique,
+					max_supply: old.max_supply,
+					burns_free_supply: old.burns_free_supply,
+					royalty: old.royalty,
+					renewal: old.renewal,
+					revocable: false,
+					creator: old.creator,
+					created_at: old.created_at,
+				})
+			});
+
+			StorageVersion::new(7).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 7");
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		/// **Record** the **number of Fragment Definitions** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 7,
+				"fragments: v7 migration was already applied"
+			);
+
+			Self::set_temp_storage(
+				<Definitions<T>>::iter_keys().count() as u32,
+				"fragments_v7_definitions",
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every Fragment Definition** was **translated**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 7,
+				"fragments: storage version was not updated to 7"
+			);
+
+			let definitions: u32 = Self::get_temp_storage("fragments_v7_definitions")
+				.ok_or("fragments: missing pre_upgrade data")?;
+			ensure!(
+				<Definitions<T>>::iter_values().count() as u32 == definitions,
+				"fragments: some `Definitions` entries were not translated"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 8 processes `ListingExpirations` and `AuctionEnds` in `on_initialize` from `NextListingExpiration` and `NextAuctionEnd`
+pub mod v8 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 7** to **version 8**
+	pub struct MigrateToV8<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 8 {
+				log::info!(
+					"fragments: skipping v8 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			// listings and auctions of the previous blocks were already processed by `on_finalize`
+			<NextListingExpiration<T>>::put(now);
+			<NextAuctionEnd<T>>::put(now);
+
+			StorageVersion::new(8).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 8");
+
+			T::DbWeight::get().reads_writes(2, 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 8,
+				"fragments: v8 migration was already applied"
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **no listing** and **no auction** is **left behind** the **cursors**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 8,
+				"fragments: storage version was not updated to 8"
+			);
+
+			let next = <NextListingExpiration<T>>::get();
+			ensure!(
+				<ListingExpirations<T>>::iter_keys().all(|(block, _, _, _)| block >= next),
+				"fragments: some `ListingExpirations` entries are before `NextListingExpiration`"
+			);
+
+			let next = <NextAuctionEnd<T>>::get();
+			ensure!(
+				<AuctionEnds<T>>::iter_keys().all(|(block, _)| block >= next),
+				"fragments: some `AuctionEnds` entries are before `NextAuctionEnd`"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Version 9 records in `AccountDeposits` the deposit of the Fragment Instance accounts that were created before it existed
+pub mod v9 {
+	use super::*;
+
+	/// **Migrate** the **storage** of this pallet from **version 8** to **version 9**
+	pub struct MigrateToV9<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 9 {
+				log::info!(
+					"fragments: skipping v9 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut instances: Weight = 0;
+			let mut backfilled: Weight = 0;
+
+			for (class, edition, copy) in <InstanceOwner<T>>::iter_keys() {
+				instances += 1;
+
+				if <AccountDeposits<T>>::contains_key((class, edition, copy)) {
+					continue
+				}
+
+				// before `AccountDeposits`, `create_account` only reserved the deposit on the account
+				let frag_account = Pallet::<T>::get_fragment_account_id(class, edition, copy);
+				let reserved =
+					<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserved_balance(
+						&frag_account,
+					);
+				if !reserved.is_zero() {
+					backfilled += 1;
+					<AccountDeposits<T>>::insert(
+						(class, edition, copy),
+						Compact(reserved.saturated_into::<u128>()),
+					);
+				}
+			}
+
+			StorageVersion::new(9).put::<Pallet<T>>();
+
+			log::info!("fragments: migrated to storage version 9");
+
+			T::DbWeight::get().reads_writes(instances * 3 + 1, backfilled + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 9,
+				"fragments: v9 migration was already applied"
+			);
+
+			Ok(())
+		}
+
+		/// **Check** that **every Fragment Instance account** that **holds** a **reserve** has a **deposit**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 9,
+				"fragments: storage version was not updated to 9"
+			);
+
+			ensure!(
+				<InstanceOwner<T>>::iter_keys().all(|(class, edition, copy)| {
+					let frag_account = Pallet::<T>::get_fragment_account_id(class, edition, copy);
+					<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserved_balance(
+						&frag_account,
+					)
+					.is_zero() || <AccountDeposits<T>>::contains_key((class, edition, copy))
+				}),
+				"fragments: some Fragment Instance accounts have no `AccountDeposits` entry"
+			);
+
+			Ok(())
+		}
+	}
+}
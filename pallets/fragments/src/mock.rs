@@ -171,6 +171,12 @@ impl pallet_proxy::Config for Test {
 
 impl pallet_fragments::Config for Test {
 	type Event = Event;
+	type Call = Call;
+	type MaxBatchSize = ConstU32<4>;
+	type MaxExpirationsPerBlock = ConstU32<2>;
+	type MaxAuctionsPerBlock = ConstU32<2>;
+	type MaxSalePhases = ConstU32<4>;
+	type MaxInstanceMetadata = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -178,6 +184,8 @@ impl pallet_detach::Config for Test {
 	type Event = Event;
 	type WeightInfo = ();
 	type AuthorityId = pallet_detach::crypto::DetachAuthId;
+	type MaxDetachRequests = ConstU32<8>;
+	type DetachTimeout = ConstU64<10>;
 }
 
 impl pallet_timestamp::Config for Test {
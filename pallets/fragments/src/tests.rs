@@ -1,9 +1,30 @@
-use crate::{mock::*, Definitions, Error, FragmentMetadata, Proto2Fragments};
-use codec::Encode;
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migration, mock::*, AccountDeposits, Allowlist, Approvals, AuctionEnds, AuctionItem,
+	AuctionKind, Auctions, BurnedEditionsCount, DefinitionMetadata, Definitions, Error,
+	Expirations, FragmentBuyOptions, FragmentMetadata, Fragments, GameAuthorities,
+	GetDefinitionsParams, GetInstancesParams, GetOwnersParams, InstanceMetadata, InstanceOwner,
+	Inventory, ListingExpirations, Listings, MinterTerms, Minters, NextAuctionEnd, NextExpiration,
+	NextListingExpiration, Owners, PriceCurve, Proto2Fragments, Publishing, Purchases, Renewal,
+	Royalty, SalePhase, UniqueData2Edition, UniqueOptions,
+};
+use codec::{Compact, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::migration::put_storage_value,
+	traits::{
+		Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, PalletInfoAccess, ReservableCurrency,
+		StorageVersion,
+	},
+	StorageHasher, Twox64Concat,
+};
+use pallet_detach::{
+	DetachHash, DetachInternalData, DetachRequests, DetachedInstances, PendingDetaches,
+	SupportedChains,
+};
 use protos::categories::{Categories, TextCategories};
 use protos::permissions::FragmentPerms;
-use sp_io::hashing::blake2_128;
+use sp_io::hashing::{blake2_128, blake2_256};
+use sp_runtime::Permill;
 
 fn initial_upload() {
 	let data = DATA.as_bytes().to_vec();
@@ -20,12 +41,67 @@ fn initial_upload() {
 	));
 }
 
+/// Initialize every block up to `n`, like the chain would
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		FragmentsPallet::on_initialize(System::block_number());
+	}
+}
+
+/// Upload the Proto-Fragment and create a Fragment Definition out of it, returning the Fragment Definition ID
+fn create_definition(
+	permissions: FragmentPerms,
+	max_supply: Option<u64>,
+	burns_free_supply: bool,
+) -> sp_clamor::Hash128 {
+	create_definition_with(permissions, None, max_supply, burns_free_supply)
+}
+
+/// Same as `create_definition`, for Fragment Definitions whose Fragment Instances hold unique data
+fn create_definition_with(
+	permissions: FragmentPerms,
+	unique: Option<UniqueOptions>,
+	max_supply: Option<u64>,
+	burns_free_supply: bool,
+) -> sp_clamor::Hash128 {
+	initial_upload();
+
+	let fragment_data =
+		FragmentMetadata { name: "name".as_bytes().to_vec().try_into().unwrap(), currency: None };
+
+	let hash = blake2_128(
+		&[&PROTO_HASH[..], &fragment_data.name.encode(), &fragment_data.currency.encode()].concat(),
+	);
+
+	// the vault of the Fragment Definition needs an existential deposit
+	Balances::make_free_balance_be(&FragmentsPallet::get_vault_id(hash), 100);
+
+	assert_ok!(FragmentsPallet::create(
+		Origin::signed(sp_core::ed25519::Public::from_raw(PUBLIC)),
+		PROTO_HASH,
+		fragment_data,
+		permissions,
+		unique,
+		max_supply,
+		burns_free_supply,
+		None,
+		None,
+		false
+	));
+
+	hash
+}
+
 #[test]
 fn create_should_works() {
 	new_test_ext().execute_with(|| {
 		initial_upload();
 
-		let fragment_data = FragmentMetadata { name: "name".as_bytes().to_vec(), currency: None };
+		let fragment_data = FragmentMetadata {
+			name: "name".as_bytes().to_vec().try_into().unwrap(),
+			currency: None,
+		};
 
 		let hash = blake2_128(
 			&[&PROTO_HASH[..], &fragment_data.name.encode(), &fragment_data.currency.encode()]
@@ -38,17 +114,24 @@ fn create_should_works() {
 			fragment_data,
 			FragmentPerms::NONE,
 			None,
-			None
+			None,
+			false,
+			None,
+			None,
+			false
 		));
 		assert!(Definitions::<Test>::contains_key(&hash));
-		assert!(Proto2Fragments::<Test>::contains_key(&PROTO_HASH));
+		assert!(Proto2Fragments::<Test>::contains_key(&PROTO_HASH, &hash));
 	});
 }
 
 #[test]
 fn create_should_not_work_if_protos_not_found() {
 	new_test_ext().execute_with(|| {
-		let fragment_data = FragmentMetadata { name: "name".as_bytes().to_vec(), currency: None };
+		let fragment_data = FragmentMetadata {
+			name: "name".as_bytes().to_vec().try_into().unwrap(),
+			currency: None,
+		};
 
 		assert_noop!(
 			FragmentsPallet::create(
@@ -57,7 +140,11 @@ fn create_should_not_work_if_protos_not_found() {
 				fragment_data,
 				FragmentPerms::NONE,
 				None,
-				None
+				None,
+				false,
+				None,
+				None,
+				false
 			),
 			Error::<Test>::ProtoNotFound
 		);
@@ -74,7 +161,10 @@ fn create_should_not_work_if_proto_owner_not_found() {
 			96, 79, 173, 215, 209, 136, 226, 220, 88, 91, 78, 26, 251,
 		];
 
-		let fragment_data = FragmentMetadata { name: "name".as_bytes().to_vec(), currency: None };
+		let fragment_data = FragmentMetadata {
+			name: "name".as_bytes().to_vec().try_into().unwrap(),
+			currency: None,
+		};
 
 		assert_noop!(
 			FragmentsPallet::create(
@@ -83,7 +173,11 @@ fn create_should_not_work_if_proto_owner_not_found() {
 				fragment_data,
 				FragmentPerms::NONE,
 				None,
-				None
+				None,
+				false,
+				None,
+				None,
+				false
 			),
 			Error::<Test>::NoPermission
 		);
@@ -95,7 +189,10 @@ fn create_should_not_work_if_fragment_already_exist() {
 	new_test_ext().execute_with(|| {
 		initial_upload();
 
-		let fragment_data = FragmentMetadata { name: "name".as_bytes().to_vec(), currency: None };
+		let fragment_data = FragmentMetadata {
+			name: "name".as_bytes().to_vec().try_into().unwrap(),
+			currency: None,
+		};
 
 		let hash = blake2_128(
 			&[&PROTO_HASH[..], &fragment_data.name.encode(), &fragment_data.currency.encode()]
@@ -108,7 +205,11 @@ fn create_should_not_work_if_fragment_already_exist() {
 			fragment_data.clone(),
 			FragmentPerms::NONE,
 			None,
-			None
+			None,
+			false,
+			None,
+			None,
+			false
 		));
 		assert!(Definitions::<Test>::contains_key(&hash));
 
@@ -119,9 +220,2669 @@ fn create_should_not_work_if_fragment_already_exist() {
 				fragment_data,
 				FragmentPerms::NONE,
 				None,
-				None
+				None,
+				false,
+				None,
+				None,
+				false
 			),
 			Error::<Test>::AlreadyExist
 		);
 	});
 }
+
+#[test]
+fn burn_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::BURN, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+
+		assert_ok!(FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1));
+
+		assert!(!Fragments::<Test>::contains_key((hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert!(!Owners::<Test>::contains_key((hash, owner, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((owner, hash, 2, 1)));
+		assert_eq!(BurnedEditionsCount::<Test>::get(hash), Some(Compact(1)));
+	});
+}
+
+#[test]
+fn burn_should_not_work_without_burn_permission() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		// editing doesn't allow burning
+		let hash = create_definition(FragmentPerms::TRANSFER | FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn burn_should_free_supply_only_if_definition_allows_it() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::BURN, Some(1), true);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_noop!(
+			FragmentsPallet::mint(Origin::signed(owner), hash, FragmentBuyOptions::Quantity(1), None),
+			Error::<Test>::MaxSupplyReached
+		);
+
+		assert_ok!(FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1));
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((owner, hash, 2, 1)));
+	});
+}
+
+#[test]
+fn burn_should_not_free_supply_by_default() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::BURN, Some(1), false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_ok!(FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1));
+
+		assert_noop!(
+			FragmentsPallet::mint(Origin::signed(owner), hash, FragmentBuyOptions::Quantity(1), None),
+			Error::<Test>::MaxSupplyReached
+		);
+	});
+}
+
+#[test]
+fn revoke_should_give_back_or_burn() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let player = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		// soulbound, the player can't give it back
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint_to(
+			Origin::signed(owner),
+			hash,
+			player,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::revoke(Origin::signed(owner), hash, 1, 1, false),
+			Error::<Test>::NotRevocable
+		);
+
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().revocable = true;
+		});
+
+		assert_noop!(
+			FragmentsPallet::revoke(Origin::signed(player), hash, 1, 1, false),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			FragmentsPallet::revoke(Origin::signed(owner), hash, 3, 1, false),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(FragmentsPallet::revoke(Origin::signed(owner), hash, 1, 1, false));
+		assert!(!Inventory::<Test>::contains_key((player, hash, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert_eq!(InstanceOwner::<Test>::get((hash, 1, 1)), Some(owner));
+		// the creator already holds it
+		assert_noop!(
+			FragmentsPallet::revoke(Origin::signed(owner), hash, 1, 1, false),
+			Error::<Test>::ParamsNotValid
+		);
+
+		assert_ok!(FragmentsPallet::revoke(Origin::signed(owner), hash, 2, 1, true));
+		assert!(!Inventory::<Test>::contains_key((player, hash, 2, 1)));
+		assert!(!Fragments::<Test>::contains_key((hash, 2, 1)));
+		assert_eq!(BurnedEditionsCount::<Test>::get(hash), Some(Compact(1)));
+	});
+}
+
+#[test]
+fn revoke_should_close_auction() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let player = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let bidder = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().revocable = true;
+		});
+
+		assert_ok!(FragmentsPallet::mint_to(
+			Origin::signed(owner),
+			hash,
+			player,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_ok!(FragmentsPallet::create_auction(
+			Origin::signed(player),
+			hash,
+			AuctionItem::Instance { edition: 1, copy: 1 },
+			AuctionKind::English { reserve: Compact(10), extension: 0 },
+			None,
+			10
+		));
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), 0, 20));
+
+		assert_ok!(FragmentsPallet::revoke(Origin::signed(owner), hash, 1, 1, false));
+
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert!(!Auctions::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&bidder), 0);
+		assert_eq!(Balances::free_balance(&bidder), 100);
+	});
+}
+
+#[test]
+fn split_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			Some(10)
+		));
+
+		assert_ok!(FragmentsPallet::split(Origin::signed(owner), hash, 1, 1, 4));
+
+		assert_eq!(Fragments::<Test>::get((hash, 1, 1)).unwrap().amount, Some(Compact(6)));
+		assert_eq!(Fragments::<Test>::get((hash, 1, 2)).unwrap().amount, Some(Compact(4)));
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 2)));
+		assert!(Owners::<Test>::contains_key((hash, owner, 1, 2)));
+
+		assert_noop!(
+			FragmentsPallet::split(Origin::signed(owner), hash, 1, 1, 6),
+			Error::<Test>::InsufficientAmount
+		);
+	});
+}
+
+#[test]
+fn merge_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			Some(10)
+		));
+
+		assert_ok!(FragmentsPallet::merge(Origin::signed(owner), hash, 1, 1, 2, 1));
+
+		assert!(!Fragments::<Test>::contains_key((hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert_eq!(Fragments::<Test>::get((hash, 2, 1)).unwrap().amount, Some(Compact(20)));
+		// the merged stack was the last copy of its edition
+		assert_eq!(BurnedEditionsCount::<Test>::get(hash), Some(Compact(1)));
+
+		// merging a copy back into its edition doesn't empty it
+		assert_ok!(FragmentsPallet::split(Origin::signed(owner), hash, 2, 1, 5));
+		assert_ok!(FragmentsPallet::merge(Origin::signed(owner), hash, 2, 2, 2, 1));
+
+		assert_eq!(Fragments::<Test>::get((hash, 2, 1)).unwrap().amount, Some(Compact(20)));
+		assert_eq!(BurnedEditionsCount::<Test>::get(hash), Some(Compact(1)));
+	});
+}
+
+#[test]
+fn consume_should_burn_empty_stack() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			Some(10)
+		));
+
+		assert_ok!(FragmentsPallet::consume(Origin::signed(owner), hash, 1, 1, 3));
+		assert_eq!(Fragments::<Test>::get((hash, 1, 1)).unwrap().amount, Some(Compact(7)));
+
+		assert_noop!(
+			FragmentsPallet::consume(Origin::signed(owner), hash, 1, 1, 8),
+			Error::<Test>::InsufficientAmount
+		);
+
+		assert_ok!(FragmentsPallet::consume(Origin::signed(owner), hash, 1, 1, 7));
+		assert!(!Fragments::<Test>::contains_key((hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn consume_should_not_work_if_not_stackable() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::consume(Origin::signed(owner), hash, 1, 1, 1),
+			Error::<Test>::NotStackable
+		);
+	});
+}
+
+#[test]
+fn update_unique_data_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition_with(
+			FragmentPerms::EDIT,
+			Some(UniqueOptions { mutable: true }),
+			None,
+			false,
+		);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::UniqueData(b"old".to_vec()),
+			None
+		));
+		assert_eq!(UniqueData2Edition::<Test>::get(hash, blake2_256(b"old")), Some(1));
+
+		assert_ok!(FragmentsPallet::update_unique_data(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			b"new".to_vec()
+		));
+
+		assert!(!UniqueData2Edition::<Test>::contains_key(hash, blake2_256(b"old")));
+		assert_eq!(UniqueData2Edition::<Test>::get(hash, blake2_256(b"new")), Some(1));
+		assert_eq!(
+			Fragments::<Test>::get((hash, 1, 1)).unwrap().custom_data,
+			Some(blake2_256(b"new"))
+		);
+	});
+}
+
+#[test]
+fn update_unique_data_should_keep_data_unique() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition_with(
+			FragmentPerms::EDIT,
+			Some(UniqueOptions { mutable: true }),
+			None,
+			false,
+		);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::UniqueData(b"first".to_vec()),
+			None
+		));
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::UniqueData(b"second".to_vec()),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::update_unique_data(
+				Origin::signed(owner),
+				hash,
+				2,
+				1,
+				b"first".to_vec()
+			),
+			Error::<Test>::UniqueDataExists
+		);
+	});
+}
+
+#[test]
+fn update_unique_data_should_not_work_if_immutable() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition_with(
+			FragmentPerms::EDIT,
+			Some(UniqueOptions { mutable: false }),
+			None,
+			false,
+		);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::UniqueData(b"old".to_vec()),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::update_unique_data(
+				Origin::signed(owner),
+				hash,
+				1,
+				1,
+				b"new".to_vec()
+			),
+			Error::<Test>::ImmutableUniqueData
+		);
+	});
+}
+
+#[test]
+fn set_instance_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let stranger = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::EDIT | FragmentPerms::BURN, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::set_instance_metadata(
+				Origin::signed(stranger),
+				hash,
+				1,
+				1,
+				b"level".to_vec(),
+				b"1".to_vec()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(FragmentsPallet::set_instance_metadata(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			b"level".to_vec(),
+			b"2".to_vec()
+		));
+
+		let key_index = pallet_protos::MetaKeys::<Test>::get(
+			pallet_protos::MetadataKey::try_from(b"level".to_vec()).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(
+			InstanceMetadata::<Test>::get((hash, 1, 1)).get(&Compact(key_index)),
+			Some(&blake2_256(b"2"))
+		);
+
+		let result = FragmentsPallet::get_instances(GetInstancesParams {
+			from: 0,
+			limit: 10,
+			definition_hash: Some(hex::encode(hash).into_bytes()),
+			owner: None,
+			metadata_keys: vec![b"level".to_vec(), b"skin".to_vec()],
+		})
+		.unwrap();
+		let instances = serde_json::from_slice::<serde_json::Value>(&result).unwrap();
+		assert_eq!(instances[0]["metadata"]["level"], hex::encode(blake2_256(b"2")));
+		assert!(instances[0]["metadata"]["skin"].is_null());
+
+		// `MaxInstanceMetadata` is 2, the metadata under the keys already set can still change
+		assert_ok!(FragmentsPallet::set_instance_metadata(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			b"skin".to_vec(),
+			b"red".to_vec()
+		));
+		assert_noop!(
+			FragmentsPallet::set_instance_metadata(
+				Origin::signed(owner),
+				hash,
+				1,
+				1,
+				b"durability".to_vec(),
+				b"3".to_vec()
+			),
+			Error::<Test>::TooManyMetadataKeys
+		);
+		assert_ok!(FragmentsPallet::set_instance_metadata(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			b"level".to_vec(),
+			b"3".to_vec()
+		));
+
+		// the metadata goes away with the Fragment Instance
+		assert_ok!(FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1));
+		assert!(!InstanceMetadata::<Test>::contains_key((hash, 1, 1)));
+	});
+}
+
+#[test]
+fn set_instance_metadata_should_allow_game_authority() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let authority = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		// without `FragmentPerms::EDIT` not even the owner can set the metadata
+		assert_noop!(
+			FragmentsPallet::set_instance_metadata(
+				Origin::signed(owner),
+				hash,
+				1,
+				1,
+				b"durability".to_vec(),
+				b"100".to_vec()
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_noop!(
+			FragmentsPallet::set_game_authority(Origin::signed(authority), hash, Some(authority)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(FragmentsPallet::set_game_authority(
+			Origin::signed(owner),
+			hash,
+			Some(authority)
+		));
+		assert_eq!(GameAuthorities::<Test>::get(hash), Some(authority));
+
+		assert_ok!(FragmentsPallet::set_instance_metadata(
+			Origin::signed(authority),
+			hash,
+			1,
+			1,
+			b"durability".to_vec(),
+			b"100".to_vec()
+		));
+		let key_index = pallet_protos::MetaKeys::<Test>::get(
+			pallet_protos::MetadataKey::try_from(b"durability".to_vec()).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(
+			InstanceMetadata::<Test>::get((hash, 1, 1)).get(&Compact(key_index)),
+			Some(&blake2_256(b"100"))
+		);
+
+		assert_ok!(FragmentsPallet::set_game_authority(Origin::signed(owner), hash, None));
+		assert_noop!(
+			FragmentsPallet::set_instance_metadata(
+				Origin::signed(authority),
+				hash,
+				1,
+				1,
+				b"durability".to_vec(),
+				b"99".to_vec()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn withdraw_proceeds_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		let vault = FragmentsPallet::get_vault_id(hash);
+
+		// the vault keeps its reserved minimum balance
+		assert_eq!(FragmentsPallet::get_vault_balances(hash).unwrap().native, 99);
+
+		assert_ok!(FragmentsPallet::withdraw_proceeds(Origin::signed(owner), hash, 99, None));
+
+		assert_eq!(Balances::free_balance(&owner), 99);
+		assert_eq!(Balances::free_balance(&vault), 0);
+		assert_eq!(Balances::reserved_balance(&vault), 1);
+		assert_eq!(FragmentsPallet::get_vault_balances(hash).unwrap().native, 0);
+	});
+}
+
+#[test]
+fn withdraw_proceeds_should_not_work_if_not_creator() {
+	new_test_ext().execute_with(|| {
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		let stranger = sp_core::ed25519::Public::from_raw([7u8; 32]);
+		assert_noop!(
+			FragmentsPallet::withdraw_proceeds(Origin::signed(stranger), hash, 1, None),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn pay_secondary_sale_should_pay_royalty() {
+	new_test_ext().execute_with(|| {
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		let beneficiary = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let buyer = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let seller = sp_core::ed25519::Public::from_raw([7u8; 32]);
+
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().royalty =
+				Some(Royalty { beneficiary, percentage: Permill::from_percent(10) });
+		});
+		assert_eq!(FragmentsPallet::get_royalty(hash, 100), Some((beneficiary, 10)));
+
+		Balances::make_free_balance_be(&buyer, 1000);
+		assert_ok!(FragmentsPallet::pay_secondary_sale(hash, &buyer, &seller, 100, None));
+
+		assert_eq!(Balances::free_balance(&buyer), 900);
+		assert_eq!(Balances::free_balance(&seller), 90);
+		assert_eq!(Balances::free_balance(&beneficiary), 10);
+	});
+}
+
+#[test]
+fn pay_secondary_sale_should_work_without_royalty() {
+	new_test_ext().execute_with(|| {
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		let buyer = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let seller = sp_core::ed25519::Public::from_raw([7u8; 32]);
+
+		assert_eq!(FragmentsPallet::get_royalty(hash, 100), None);
+
+		Balances::make_free_balance_be(&buyer, 1000);
+		assert_ok!(FragmentsPallet::pay_secondary_sale(hash, &buyer, &seller, 100, None));
+
+		assert_eq!(Balances::free_balance(&buyer), 900);
+		assert_eq!(Balances::free_balance(&seller), 100);
+	});
+}
+
+/// Create a transferable Fragment Definition, mint its first edition and list it for `price` NOVA
+fn list_first_edition(price: u128, expiration: Option<u64>) -> sp_clamor::Hash128 {
+	let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+	let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+	assert_ok!(FragmentsPallet::mint(
+		Origin::signed(owner),
+		hash,
+		FragmentBuyOptions::Quantity(1),
+		None
+	));
+	assert_ok!(FragmentsPallet::list_instance(
+		Origin::signed(owner),
+		hash,
+		1,
+		1,
+		price,
+		None,
+		expiration
+	));
+
+	hash
+}
+
+#[test]
+fn buy_instance_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = list_first_edition(100, None);
+
+		let beneficiary = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().royalty =
+				Some(Royalty { beneficiary, percentage: Permill::from_percent(10) });
+		});
+		assert_eq!(FragmentsPallet::get_listings(hash).len(), 1);
+
+		let buyer = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		Balances::make_free_balance_be(&buyer, 1000);
+		assert_ok!(FragmentsPallet::buy_instance(Origin::signed(buyer), hash, 1, 1));
+
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert!(!Owners::<Test>::contains_key((hash, owner, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((buyer, hash, 1, 1)));
+		assert!(Owners::<Test>::contains_key((hash, buyer, 1, 1)));
+		assert!(!Listings::<Test>::contains_key((hash, 1, 1)));
+
+		assert_eq!(Balances::free_balance(&buyer), 900);
+		assert_eq!(Balances::free_balance(&owner), 90);
+		assert_eq!(Balances::free_balance(&beneficiary), 10);
+	});
+}
+
+#[test]
+fn buy_instance_should_not_work_after_cancel_listing() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = list_first_edition(100, None);
+
+		let buyer = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		assert_noop!(
+			FragmentsPallet::cancel_listing(Origin::signed(buyer), hash, 1, 1),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(FragmentsPallet::cancel_listing(Origin::signed(owner), hash, 1, 1));
+
+		Balances::make_free_balance_be(&buyer, 1000);
+		assert_noop!(
+			FragmentsPallet::buy_instance(Origin::signed(buyer), hash, 1, 1),
+			Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn listing_should_be_removed_on_give() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = list_first_edition(100, None);
+
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		assert_ok!(FragmentsPallet::give(Origin::signed(owner), hash, 1, 1, friend, None, None));
+
+		assert!(!Listings::<Test>::contains_key((hash, 1, 1)));
+		assert!(FragmentsPallet::get_listings(hash).is_empty());
+	});
+}
+
+#[test]
+fn listing_should_be_removed_when_it_ends() {
+	new_test_ext().execute_with(|| {
+		let hash = list_first_edition(100, Some(5));
+
+		run_to_block(4);
+		assert!(Listings::<Test>::contains_key((hash, 1, 1)));
+
+		run_to_block(5);
+		assert!(!Listings::<Test>::contains_key((hash, 1, 1)));
+		assert_eq!(ListingExpirations::<Test>::iter_keys().count(), 0);
+	});
+}
+
+#[test]
+fn listing_expirations_should_carry_over_to_next_blocks() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(3),
+			None
+		));
+		// the mock removes at most 2 ended listings per block
+		for edition in 1..=3 {
+			assert_ok!(FragmentsPallet::list_instance(
+				Origin::signed(owner),
+				hash,
+				edition,
+				1,
+				100,
+				None,
+				Some(5)
+			));
+		}
+
+		run_to_block(5);
+		assert_eq!(Listings::<Test>::iter_keys().count(), 1);
+		assert_eq!(NextListingExpiration::<Test>::get(), 5);
+		// already ended even if it is not removed yet
+		assert!(FragmentsPallet::get_listings(hash).is_empty());
+
+		run_to_block(6);
+		assert_eq!(Listings::<Test>::iter_keys().count(), 0);
+		assert_eq!(ListingExpirations::<Test>::iter_keys().count(), 0);
+		assert_eq!(NextListingExpiration::<Test>::get(), 7);
+	});
+}
+
+#[test]
+fn english_auction_should_settle_edition_to_highest_bidder() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		let vault = FragmentsPallet::get_vault_id(hash);
+
+		assert_ok!(FragmentsPallet::create_auction(
+			Origin::signed(owner),
+			hash,
+			AuctionItem::Edition { amount: None },
+			AuctionKind::English { reserve: Compact(10), extension: 0 },
+			None,
+			10
+		));
+
+		let first = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let second = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		Balances::make_free_balance_be(&first, 100);
+		Balances::make_free_balance_be(&second, 100);
+
+		assert_ok!(FragmentsPallet::bid(Origin::signed(first), 0, 20));
+		assert_noop!(FragmentsPallet::bid(Origin::signed(second), 0, 20), Error::<Test>::BidTooLow);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(second), 0, 30));
+
+		// the outbid bidder is refunded
+		assert_eq!(Balances::reserved_balance(&first), 0);
+		assert_eq!(Balances::reserved_balance(&second), 30);
+
+		run_to_block(10);
+
+		assert!(!Auctions::<Test>::contains_key(0));
+		assert!(Inventory::<Test>::contains_key((second, hash, 1, 1)));
+		assert_eq!(Balances::reserved_balance(&second), 0);
+		assert_eq!(Balances::free_balance(&second), 70);
+		assert_eq!(Balances::free_balance(&vault), 99 + 30);
+	});
+}
+
+#[test]
+fn english_auction_should_be_extended_by_late_bids() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::create_auction(
+			Origin::signed(owner),
+			hash,
+			AuctionItem::Edition { amount: None },
+			AuctionKind::English { reserve: Compact(10), extension: 5 },
+			None,
+			10
+		));
+
+		let bidder = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		Balances::make_free_balance_be(&bidder, 100);
+
+		run_to_block(8);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), 0, 10));
+		assert_eq!(Auctions::<Test>::get(0).unwrap().end, 13);
+
+		run_to_block(10);
+		assert!(Auctions::<Test>::contains_key(0));
+
+		run_to_block(13);
+		assert!(!Auctions::<Test>::contains_key(0));
+		assert!(Inventory::<Test>::contains_key((bidder, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn auction_ends_should_carry_over_to_next_blocks() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		// the mock settles at most 2 ended auctions per block
+		for _ in 0..3 {
+			assert_ok!(FragmentsPallet::create_auction(
+				Origin::signed(owner),
+				hash,
+				AuctionItem::Edition { amount: None },
+				AuctionKind::English { reserve: Compact(10), extension: 0 },
+				None,
+				5
+			));
+		}
+
+		let bidder = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		Balances::make_free_balance_be(&bidder, 100);
+		for auction_id in 0..3 {
+			assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), auction_id, 10));
+		}
+
+		run_to_block(5);
+		assert_eq!(Auctions::<Test>::iter_keys().count(), 1);
+		assert_eq!(NextAuctionEnd::<Test>::get(), 5);
+		// already ended even if it is not settled yet
+		let auction_id = Auctions::<Test>::iter_keys().next().unwrap();
+		assert_noop!(
+			FragmentsPallet::bid(Origin::signed(bidder), auction_id, 20),
+			Error::<Test>::Expired
+		);
+
+		run_to_block(6);
+		assert_eq!(Auctions::<Test>::iter_keys().count(), 0);
+		assert_eq!(AuctionEnds::<Test>::iter_keys().count(), 0);
+		assert_eq!(NextAuctionEnd::<Test>::get(), 7);
+		assert_eq!(Inventory::<Test>::iter_prefix((bidder,)).count(), 3);
+		assert_eq!(Balances::reserved_balance(&bidder), 0);
+	});
+}
+
+#[test]
+fn dutch_auction_should_settle_instance_at_current_price() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_ok!(FragmentsPallet::create_auction(
+			Origin::signed(owner),
+			hash,
+			AuctionItem::Instance { edition: 1, copy: 1 },
+			AuctionKind::Dutch { start_price: Compact(100), end_price: Compact(0) },
+			None,
+			10
+		));
+
+		let bidder = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		Balances::make_free_balance_be(&bidder, 100);
+
+		System::set_block_number(5);
+		assert_noop!(FragmentsPallet::bid(Origin::signed(bidder), 0, 40), Error::<Test>::BidTooLow);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), 0, 60));
+
+		assert!(!Auctions::<Test>::contains_key(0));
+		assert!(Inventory::<Test>::contains_key((bidder, hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert_eq!(Balances::free_balance(&bidder), 50);
+		assert_eq!(Balances::free_balance(&owner), 50);
+	});
+}
+
+#[test]
+fn cancel_auction_should_not_work_with_bids() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		for _ in 0..2 {
+			assert_ok!(FragmentsPallet::create_auction(
+				Origin::signed(owner),
+				hash,
+				AuctionItem::Edition { amount: None },
+				AuctionKind::English { reserve: Compact(10), extension: 0 },
+				None,
+				10
+			));
+		}
+
+		let bidder = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), 0, 10));
+
+		assert_noop!(
+			FragmentsPallet::cancel_auction(Origin::signed(owner), 0),
+			Error::<Test>::AuctionHasBids
+		);
+		assert_ok!(FragmentsPallet::cancel_auction(Origin::signed(owner), 1));
+		assert!(!Auctions::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn instance_should_be_frozen_while_in_auction() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = list_first_edition(100, None);
+		Fragments::<Test>::mutate((hash, 1, 1), |item_data| {
+			item_data.as_mut().unwrap().permissions = FragmentPerms::TRANSFER | FragmentPerms::BURN;
+		});
+
+		assert_ok!(FragmentsPallet::create_auction(
+			Origin::signed(owner),
+			hash,
+			AuctionItem::Instance { edition: 1, copy: 1 },
+			AuctionKind::English { reserve: Compact(10), extension: 0 },
+			None,
+			10
+		));
+		// it can no longer be bought from its listing
+		assert!(!Listings::<Test>::contains_key((hash, 1, 1)));
+
+		let bidder = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(FragmentsPallet::bid(Origin::signed(bidder), 0, 20));
+
+		assert_noop!(
+			FragmentsPallet::give(Origin::signed(owner), hash, 1, 1, friend, None, None),
+			Error::<Test>::InAuction
+		);
+		assert_noop!(
+			FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::InAuction
+		);
+		assert_noop!(
+			FragmentsPallet::list_instance(Origin::signed(owner), hash, 1, 1, 100, None, None),
+			Error::<Test>::InAuction
+		);
+		assert_noop!(
+			FragmentsPallet::create_auction(
+				Origin::signed(owner),
+				hash,
+				AuctionItem::Instance { edition: 1, copy: 1 },
+				AuctionKind::English { reserve: Compact(10), extension: 0 },
+				None,
+				10
+			),
+			Error::<Test>::InAuction
+		);
+
+		run_to_block(10);
+
+		assert!(Inventory::<Test>::contains_key((bidder, hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn close_account_should_work_for_accounts_created_before_deposits_were_recorded() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		// an account created by the `create_account` of version 8, which only reserved the deposit
+		let frag_account = FragmentsPallet::get_fragment_account_id(hash, 1, 1);
+		Balances::make_free_balance_be(&frag_account, 10);
+		assert_ok!(Balances::reserve(&frag_account, 1));
+		StorageVersion::new(8).put::<FragmentsPallet>();
+
+		migration::v9::MigrateToV9::<Test>::on_runtime_upgrade();
+
+		assert_eq!(FragmentsPallet::on_chain_storage_version(), 9);
+		assert_eq!(AccountDeposits::<Test>::get((hash, 1, 1)), Some(Compact(1)));
+
+		// the account is not created a second time
+		Balances::make_free_balance_be(&owner, 100);
+		assert_noop!(
+			FragmentsPallet::create_account(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::AlreadyExist
+		);
+
+		assert_ok!(FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![]));
+		assert_eq!(Balances::total_balance(&frag_account), 0);
+		assert_eq!(Balances::free_balance(&owner), 110);
+	});
+}
+
+#[test]
+fn as_fragment_should_dispatch_as_fragment_account() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		// the owner pays for the deposit of the account
+		Balances::make_free_balance_be(&owner, 100);
+		assert_ok!(FragmentsPallet::create_account(Origin::signed(owner), hash, 1, 1));
+		assert_eq!(Balances::free_balance(&owner), 99);
+
+		let frag_account = FragmentsPallet::get_fragment_account_id(hash, 1, 1);
+		assert_eq!(Balances::reserved_balance(&frag_account), 1);
+		Balances::make_free_balance_be(&frag_account, 50);
+
+		let friend = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let call = Call::Balances(pallet_balances::Call::transfer { dest: friend, value: 10 });
+
+		assert_noop!(
+			FragmentsPallet::as_fragment(Origin::signed(friend), hash, 1, 1, Box::new(call.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(FragmentsPallet::as_fragment(Origin::signed(owner), hash, 1, 1, Box::new(call)));
+
+		assert_eq!(Balances::free_balance(&friend), 10);
+		assert_eq!(Balances::free_balance(&frag_account), 40);
+	});
+}
+
+#[test]
+fn close_account_should_sweep_funds_to_owner() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		// an account that was never created can't be closed
+		assert_noop!(
+			FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![]),
+			Error::<Test>::NotFound
+		);
+
+		Balances::make_free_balance_be(&owner, 100);
+		assert_ok!(FragmentsPallet::create_account(Origin::signed(owner), hash, 1, 1));
+		assert_noop!(
+			FragmentsPallet::create_account(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::AlreadyExist
+		);
+
+		let frag_account = FragmentsPallet::get_fragment_account_id(hash, 1, 1);
+		Balances::make_free_balance_be(&frag_account, 40);
+
+		assert_noop!(
+			FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![1; 5]),
+			Error::<Test>::TooManyItems
+		);
+
+		assert_ok!(FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![]));
+
+		// the deposit comes back too
+		assert_eq!(Balances::free_balance(&owner), 99 + 41);
+		assert_eq!(Balances::total_balance(&frag_account), 0);
+
+		assert_noop!(
+			FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![]),
+			Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn close_account_should_sweep_assets_and_keep_other_reserves() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		Balances::make_free_balance_be(&owner, 100);
+		assert_ok!(FragmentsPallet::create_account(Origin::signed(owner), hash, 1, 1));
+
+		let frag_account = FragmentsPallet::get_fragment_account_id(hash, 1, 1);
+		Balances::make_free_balance_be(&frag_account, 40);
+		// e.g. a bid that the Fragment Instance's account placed
+		assert_ok!(Balances::reserve(&frag_account, 15));
+
+		assert_ok!(Assets::force_create(Origin::root(), 1, owner, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 2, owner, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(owner), 1, frag_account, 30));
+
+		assert_ok!(FragmentsPallet::close_account(Origin::signed(owner), hash, 1, 1, vec![1, 2]));
+
+		assert_eq!(Assets::balance(1, &owner), 30);
+		assert_eq!(Assets::balance(1, &frag_account), 0);
+		assert_eq!(Assets::balance(2, &owner), 0);
+
+		// only the deposit is unreserved
+		assert_eq!(Balances::reserved_balance(&frag_account), 15);
+		assert_eq!(Balances::free_balance(&frag_account), 0);
+		assert_eq!(Balances::free_balance(&owner), 99 + 1 + 25);
+	});
+}
+
+#[test]
+fn transfer_from_should_work_for_approved_spender() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let spender = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let to = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::transfer_from(Origin::signed(spender), hash, 1, 1, owner, to, None, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			FragmentsPallet::approve(Origin::signed(spender), hash, 1, 1, Some(spender)),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(FragmentsPallet::approve(Origin::signed(owner), hash, 1, 1, Some(spender)));
+		assert_ok!(FragmentsPallet::transfer_from(
+			Origin::signed(spender),
+			hash,
+			1,
+			1,
+			owner,
+			to,
+			None,
+			None
+		));
+
+		assert!(Inventory::<Test>::contains_key((to, hash, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		// the approval is cleared with the transfer
+		assert!(!Approvals::<Test>::contains_key((hash, 1, 1)));
+		assert_noop!(
+			FragmentsPallet::transfer_from(Origin::signed(spender), hash, 1, 1, to, owner, None, None),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_from_should_use_up_approval_of_copyable_instance() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let spender = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let to = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER | FragmentPerms::COPY, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_ok!(FragmentsPallet::approve(Origin::signed(owner), hash, 1, 1, Some(spender)));
+		assert_ok!(FragmentsPallet::transfer_from(
+			Origin::signed(spender),
+			hash,
+			1,
+			1,
+			owner,
+			to,
+			None,
+			None
+		));
+
+		// `to` got a copy, the owner keeps the original but not the approval
+		assert!(Inventory::<Test>::contains_key((to, hash, 1, 2)));
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		assert!(!Approvals::<Test>::contains_key((hash, 1, 1)));
+		assert_noop!(
+			FragmentsPallet::transfer_from(
+				Origin::signed(spender),
+				hash,
+				1,
+				1,
+				owner,
+				to,
+				None,
+				None
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_from_should_work_for_operator() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let operator = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let to = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+
+		assert_ok!(FragmentsPallet::set_approval_for_all(
+			Origin::signed(owner),
+			hash,
+			operator,
+			true
+		));
+		assert_ok!(FragmentsPallet::transfer_from(
+			Origin::signed(operator),
+			hash,
+			1,
+			1,
+			owner,
+			to,
+			None,
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((to, hash, 1, 1)));
+
+		assert_ok!(FragmentsPallet::set_approval_for_all(
+			Origin::signed(owner),
+			hash,
+			operator,
+			false
+		));
+		assert_noop!(
+			FragmentsPallet::transfer_from(Origin::signed(operator), hash, 2, 1, owner, to, None, None),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn approve_should_not_work_if_not_transferable() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let spender = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::approve(Origin::signed(owner), hash, 1, 1, Some(spender)),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn mint_to_many_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let players: Vec<_> =
+			(5u8..8).map(|i| sp_core::ed25519::Public::from_raw([i; 32])).collect();
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_noop!(
+			FragmentsPallet::mint_to_many(Origin::signed(players[0]), hash, players.clone(), None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			FragmentsPallet::mint_to_many(
+				Origin::signed(owner),
+				hash,
+				[players.clone(), players.clone()].concat(),
+				None
+			),
+			Error::<Test>::TooManyItems
+		);
+
+		assert_ok!(FragmentsPallet::mint_to_many(Origin::signed(owner), hash, players.clone(), None));
+
+		for (edition, player) in players.iter().enumerate() {
+			let edition = edition as u64 + 1;
+			assert!(Inventory::<Test>::contains_key((*player, hash, edition, 1)));
+			assert!(Owners::<Test>::contains_key((hash, *player, edition, 1)));
+		}
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn give_many_should_give_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let player = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let other = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+
+		// the last instance does not exist, so nothing is given
+		assert_noop!(
+			FragmentsPallet::give_many(
+				Origin::signed(owner),
+				vec![(hash, 1, 1, player), (hash, 2, 1, other), (hash, 3, 1, other)]
+			),
+			Error::<Test>::NotFound
+		);
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+
+		assert_ok!(FragmentsPallet::give_many(
+			Origin::signed(owner),
+			vec![(hash, 1, 1, player), (hash, 2, 1, other)]
+		));
+		assert!(Inventory::<Test>::contains_key((player, hash, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((other, hash, 2, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 2, 1)));
+	});
+}
+
+#[test]
+fn mint_to_should_issue_non_transferable_instances() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let player = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_noop!(
+			FragmentsPallet::mint_to(
+				Origin::signed(player),
+				hash,
+				player,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(FragmentsPallet::mint_to(
+			Origin::signed(owner),
+			hash,
+			player,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert!(Inventory::<Test>::contains_key((player, hash, 1, 1)));
+		assert!(Owners::<Test>::contains_key((hash, player, 1, 1)));
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn mint_should_work_for_minters() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let minter = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let player = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		let terms =
+			|quota: u64| Some(MinterTerms { quota: Some(Compact(quota)), expiration: None });
+
+		assert_noop!(
+			FragmentsPallet::set_minter(Origin::signed(minter), hash, minter, terms(3)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			FragmentsPallet::set_minter(Origin::signed(owner), hash, minter, terms(0)),
+			Error::<Test>::ParamsNotValid
+		);
+		assert_ok!(FragmentsPallet::set_minter(Origin::signed(owner), hash, minter, terms(3)));
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(minter),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((minter, hash, 2, 1)));
+		assert_eq!(Minters::<Test>::get(hash, minter), terms(1));
+
+		assert_noop!(
+			FragmentsPallet::mint_to_many(Origin::signed(minter), hash, vec![player, player], None),
+			Error::<Test>::MinterQuotaExceeded
+		);
+		assert_ok!(FragmentsPallet::mint_to(
+			Origin::signed(minter),
+			hash,
+			player,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((player, hash, 3, 1)));
+		assert_noop!(
+			FragmentsPallet::mint_to(
+				Origin::signed(minter),
+				hash,
+				player,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::MinterQuotaExceeded
+		);
+
+		// the owner of the proto is not limited by the quotas of the minters
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_ok!(FragmentsPallet::set_minter(Origin::signed(owner), hash, minter, None));
+		assert!(!Minters::<Test>::contains_key(hash, minter));
+		assert_noop!(
+			FragmentsPallet::mint(
+				Origin::signed(minter),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn mint_should_not_work_for_expired_minters() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let minter = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::set_minter(
+			Origin::signed(owner),
+			hash,
+			minter,
+			Some(MinterTerms { quota: None, expiration: Some(5) })
+		));
+
+		run_to_block(4);
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(minter),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		// no quota, nothing to count
+		assert_eq!(Minters::<Test>::get(hash, minter).unwrap().quota, None);
+
+		run_to_block(5);
+		assert_noop!(
+			FragmentsPallet::mint(
+				Origin::signed(minter),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::Expired
+		);
+		assert_noop!(
+			FragmentsPallet::set_minter(
+				Origin::signed(owner),
+				hash,
+				minter,
+				Some(MinterTerms { quota: None, expiration: Some(5) })
+			),
+			Error::<Test>::ParamsNotValid
+		);
+	});
+}
+
+#[test]
+fn buy_for_should_charge_buyer_and_credit_recipient() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		let vault = FragmentsPallet::get_vault_id(hash);
+
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		Balances::make_free_balance_be(&buyer, 100);
+		assert_ok!(FragmentsPallet::buy_for(
+			Origin::signed(buyer),
+			hash,
+			friend,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+
+		assert!(Inventory::<Test>::contains_key((friend, hash, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((friend, hash, 2, 1)));
+		assert!(!Inventory::<Test>::contains_key((buyer, hash, 1, 1)));
+		assert_eq!(Balances::free_balance(&buyer), 80);
+		assert_eq!(Balances::free_balance(&vault), 100 - 1 + 20);
+	});
+}
+
+#[test]
+fn buy_should_check_merkle_allowlist_and_max_per_account() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		// Merkle tree of `buyer` and `friend`
+		let buyer_leaf = blake2_256(&buyer.encode());
+		let friend_leaf = blake2_256(&friend.encode());
+		let root = if buyer_leaf <= friend_leaf {
+			blake2_256(&[buyer_leaf, friend_leaf].concat())
+		} else {
+			blake2_256(&[friend_leaf, buyer_leaf].concat())
+		};
+
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			None,
+			None,
+			Some(Allowlist::MerkleRoot(root)),
+			Some(2),
+			None,
+			vec![],
+			None
+		));
+
+		Balances::make_free_balance_be(&buyer, 100);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				Some(vec![buyer_leaf])
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			Some(vec![friend_leaf])
+		));
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				Some(vec![friend_leaf])
+			),
+			Error::<Test>::MaxPerAccountReached
+		);
+
+		// purchases of the previous sale are not counted in the next one
+		assert_ok!(FragmentsPallet::unpublish(Origin::signed(owner), hash));
+		run_to_block(System::block_number() + 1);
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			None,
+			None,
+			Some(Allowlist::MerkleRoot(root)),
+			Some(2),
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			Some(vec![friend_leaf])
+		));
+		assert_eq!(Purchases::<Test>::get(hash, buyer), Some((System::block_number(), Compact(1))));
+		assert!(Inventory::<Test>::contains_key((buyer, hash, 3, 1)));
+	});
+}
+
+#[test]
+fn buy_for_should_check_allowlist_of_recipient() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_noop!(
+			FragmentsPallet::set_allowlist(Origin::signed(buyer), hash, vec![buyer], true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(FragmentsPallet::set_allowlist(Origin::signed(owner), hash, vec![friend], true));
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			None,
+			None,
+			Some(Allowlist::Accounts),
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		Balances::make_free_balance_be(&buyer, 100);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(FragmentsPallet::buy_for(
+			Origin::signed(buyer),
+			hash,
+			friend,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((friend, hash, 1, 1)));
+
+		assert_ok!(FragmentsPallet::set_allowlist(
+			Origin::signed(owner),
+			hash,
+			vec![friend],
+			false
+		));
+		assert_noop!(
+			FragmentsPallet::buy_for(
+				Origin::signed(buyer),
+				hash,
+				friend,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn buy_should_not_work_before_starts_at() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		run_to_block(2);
+
+		assert_noop!(
+			FragmentsPallet::publish(
+				Origin::signed(owner),
+				hash,
+				10,
+				None,
+				Some(10),
+				None,
+				None,
+				None,
+				Some(1),
+				vec![],
+				None
+			),
+			Error::<Test>::ParamsNotValid
+		);
+		assert_noop!(
+			FragmentsPallet::publish(
+				Origin::signed(owner),
+				hash,
+				10,
+				None,
+				Some(10),
+				None,
+				None,
+				None,
+				Some(10),
+				vec![],
+				None
+			),
+			Error::<Test>::ParamsNotValid
+		);
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			Some(10),
+			None,
+			None,
+			None,
+			Some(5),
+			vec![],
+			None
+		));
+
+		Balances::make_free_balance_be(&buyer, 100);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NotStarted
+		);
+
+		run_to_block(5);
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert!(Inventory::<Test>::contains_key((buyer, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn buy_should_not_overflow_with_huge_price() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			u64::MAX.into(),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		// the price plus the existential deposit doesn't fit in a balance
+		Balances::make_free_balance_be(&buyer, u64::MAX);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn buy_should_follow_sale_phases() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let friend = sp_core::ed25519::Public::from_raw([6u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		run_to_block(1);
+
+		let public_phase = SalePhase {
+			starts_at: 5,
+			price: Compact(10),
+			allowlist: None,
+			max_per_account: Some(Compact(2)),
+		};
+		assert_noop!(
+			FragmentsPallet::publish(
+				Origin::signed(owner),
+				hash,
+				5,
+				None,
+				None,
+				None,
+				Some(Allowlist::Accounts),
+				None,
+				Some(5),
+				vec![public_phase.clone()],
+				None
+			),
+			Error::<Test>::ParamsNotValid
+		);
+
+		assert_ok!(FragmentsPallet::set_allowlist(Origin::signed(owner), hash, vec![buyer], true));
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			5,
+			None,
+			None,
+			None,
+			Some(Allowlist::Accounts),
+			None,
+			None,
+			vec![public_phase],
+			None
+		));
+
+		let current_phase = || {
+			let result = FragmentsPallet::get_definitions(GetDefinitionsParams {
+				desc: false,
+				from: 0,
+				limit: 10,
+				proto_hash: None,
+				creator: None,
+				on_sale: true,
+				metadata_keys: vec![],
+			})
+			.unwrap();
+			serde_json::from_slice::<serde_json::Value>(&result).unwrap()[0]["sale"]
+				["current_phase"]
+				.clone()
+		};
+
+		// allowlist phase
+		Balances::make_free_balance_be(&buyer, 100);
+		Balances::make_free_balance_be(&friend, 100);
+		assert_eq!(current_phase(), 0);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(friend),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+		assert_eq!(Balances::free_balance(&buyer), 90);
+
+		// public phase, the purchases of the allowlist phase are counted
+		run_to_block(5);
+		assert_eq!(current_phase(), 1);
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(friend),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_eq!(Balances::free_balance(&friend), 90);
+		assert_noop!(
+			FragmentsPallet::buy(
+				Origin::signed(buyer),
+				hash,
+				FragmentBuyOptions::Quantity(1),
+				None
+			),
+			Error::<Test>::MaxPerAccountReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_price_bonding_curves() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		Balances::make_free_balance_be(&buyer, 1_000);
+
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			Some(PriceCurve::Linear { increment: Compact(5) })
+		));
+		// 10 + 15 + 20
+		assert_eq!(FragmentsPallet::quote_price(hash, 3), Some(45));
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(3),
+			None
+		));
+		assert_eq!(Balances::free_balance(&buyer), 1_000 - 45);
+		// 25 + 30, the curve moved on
+		assert_eq!(FragmentsPallet::quote_price(hash, 2), Some(55));
+
+		// a new sale starts a new curve
+		assert_ok!(FragmentsPallet::unpublish(Origin::signed(owner), hash));
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			100,
+			Some(3),
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			Some(PriceCurve::Exponential { rate: Permill::from_percent(50) })
+		));
+		// 100 + 150 + 225
+		assert_eq!(FragmentsPallet::quote_price(hash, 3), Some(475));
+		assert_eq!(FragmentsPallet::quote_price(hash, 4), None);
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+		assert_eq!(Balances::free_balance(&buyer), 1_000 - 45 - 475);
+
+		// quotes don't depend on the quantity asked for, even without a limit
+		assert_ok!(FragmentsPallet::unpublish(Origin::signed(owner), hash));
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			100,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			Some(PriceCurve::Exponential { rate: Permill::from_percent(50) })
+		));
+		assert!(FragmentsPallet::quote_price(hash, u64::MAX).unwrap() > u64::MAX as u128);
+	});
+}
+
+#[test]
+fn buy_should_price_dutch_decay() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let buyer = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		Balances::make_free_balance_be(&buyer, 1_000);
+		run_to_block(1);
+
+		assert_noop!(
+			FragmentsPallet::publish(
+				Origin::signed(owner),
+				hash,
+				100,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				vec![],
+				Some(PriceCurve::Decay { floor: Compact(101), duration: 8 })
+			),
+			Error::<Test>::ParamsNotValid
+		);
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			100,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			Some(PriceCurve::Decay { floor: Compact(20), duration: 8 })
+		));
+		assert_eq!(FragmentsPallet::quote_price(hash, 1), Some(100));
+
+		// half way through the decay
+		run_to_block(5);
+		assert_eq!(FragmentsPallet::quote_price(hash, 2), Some(120));
+		assert_ok!(FragmentsPallet::buy(
+			Origin::signed(buyer),
+			hash,
+			FragmentBuyOptions::Quantity(2),
+			None
+		));
+		assert_eq!(Balances::free_balance(&buyer), 1_000 - 120);
+
+		run_to_block(20);
+		assert_eq!(FragmentsPallet::quote_price(hash, 1), Some(20));
+	});
+}
+
+#[test]
+fn renew_should_extend_expiration() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+		let vault = FragmentsPallet::get_vault_id(hash);
+
+		assert_ok!(FragmentsPallet::mint_fragments(
+			&owner,
+			&hash,
+			None,
+			&FragmentBuyOptions::Quantity(1),
+			1,
+			0,
+			Some(10),
+			None
+		));
+
+		// no renewal terms
+		assert_noop!(
+			FragmentsPallet::renew(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::NoPermission
+		);
+
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().renewal = Some(Renewal { price: Compact(5), period: 20 });
+		});
+
+		Balances::make_free_balance_be(&owner, 100);
+		assert_ok!(FragmentsPallet::renew(Origin::signed(owner), hash, 1, 1));
+
+		assert_eq!(Fragments::<Test>::get((hash, 1, 1)).unwrap().expiring_at, Some(30));
+		assert!(!Expirations::<Test>::contains_key((10, hash, 1, 1)));
+		assert!(Expirations::<Test>::contains_key((30, hash, 1, 1)));
+		assert_eq!(Balances::free_balance(&owner), 95);
+		assert_eq!(Balances::free_balance(&vault), 99 + 5);
+
+		// the old expiration no longer applies
+		run_to_block(10);
+		assert!(Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+		run_to_block(30);
+		assert!(!Inventory::<Test>::contains_key((owner, hash, 1, 1)));
+	});
+}
+
+#[test]
+fn renew_should_not_work_if_not_expiring() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		Definitions::<Test>::mutate(hash, |definition| {
+			definition.as_mut().unwrap().renewal = Some(Renewal { price: Compact(5), period: 20 });
+		});
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::renew(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::ParamsNotValid
+		);
+	});
+}
+
+#[test]
+fn instance_owner_should_follow_transfers() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let friend = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER | FragmentPerms::BURN, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+		assert_eq!(InstanceOwner::<Test>::get((hash, 1, 1)), Some(owner));
+
+		assert_ok!(FragmentsPallet::give(Origin::signed(owner), hash, 1, 1, friend, None, None));
+		assert_eq!(InstanceOwner::<Test>::get((hash, 1, 1)), Some(friend));
+
+		assert_ok!(FragmentsPallet::burn(Origin::signed(friend), hash, 1, 1));
+		assert_eq!(InstanceOwner::<Test>::get((hash, 1, 1)), None);
+	});
+}
+
+#[test]
+fn expirations_should_carry_over_to_next_blocks() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		// the mock removes at most 2 expired Fragment Instances per block
+		assert_ok!(FragmentsPallet::mint_fragments(
+			&owner,
+			&hash,
+			None,
+			&FragmentBuyOptions::Quantity(3),
+			3,
+			0,
+			Some(5),
+			None
+		));
+
+		run_to_block(4);
+		assert_eq!(Fragments::<Test>::iter_keys().count(), 3);
+
+		run_to_block(5);
+		assert_eq!(Fragments::<Test>::iter_keys().count(), 1);
+		assert_eq!(NextExpiration::<Test>::get(), 5);
+		// already expired even if it is not removed yet
+		assert_noop!(
+			FragmentsPallet::burn(Origin::signed(owner), hash, 3, 1),
+			Error::<Test>::NotFound
+		);
+
+		run_to_block(6);
+		assert_eq!(Fragments::<Test>::iter_keys().count(), 0);
+		assert_eq!(Expirations::<Test>::iter_keys().count(), 0);
+		assert_eq!(Owners::<Test>::iter_keys().count(), 0);
+		assert_eq!(InstanceOwner::<Test>::iter_keys().count(), 0);
+		assert_eq!(NextExpiration::<Test>::get(), 7);
+	});
+}
+
+#[test]
+fn get_definitions_should_filter_by_proto_and_sale() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		let query = |on_sale: bool| {
+			let result = FragmentsPallet::get_definitions(GetDefinitionsParams {
+				desc: false,
+				from: 0,
+				limit: 10,
+				proto_hash: Some(hex::encode(PROTO_HASH).into_bytes()),
+				creator: Some(owner),
+				on_sale,
+				metadata_keys: vec![],
+			})
+			.unwrap();
+			serde_json::from_slice::<serde_json::Value>(&result).unwrap()
+		};
+
+		let definitions = query(false);
+		assert_eq!(definitions[0]["id"], hex::encode(hash));
+		assert_eq!(definitions[0]["name"], "name");
+		assert!(definitions[0]["sale"].is_null());
+		assert_eq!(query(true).as_array().unwrap().len(), 0);
+
+		assert_ok!(FragmentsPallet::publish(
+			Origin::signed(owner),
+			hash,
+			10,
+			Some(5),
+			None,
+			None,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		let definitions = query(true);
+		assert_eq!(definitions.as_array().unwrap().len(), 1);
+		assert_eq!(definitions[0]["sale"]["price"], "10");
+		assert_eq!(definitions[0]["sale"]["units_left"], 5);
+
+		assert!(FragmentsPallet::get_definitions(GetDefinitionsParams {
+			desc: false,
+			from: 0,
+			limit: 10,
+			proto_hash: Some(b"not hex".to_vec()),
+			creator: None,
+			on_sale: false,
+			metadata_keys: vec![],
+		})
+		.is_err());
+	});
+}
+
+#[test]
+fn set_definition_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let stranger = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_noop!(
+			FragmentsPallet::set_definition_metadata(
+				Origin::signed(stranger),
+				hash,
+				b"description".to_vec(),
+				b"a sword".to_vec()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(FragmentsPallet::set_definition_metadata(
+			Origin::signed(owner),
+			hash,
+			b"description".to_vec(),
+			b"a sword".to_vec()
+		));
+		// replacing the metadata does not intern the key again
+		assert_ok!(FragmentsPallet::set_definition_metadata(
+			Origin::signed(owner),
+			hash,
+			b"description".to_vec(),
+			b"a sharp sword".to_vec()
+		));
+
+		let key_index = pallet_protos::MetaKeys::<Test>::get(
+			pallet_protos::MetadataKey::try_from(b"description".to_vec()).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(pallet_protos::MetaKeysIndex::<Test>::get(), key_index);
+		assert_eq!(
+			DefinitionMetadata::<Test>::get(hash, Compact(key_index)),
+			Some(blake2_256(b"a sharp sword"))
+		);
+		// the Fragment Definition ID does not change
+		assert!(Definitions::<Test>::contains_key(hash));
+
+		let result = FragmentsPallet::get_definitions(GetDefinitionsParams {
+			desc: false,
+			from: 0,
+			limit: 10,
+			proto_hash: None,
+			creator: None,
+			on_sale: false,
+			metadata_keys: vec![b"description".to_vec(), b"image".to_vec()],
+		})
+		.unwrap();
+		let definitions = serde_json::from_slice::<serde_json::Value>(&result).unwrap();
+		assert_eq!(
+			definitions[0]["metadata"]["description"],
+			hex::encode(blake2_256(b"a sharp sword"))
+		);
+		assert!(definitions[0]["metadata"]["image"].is_null());
+	});
+}
+
+#[test]
+fn get_instances_and_get_owners_should_return_inventories() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let friend = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(3),
+			None
+		));
+		assert_ok!(FragmentsPallet::give(Origin::signed(owner), hash, 2, 1, friend, None, None));
+
+		let instances = |owner: Option<sp_core::ed25519::Public>, from: u64| {
+			let result = FragmentsPallet::get_instances(GetInstancesParams {
+				from,
+				limit: 10,
+				definition_hash: Some(hex::encode(hash).into_bytes()),
+				owner,
+				metadata_keys: vec![],
+			})
+			.unwrap();
+			serde_json::from_slice::<serde_json::Value>(&result).unwrap()
+		};
+
+		assert_eq!(instances(None, 0).as_array().unwrap().len(), 3);
+		assert_eq!(instances(None, 2).as_array().unwrap().len(), 1);
+		let inventory = instances(Some(friend), 0);
+		assert_eq!(inventory.as_array().unwrap().len(), 1);
+		assert_eq!(inventory[0]["edition"], 2);
+		assert_eq!(inventory[0]["owner"], hex::encode(friend.encode()));
+
+		assert!(FragmentsPallet::get_instances(GetInstancesParams {
+			from: 0,
+			limit: 10,
+			definition_hash: None,
+			owner: None,
+			metadata_keys: vec![],
+		})
+		.is_err());
+
+		let result = FragmentsPallet::get_owners(GetOwnersParams {
+			from: 0,
+			limit: 10,
+			definition_hash: hex::encode(hash).into_bytes(),
+		})
+		.unwrap();
+		let owners = serde_json::from_slice::<serde_json::Value>(&result).unwrap();
+		let owners = owners.as_array().unwrap();
+		assert_eq!(owners.len(), 2);
+		let owned = |account: sp_core::ed25519::Public| {
+			owners
+				.iter()
+				.find(|entry| entry["owner"] == hex::encode(account.encode()))
+				.map(|entry| entry["instances"].as_array().unwrap().len())
+		};
+		assert_eq!(owned(owner), Some(2));
+		assert_eq!(owned(friend), Some(1));
+
+		let result = FragmentsPallet::get_owners(GetOwnersParams {
+			from: 1,
+			limit: 10,
+			definition_hash: hex::encode(hash).into_bytes(),
+		})
+		.unwrap();
+		let owners = serde_json::from_slice::<serde_json::Value>(&result).unwrap();
+		assert_eq!(owners.as_array().unwrap().len(), 1);
+	});
+}
+
+#[test]
+fn detach_should_freeze_instance() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let friend = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER | FragmentPerms::EDIT, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_ok!(FragmentsPallet::detach(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			SupportedChains::EthereumGoerli,
+			vec![7u8; 20]
+		));
+		// frozen before the offchain worker finalizes the detach
+		assert_eq!(DetachedInstances::<Test>::get((hash, 1, 1)), Some(None));
+		assert_eq!(DetachRequests::<Test>::get()[0].hash, DetachHash::Instance(hash, 1, 1, 1));
+
+		assert_noop!(
+			FragmentsPallet::give(Origin::signed(owner), hash, 1, 1, friend, None, None),
+			Error::<Test>::Detached
+		);
+		assert_noop!(
+			FragmentsPallet::burn(Origin::signed(owner), hash, 1, 1),
+			Error::<Test>::Detached
+		);
+		assert_noop!(
+			FragmentsPallet::detach(
+				Origin::signed(owner),
+				hash,
+				1,
+				1,
+				SupportedChains::EthereumGoerli,
+				vec![7u8; 20]
+			),
+			Error::<Test>::Detached
+		);
+	});
+}
+
+#[test]
+fn detach_should_time_out_if_never_finalized() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let friend = sp_core::ed25519::Public::from_raw([5u8; 32]);
+		let hash = create_definition(FragmentPerms::TRANSFER, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			DetachPallet::cancel_detach(Origin::signed(friend), hash, 1, 1),
+			pallet_detach::Error::<Test>::NotPending
+		);
+
+		assert_ok!(FragmentsPallet::detach(
+			Origin::signed(owner),
+			hash,
+			1,
+			1,
+			SupportedChains::EthereumGoerli,
+			vec![7u8; 20]
+		));
+
+		let timeout_at = System::block_number() + 10;
+		assert_eq!(PendingDetaches::<Test>::get((hash, 1, 1)), Some(timeout_at));
+		assert_noop!(
+			DetachPallet::cancel_detach(Origin::signed(friend), hash, 1, 1),
+			pallet_detach::Error::<Test>::NotTimedOut
+		);
+
+		run_to_block(timeout_at);
+		assert_ok!(DetachPallet::cancel_detach(Origin::signed(friend), hash, 1, 1));
+		assert!(!DetachedInstances::<Test>::contains_key((hash, 1, 1)));
+		assert!(!PendingDetaches::<Test>::contains_key((hash, 1, 1)));
+
+		// a cancelled detach can no longer be finalized
+		assert_noop!(
+			DetachPallet::internal_finalize_detach(
+				Origin::none(),
+				DetachInternalData {
+					public: owner,
+					hash: DetachHash::Instance(hash, 1, 1, 1),
+					target_chain: SupportedChains::EthereumGoerli,
+					target_account: vec![7u8; 20].try_into().unwrap(),
+					remote_signature: vec![],
+					nonce: 1,
+				},
+				sp_core::ed25519::Signature::from_raw([0u8; 64]),
+			),
+			pallet_detach::Error::<Test>::NotPending
+		);
+
+		// the Fragment Instance is no longer frozen
+		assert_ok!(FragmentsPallet::give(Origin::signed(owner), hash, 1, 1, friend, None, None));
+	});
+}
+
+#[test]
+fn detach_should_not_work_if_not_transferable() {
+	new_test_ext().execute_with(|| {
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let hash = create_definition(FragmentPerms::NONE, None, false);
+
+		assert_ok!(FragmentsPallet::mint(
+			Origin::signed(owner),
+			hash,
+			FragmentBuyOptions::Quantity(1),
+			None
+		));
+
+		assert_noop!(
+			FragmentsPallet::detach(
+				Origin::signed(owner),
+				hash,
+				1,
+				1,
+				SupportedChains::EthereumGoerli,
+				vec![7u8; 20]
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn migrations_should_upgrade_version_0_storage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		StorageVersion::new(0).put::<FragmentsPallet>();
+
+		let pallet = <FragmentsPallet as PalletInfoAccess>::name().as_bytes();
+		let owner = sp_core::ed25519::Public::from_raw(PUBLIC);
+		let proto_hash = [1u8; 32];
+		let class = [2u8; 16];
+		let ids = vec![(Compact(1u64), Compact(1u64))];
+
+		// the layouts of version 0
+		put_storage_value(pallet, b"Proto2Fragments", &proto_hash, vec![class]);
+		put_storage_value(
+			pallet,
+			b"Owners",
+			&[&class[..], &Twox64Concat::hash(&owner.encode())].concat(),
+			ids.clone(),
+		);
+		put_storage_value(
+			pallet,
+			b"Inventory",
+			&[&Twox64Concat::hash(&owner.encode()), &class[..]].concat(),
+			ids,
+		);
+		put_storage_value(
+			pallet,
+			b"Expirations",
+			&Twox64Concat::hash(&5u64.encode()),
+			vec![(class, Compact(1u64), Compact(1u64))],
+		);
+		put_storage_value(
+			pallet,
+			b"Definitions",
+			&class,
+			(
+				proto_hash,
+				FragmentMetadata::<u32> {
+					name: b"name".to_vec().try_into().unwrap(),
+					currency: None,
+				},
+				FragmentPerms::TRANSFER,
+				None::<UniqueOptions>,
+				Some(Compact(10u64)),
+				owner,
+				1u64,
+			),
+		);
+		put_storage_value(
+			pallet,
+			b"Publishing",
+			&class,
+			(Compact(100u128), Some(Compact(5u64)), None::<u64>, None::<Compact<u64>>),
+		);
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		migration::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+		migration::v5::MigrateToV5::<Test>::on_runtime_upgrade();
+		migration::v6::MigrateToV6::<Test>::on_runtime_upgrade();
+		migration::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+		migration::v8::MigrateToV8::<Test>::on_runtime_upgrade();
+		migration::v9::MigrateToV9::<Test>::on_runtime_upgrade();
+
+		assert_eq!(FragmentsPallet::on_chain_storage_version(), 9);
+
+		assert!(Proto2Fragments::<Test>::contains_key(proto_hash, class));
+		assert!(Owners::<Test>::contains_key((class, owner, 1, 1)));
+		assert!(Inventory::<Test>::contains_key((owner, class, 1, 1)));
+		assert!(Expirations::<Test>::contains_key((5, class, 1, 1)));
+		assert_eq!(InstanceOwner::<Test>::get((class, 1, 1)), Some(owner));
+		assert_eq!(NextExpiration::<Test>::get(), 3);
+		assert_eq!(NextListingExpiration::<Test>::get(), 3);
+		assert_eq!(NextAuctionEnd::<Test>::get(), 3);
+
+		let definition = Definitions::<Test>::get(class).unwrap();
+		assert_eq!(definition.proto_hash, proto_hash);
+		assert_eq!(definition.max_supply, Some(Compact(10)));
+		assert!(!definition.burns_free_supply);
+		assert!(definition.royalty.is_none());
+		assert!(definition.renewal.is_none());
+		assert!(!definition.revocable);
+		assert_eq!(definition.creator, owner);
+
+		let sale = Publishing::<Test>::get(class).unwrap();
+		assert_eq!(sale.price, Compact(100));
+		assert_eq!(sale.units_left, Some(Compact(5)));
+		assert_eq!(sale.starts_at, None);
+		assert_eq!(sale.published_at, 3);
+		assert!(sale.curve.is_none());
+		assert_eq!(sale.sold, Compact(0));
+
+		// running them again does nothing
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		migration::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+		assert_eq!(Definitions::<Test>::get(class).unwrap().creator, owner);
+		assert_eq!(Owners::<Test>::iter_keys().count(), 1);
+	});
+}
@@ -15,15 +15,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_fragments
+//! Weights for pallet_fragments
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-01-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! NOTE: these weights are estimates that follow `benchmarking.rs`, they were NOT produced by the benchmark CLI.
+//! Regenerate this file with the command below (on the reference hardware) before relying on them.
 
-// Executed Command:
+// Command to regenerate it:
 // ./target/release/clamor
 // benchmark
+// pallet
 // --chain=dev
 // --execution=wasm
 // --wasm-execution=compiled
@@ -45,6 +45,44 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_fragments.
 pub trait WeightInfo {
 	fn create() -> Weight;
+	fn publish(p: u32) -> Weight;
+	fn unpublish() -> Weight;
+	fn mint_quantity(q: u32) -> Weight;
+	fn mint_unique(s: u32) -> Weight;
+	fn buy_quantity(q: u32) -> Weight;
+	fn buy_unique(s: u32) -> Weight;
+	fn give() -> Weight;
+	fn create_account() -> Weight;
+	fn burn() -> Weight;
+	fn split() -> Weight;
+	fn merge() -> Weight;
+	fn consume() -> Weight;
+	fn update_unique_data(s: u32) -> Weight;
+	fn withdraw_proceeds() -> Weight;
+	fn list_instance() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn buy_instance() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn as_fragment() -> Weight;
+	fn close_account(n: u32) -> Weight;
+	fn approve() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn transfer_from() -> Weight;
+	fn mint_to_many(n: u32) -> Weight;
+	fn give_many(n: u32) -> Weight;
+	fn renew() -> Weight;
+	fn process_expirations(n: u32) -> Weight;
+	fn process_listing_expirations(n: u32) -> Weight;
+	fn process_auction_ends(n: u32) -> Weight;
+	fn detach() -> Weight;
+	fn set_allowlist(n: u32) -> Weight;
+	fn set_definition_metadata(s: u32) -> Weight;
+	fn set_game_authority() -> Weight;
+	fn set_instance_metadata(s: u32) -> Weight;
+	fn set_minter() -> Weight;
+	fn revoke() -> Weight;
 }
 
 /// Weights for pallet_fragments using the Substrate node and recommended hardware.
@@ -59,6 +97,502 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:0)
+	// Storage: Fragments SalePhases (r:0 w:1)
+	fn publish(p: u32) -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SalePhases (r:0 w:1)
+	fn unpublish() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_quantity(q: u32) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_unique(s: u32) -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SaleAllowlists (r:1 w:0)
+	// Storage: Fragments Purchases (r:1 w:1)
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments SalePhases (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn buy_quantity(q: u32) -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SaleAllowlists (r:1 w:0)
+	// Storage: Fragments Purchases (r:1 w:1)
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments SalePhases (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn buy_unique(s: u32) -> Weight {
+		(87_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments CopiesCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	fn give() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments AccountDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn create_account() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn burn() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:2)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments CopiesCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn split() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Fragments Fragments (r:3 w:2)
+	// Storage: Fragments Inventory (r:2 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn merge() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn consume() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn update_unique_data(s: u32) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_proceeds() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn list_instance() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	fn cancel_listing() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	fn buy_instance() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:1)
+	// Storage: Fragments NextAuctionId (r:1 w:1)
+	// Storage: Fragments Auctions (r:0 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn create_auction() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn bid() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	fn cancel_auction() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn as_fragment() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments AccountDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn close_account(n: u32) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((41_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn approve() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments Operators (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Approvals (r:1 w:1)
+	// Storage: Fragments Operators (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn transfer_from() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_to_many(n: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn give_many(n: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Fragments Expirations (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn renew() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments NextExpiration (r:1 w:1)
+	// Storage: Fragments Expirations (r:1 w:1)
+	// Storage: Fragments InstanceOwner (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn process_expirations(n: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments NextListingExpiration (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:1 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn process_listing_expirations(n: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments NextAuctionEnd (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:1 w:1)
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn process_auction_ends(n: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((92_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((14 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Detach DetachedInstances (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Detach DetachRequests (r:1 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Detach PendingDetaches (r:0 w:1)
+	fn detach() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments SaleAllowlists (r:0 w:1)
+	fn set_allowlist(n: u32) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos MetaKeys (r:1 w:1)
+	// Storage: Protos MetaKeysIndex (r:1 w:1)
+	// Storage: Fragments DefinitionMetadata (r:0 w:1)
+	fn set_definition_metadata(s: u32) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments GameAuthorities (r:0 w:1)
+	fn set_game_authority() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments GameAuthorities (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos MetaKeys (r:1 w:1)
+	// Storage: Protos MetaKeysIndex (r:1 w:1)
+	// Storage: Fragments InstanceMetadata (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn set_instance_metadata(s: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments Minters (r:0 w:1)
+	fn set_minter() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments InstanceOwner (r:1 w:1)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:1)
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -72,4 +606,501 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:0)
+	// Storage: Fragments SalePhases (r:0 w:1)
+	fn publish(p: u32) -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SalePhases (r:0 w:1)
+	fn unpublish() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_quantity(q: u32) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_unique(s: u32) -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SaleAllowlists (r:1 w:0)
+	// Storage: Fragments Purchases (r:1 w:1)
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments SalePhases (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn buy_quantity(q: u32) -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: Fragments Publishing (r:1 w:1)
+	// Storage: Fragments SaleAllowlists (r:1 w:0)
+	// Storage: Fragments Purchases (r:1 w:1)
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments SalePhases (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn buy_unique(s: u32) -> Weight {
+		(87_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments CopiesCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	fn give() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments AccountDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn create_account() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn burn() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:2)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments CopiesCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn split() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Fragments Fragments (r:3 w:2)
+	// Storage: Fragments Inventory (r:2 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn merge() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn consume() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments UniqueData2Edition (r:1 w:2)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn update_unique_data(s: u32) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_proceeds() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn list_instance() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	fn cancel_listing() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments ListingExpirations (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	fn buy_instance() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:1)
+	// Storage: Fragments NextAuctionId (r:1 w:1)
+	// Storage: Fragments Auctions (r:0 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn create_auction() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn bid() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	fn cancel_auction() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn as_fragment() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments AccountDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn close_account(n: u32) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((41_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn approve() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Fragments Operators (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Approvals (r:1 w:1)
+	// Storage: Fragments Operators (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn transfer_from() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Fragments Definitions (r:2 w:1)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: Fragments Minters (r:1 w:1)
+	// Storage: Fragments EditionsCount (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments CopiesCount (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn mint_to_many(n: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	fn give_many(n: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:1)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Fragments Expirations (r:0 w:2)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn renew() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments NextExpiration (r:1 w:1)
+	// Storage: Fragments Expirations (r:1 w:1)
+	// Storage: Fragments InstanceOwner (r:1 w:1)
+	// Storage: Fragments Fragments (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:0)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn process_expirations(n: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments NextListingExpiration (r:1 w:1)
+	// Storage: Fragments ListingExpirations (r:1 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn process_listing_expirations(n: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments NextAuctionEnd (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:1 w:1)
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:0 w:1)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:2)
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Fragments Owners (r:0 w:2)
+	// Storage: Fragments InstanceOwner (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	fn process_auction_ends(n: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((92_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((14 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Detach DetachedInstances (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	// Storage: Detach DetachRequests (r:1 w:1)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Detach PendingDetaches (r:0 w:1)
+	fn detach() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments SaleAllowlists (r:0 w:1)
+	fn set_allowlist(n: u32) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Detach DetachedHashes (r:1 w:0)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos MetaKeys (r:1 w:1)
+	// Storage: Protos MetaKeysIndex (r:1 w:1)
+	// Storage: Fragments DefinitionMetadata (r:0 w:1)
+	fn set_definition_metadata(s: u32) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments GameAuthorities (r:0 w:1)
+	fn set_game_authority() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments GameAuthorities (r:1 w:0)
+	// Storage: Fragments Fragments (r:1 w:0)
+	// Storage: Fragments Inventory (r:1 w:0)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos MetaKeys (r:1 w:1)
+	// Storage: Protos MetaKeysIndex (r:1 w:1)
+	// Storage: Fragments InstanceMetadata (r:1 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:0)
+	fn set_instance_metadata(s: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments Minters (r:0 w:1)
+	fn set_minter() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Fragments Definitions (r:1 w:0)
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Fragments Fragments (r:2 w:1)
+	// Storage: Fragments InstanceOwner (r:1 w:1)
+	// Storage: Detach DetachedInstances (r:1 w:0)
+	// Storage: Fragments Listings (r:1 w:1)
+	// Storage: Fragments BurnedEditionsCount (r:1 w:1)
+	// Storage: Fragments InstanceMetadata (r:0 w:1)
+	// Storage: Fragments Owners (r:0 w:1)
+	// Storage: Fragments Inventory (r:0 w:1)
+	// Storage: Fragments Approvals (r:0 w:1)
+	// Storage: Fragments Expirations (r:0 w:1)
+	// Storage: Fragments InstanceAuctions (r:1 w:1)
+	// Storage: Fragments Auctions (r:1 w:1)
+	// Storage: Fragments AuctionEnds (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
 }
+
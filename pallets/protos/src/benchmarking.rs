@@ -9,6 +9,7 @@ use pallet_detach::Pallet as Detach;
 use protos::categories::{Categories, TextCategories};
 use sp_clamor::CID_PREFIX;
 use sp_io::hashing::blake2_256;
+use sp_runtime::{traits::Zero, SaturatedConversion};
 
 const SEED: u32 = 0;
 
@@ -97,5 +98,52 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::Transferred { proto_hash: proto_hash, owner_id: new_owner }.into())
 	}
 
+	stake {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let mut immutable_data: [u8; 9] = [0; 9];
+		hex::decode_to_slice("010000000b00803103", &mut immutable_data).unwrap();
+		let immutable_data = immutable_data.to_vec();
+		let proto_hash = blake2_256(immutable_data.as_slice());
+		let references = vec![];
+
+		Protos::<T>::upload(RawOrigin::Signed(caller.clone()).into(), references, Categories::Text(TextCategories::Plain), <Vec<Vec<u8>>>::new(), None, None, immutable_data.clone())?;
+
+		// stakes are recorded against the locked FRAG account of the caller, which must be linked to an Ethereum account that locked FRAG
+		let frag_account = get_locked_frag_account(&caller).map_err(|_| "locked FRAG account should decode")?;
+		let eth_account = H160::from([7u8; 20]);
+		let amount: T::Balance = 100u32.into();
+		<pallet_accounts::EVMLinks<T>>::insert(&frag_account, eth_account);
+		<pallet_accounts::EthLockedFrag<T>>::insert(&eth_account, pallet_accounts::EthLock { amount, block_number: <frame_system::Pallet<T>>::block_number() });
+		<pallet_accounts::FragUsage<T>>::insert(&frag_account, T::Balance::zero());
+	}: _(RawOrigin::Signed(caller), proto_hash, amount)
+	verify {
+		assert_last_event::<T>(Event::<T>::Staked { proto_hash, account_id: frag_account, balance: amount }.into())
+	}
+
+	unstake {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let mut immutable_data: [u8; 9] = [0; 9];
+		hex::decode_to_slice("010000000b00803103", &mut immutable_data).unwrap();
+		let immutable_data = immutable_data.to_vec();
+		let proto_hash = blake2_256(immutable_data.as_slice());
+		let references = vec![];
+
+		Protos::<T>::upload(RawOrigin::Signed(caller.clone()).into(), references, Categories::Text(TextCategories::Plain), <Vec<Vec<u8>>>::new(), None, None, immutable_data.clone())?;
+
+		let amount: T::Balance = 100u32.into();
+		<ProtoStakes<T>>::insert(proto_hash, &caller, (amount, <frame_system::Pallet<T>>::block_number()));
+		<AccountStakes<T>>::insert(caller.clone(), proto_hash, ());
+		<pallet_accounts::FragUsage<T>>::insert(&caller, amount);
+
+		// move past the lock-up period of the stake
+		let unlocked_at = <frame_system::Pallet<T>>::block_number() + T::StakeLockupPeriod::get().saturated_into::<T::BlockNumber>() + 1u32.into();
+		<frame_system::Pallet<T>>::set_block_number(unlocked_at);
+	}: _(RawOrigin::Signed(caller.clone()), proto_hash)
+	verify {
+		assert_last_event::<T>(Event::<T>::Unstaked { proto_hash, account_id: caller, balance: amount }.into())
+	}
+
 	impl_benchmark_test_suite!(Protos, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -18,20 +18,24 @@ mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-mod weights;
+pub mod weights;
+
+pub mod migration;
 
 use protos::categories::Categories;
 
 use sp_core::{ecdsa, H160, U256};
 
-use codec::{Compact, Decode, Encode};
+use codec::{Compact, Decode, Encode, MaxEncodedLen};
 pub use pallet::*;
 
+use frame_support::{traits::ConstU32, BoundedBTreeMap, BoundedVec};
+
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
 use sp_io::{hashing::blake2_256, transaction_index};
-use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
+use sp_std::{vec, vec::Vec};
 
 pub use weights::WeightInfo;
 
@@ -45,8 +49,39 @@ use serde_json::{json, Map, Value};
 
 use base58::ToBase58;
 
-/// ¿
+/// **Maximum length** of an **encoded** `Categories` (see `ProtoCategory`)
+pub const MAX_CATEGORY_LEN: u32 = 256;
+
+/// **Tag** of a **Proto-Fragment** (see `Tags`)
+pub type Tag = BoundedVec<u8, ConstU32<64>>;
+/// **Metadata Key** of a **Proto-Fragment** (see `MetaKeys`)
+pub type MetadataKey = BoundedVec<u8, ConstU32<64>>;
+/// **List of Proto-Fragments** that are **referenced** by a **Proto-Fragment** or by a **Proto-Fragment Patch**
+pub type ProtoReferences = BoundedVec<Hash256, ConstU32<32>>;
+/// **List of indices of the Tags** of a **Proto-Fragment**
+pub type ProtoTags = BoundedVec<Compact<u64>, ConstU32<32>>;
+/// **List of Patches** of a **Proto-Fragment**
+pub type ProtoPatches<TBlockNumber> = BoundedVec<ProtoPatch<TBlockNumber>, ConstU32<64>>;
+/// **Map** that maps the **index of a Metadata Key** to the **hash** of the **metadata**
+pub type ProtoMetadata = BoundedBTreeMap<Compact<u64>, Hash256, ConstU32<64>>;
+
+/// **Category** of a **Proto-Fragment**, as it is **stored**
+///
+/// Footnotes:
+///
+/// `Categories` is **not** `MaxEncodedLen` in the `protos` crate, so its **encoding** is **limited** to `MAX_CATEGORY_LEN` bytes when it is **uploaded**.
+/// The **encoding** is the **same** as the one of `Categories`.
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo)]
+pub struct ProtoCategory(pub Categories);
+
+impl MaxEncodedLen for ProtoCategory {
+	fn max_encoded_len() -> usize {
+		MAX_CATEGORY_LEN as usize
+	}
+}
+
+/// ¿
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 pub enum LinkSource {
 	// Generally we just store this data, we don't verify it as we assume auth service did it.
 	// (Link signature, Linked block number, EIP155 Chain ID)
@@ -54,14 +89,14 @@ pub enum LinkSource {
 }
 
 /// **Types** of **Assets that are linked to a Proto-Fragment** (e.g an ERC-721 Contract etc.)
-#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 pub enum LinkedAsset {
 	// Ethereum (ERC721 Contract address, Token ID, Link source)
 	Erc721(H160, U256, LinkSource),
 }
 
 /// **Types** of **Proto-Fragment Owners**
-#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 pub enum ProtoOwner<TAccountId> {
 	// A **regular account** on **this chain**
 	User(TAccountId),
@@ -85,29 +120,31 @@ pub struct GetProtosParams<TAccountId, TString> {
 }
 
 /// **Struct** of a **Proto-Fragment Patch**
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct ProtoPatch<TBlockNumber> {
 	/// **Block Number** in which the **patch was created**
 	pub block: TBlockNumber,
 	/// **Hash** of patch data
 	pub data_hash: Hash256,
 	/// **List of New Proto-Fragments** that was **used** to **create** the **patch** (INCDT)
-	pub references: Vec<Hash256>,
+	pub references: ProtoReferences,
 }
 
-#[derive(Default, Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(
+	Default, Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen,
+)]
 pub struct AccountsInfo {
 	pub active_accounts: u128,
 	pub lifetime_accounts: u128,
 }
 
 /// **Struct** of a **Proto-Fragment**
-#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq)]
+#[derive(Encode, Decode, Clone, scale_info::TypeInfo, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct Proto<TAccountId, TBlockNumber> {
 	/// **Block Number** in which the **Proto-Fragment was minted in**
 	pub block: TBlockNumber,
 	/// **List of *ProtoPatch* structs** of the **Proto-Fragment**
-	pub patches: Vec<ProtoPatch<TBlockNumber>>,
+	pub patches: ProtoPatches<TBlockNumber>,
 	/// **Amount of FRAG tokens** that **need to be staked on the Proto-Fragment** to **use the Proto-Fragment in other Proto-Fragments** (*optional*)
 	/// NOTE: If None, the **Proto-Fragment** *<u>can't be used</u>* in **other Proto-Fragments**
 	pub include_cost: Option<Compact<u64>>,
@@ -116,13 +153,13 @@ pub struct Proto<TAccountId, TBlockNumber> {
 	/// *Current Owner** of the **Proto-Fragment**
 	pub owner: ProtoOwner<TAccountId>,
 	/// **List of other Proto-Fragments** used to create the **Proto-Fragment**
-	pub references: Vec<Hash256>,
+	pub references: ProtoReferences,
 	/// **Category** of the **Proto-Fragment**
-	pub category: Categories,
+	pub category: ProtoCategory,
 	/// **List of Tags** associated with the **Proto-Fragment**
-	pub tags: Vec<Compact<u64>>,
+	pub tags: ProtoTags,
 	/// **Map** that maps the **Key of a Proto-Fragment's Metadata Object** to the **Hash of the aforementioned Metadata Object**
-	pub metadata: BTreeMap<Compact<u64>, Hash256>,
+	pub metadata: ProtoMetadata,
 	/// Accounts information for this proto.
 	pub accounts_info: AccountsInfo,
 }
@@ -132,7 +169,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, Twox64Concat};
 	use frame_system::pallet_prelude::*;
-	use pallet_detach::{DetachRequest, DetachRequests, DetachedHashes, SupportedChains};
+	use pallet_detach::{DetachHash, DetachRequest, DetachedHashes, SupportedChains, TargetAccount};
 	use sp_clamor::CID_PREFIX;
 	use sp_runtime::{traits::Saturating, SaturatedConversion};
 
@@ -154,22 +191,25 @@ pub mod pallet {
 		type StakeLockupPeriod: Get<u64>;
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// **StorageMap** that maps a **Tag (of type `Vec<u8>`)** to an **index number**
+	/// **StorageMap** that maps a **Tag (of type `Tag`)** to an **index number**
 	#[pallet::storage]
-	pub type Tags<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, u64>;
+	pub type Tags<T: Config> = StorageMap<_, Twox64Concat, Tag, u64>;
 
 	/// **StorageValue** that **equals** the **total number of unique tags in the blockchain**
 	#[pallet::storage]
 	pub type TagsIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-	/// **StorageMap** that maps a **Metadata Key (of type `Vec<u8>`)** to an **index number**
+	/// **StorageMap** that maps a **Metadata Key (of type `MetadataKey`)** to an **index number**
 	#[pallet::storage]
-	pub type MetaKeys<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, u64>;
+	pub type MetaKeys<T: Config> = StorageMap<_, Twox64Concat, MetadataKey, u64>;
 
 	/// **StorageValue** that **equals** the **total number of unique Metadata Keys in the blockchain**
 	#[pallet::storage]
@@ -180,15 +220,16 @@ pub mod pallet {
 	pub type Protos<T: Config> =
 		StorageMap<_, Identity, Hash256, Proto<T::AccountId, T::BlockNumber>>;
 
-	/// **StorageMap** that maps a **variant of the *Category* enum** to a **list of Proto-Fragment hashes (that have the aforementioned variant)**
-	// Not ideal but to have it iterable...
+	/// **StorageDoubleMap** that maps a **variant of the *Category* enum** and a **Proto-Fragment hash (that has the aforementioned variant)** to `()`
+	// One entry per Proto-Fragment so that a category never grows into a single unbounded value
 	#[pallet::storage]
-	pub type ProtosByCategory<T: Config> = StorageMap<_, Twox64Concat, Categories, Vec<Hash256>>;
+	pub type ProtosByCategory<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ProtoCategory, Identity, Hash256, ()>;
 
-	/// **StorageMap** that maps a **variant of the *ProtoOwner* enum** to a **list of Proto-Fragment hashes (that have the aforementioned variant)**
+	/// **StorageDoubleMap** that maps a **variant of the *ProtoOwner* enum** and a **Proto-Fragment hash (that has the aforementioned variant)** to `()`
 	#[pallet::storage]
 	pub type ProtosByOwner<T: Config> =
-		StorageMap<_, Twox64Concat, ProtoOwner<T::AccountId>, Vec<Hash256>>;
+		StorageDoubleMap<_, Twox64Concat, ProtoOwner<T::AccountId>, Identity, Hash256, ()>;
 
 	/// **StorageDoubleMap** that maps a **Proto-Fragment and a Clamor Account ID** to a **tuple that contains the Staked Amount (that was staked by the aforementioned Clamor Account ID) and the Block Number**
 	// Staking management
@@ -203,9 +244,10 @@ pub mod pallet {
 		(T::Balance, T::BlockNumber),
 	>;
 
-	/// **StorageMap** that maps a **Clamor Account ID** to a **list of Proto-Fragments that was staked on by the aforementioned Clamor Account ID**
+	/// **StorageDoubleMap** that maps a **Clamor Account ID** and a **Proto-Fragment that was staked on by the aforementioned Clamor Account ID** to `()`
 	#[pallet::storage]
-	pub type AccountStakes<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Vec<Hash256>>;
+	pub type AccountStakes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Identity, Hash256, ()>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -251,6 +293,22 @@ pub mod pallet {
 		StakeLocked,
 		/// Cannot find FRAG link to use as stake funds
 		NoFragLink,
+		/// Target account is too long
+		InvalidTargetAccount,
+		/// Too many references
+		TooManyReferences,
+		/// Too many tags
+		TooManyTags,
+		/// Tag is too long
+		TagTooLong,
+		/// Encoded category is too long
+		CategoryTooLong,
+		/// Too many patches
+		TooManyPatches,
+		/// Too many metadata keys
+		TooManyMetadataKeys,
+		/// Metadata key is too long
+		MetadataKeyTooLong,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -300,6 +358,17 @@ pub mod pallet {
 			// make sure the proto does not exist already!
 			ensure!(!<Protos<T>>::contains_key(&proto_hash), Error::<T>::ProtoExists);
 
+			let references: ProtoReferences =
+				references.try_into().map_err(|_| Error::<T>::TooManyReferences)?;
+
+			ensure!(
+				category.encoded_size() <= MAX_CATEGORY_LEN as usize,
+				Error::<T>::CategoryTooLong
+			);
+			let category = ProtoCategory(category);
+
+			let tags = Self::bound_tags(tags)?;
+
 			// we need this to index transactions
 			let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index()
 				.ok_or(Error::<T>::SystematicFailure)?;
@@ -315,35 +384,19 @@ pub mod pallet {
 				ProtoOwner::User(who.clone())
 			};
 
-			let tags = tags
-				.iter()
-				.map(|s| {
-					let tag_index = <Tags<T>>::get(s);
-					if let Some(tag_index) = tag_index {
-						<Compact<u64>>::from(tag_index)
-					} else {
-						let next_index = <TagsIndex<T>>::try_get().unwrap_or_default() + 1;
-						<Tags<T>>::insert(s, next_index);
-						// storing is dangerous inside a closure
-						// but after this call we start storing..
-						// so it's fine here
-						<TagsIndex<T>>::put(next_index);
-						<Compact<u64>>::from(next_index)
-					}
-				})
-				.collect();
+			let tags = Self::intern_tags(tags);
 
 			// store in the state the proto
 			let proto = Proto {
 				block: current_block_number,
-				patches: vec![],
+				patches: ProtoPatches::default(),
 				include_cost,
 				creator: who.clone(),
 				owner: owner.clone(),
 				references,
 				category: category.clone(),
 				tags,
-				metadata: BTreeMap::new(),
+				metadata: ProtoMetadata::new(),
 				accounts_info: AccountsInfo::default(),
 			};
 
@@ -351,9 +404,9 @@ pub mod pallet {
 			<Protos<T>>::insert(proto_hash, proto);
 
 			// store by category
-			<ProtosByCategory<T>>::append(category, proto_hash);
+			<ProtosByCategory<T>>::insert(category, proto_hash, ());
 
-			<ProtosByOwner<T>>::append(owner, proto_hash);
+			<ProtosByOwner<T>>::insert(owner, proto_hash, ());
 
 			// index immutable data for IPFS discovery
 			transaction_index::index(extrinsic_index, data_len as u32, proto_hash);
@@ -408,6 +461,16 @@ pub mod pallet {
 
 			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached);
 
+			ensure!(
+				data.is_empty() || proto.patches.len() < ProtoPatches::<T::BlockNumber>::bound(),
+				Error::<T>::TooManyPatches
+			);
+
+			let new_references: ProtoReferences =
+				new_references.try_into().map_err(|_| Error::<T>::TooManyReferences)?;
+
+			let new_tags = new_tags.map(Self::bound_tags).transpose()?;
+
 			let data_hash = blake2_256(&data);
 
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
@@ -421,17 +484,22 @@ pub mod pallet {
 
 			// Write STATE from now, ensure no errors from now...
 
-			<Protos<T>>::mutate(&proto_hash, |proto| {
-				let proto = proto.as_mut().unwrap();
+			let new_tags = new_tags.map(Self::intern_tags);
+
+			<Protos<T>>::try_mutate(&proto_hash, |proto| -> DispatchResult {
+				let proto = proto.as_mut().ok_or(Error::<T>::ProtoNotFound)?;
 
 				// Add a data patch if not empty
 				if !data.is_empty() {
 					// No failures from here on out
-					proto.patches.push(ProtoPatch {
-						block: current_block_number,
-						data_hash,
-						references: new_references,
-					});
+					proto
+						.patches
+						.try_push(ProtoPatch {
+							block: current_block_number,
+							data_hash,
+							references: new_references,
+						})
+						.map_err(|_| Error::<T>::TooManyPatches)?;
 					// index mutable data for IPFS discovery as well
 					transaction_index::index(extrinsic_index, data.len() as u32, data_hash);
 				}
@@ -441,27 +509,11 @@ pub mod pallet {
 
 				// Replace previous tags if not None
 				if let Some(new_tags) = new_tags {
-					let tags = new_tags
-						.iter()
-						.map(|s| {
-							let tag_index = <Tags<T>>::get(s);
-							if let Some(tag_index) = tag_index {
-								<Compact<u64>>::from(tag_index)
-							} else {
-								let next_index = <TagsIndex<T>>::try_get().unwrap_or_default() + 1;
-								<Tags<T>>::insert(s, next_index);
-								// storing is dangerous inside a closure
-								// but after this call we start storing..
-								// so it's fine here
-								<TagsIndex<T>>::put(next_index);
-								<Compact<u64>>::from(next_index)
-							}
-						})
-						.collect();
-
-					proto.tags = tags;
+					proto.tags = new_tags;
 				}
-			});
+
+				Ok(())
+			})?;
 
 			let cid = [&CID_PREFIX[..], &data_hash[..]].concat();
 			let cid = cid.to_base58();
@@ -513,14 +565,10 @@ pub mod pallet {
 			// WRITING STATE FROM NOW
 
 			// remove proto from old owner
-			<ProtosByOwner<T>>::mutate(proto.owner, |proto_by_owner| {
-				if let Some(list) = proto_by_owner {
-					list.retain(|current_hash| proto_hash != *current_hash);
-				}
-			});
+			<ProtosByOwner<T>>::remove(proto.owner, proto_hash);
 
 			// add proto to new owner
-			<ProtosByOwner<T>>::append(new_owner_s.clone(), proto_hash);
+			<ProtosByOwner<T>>::insert(new_owner_s.clone(), proto_hash, ());
 
 			// update proto
 			<Protos<T>>::mutate(&proto_hash, |proto| {
@@ -570,34 +618,45 @@ pub mod pallet {
 
 			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached);
 
+			let bounded_metadata_key: MetadataKey =
+				metadata_key.clone().try_into().map_err(|_| Error::<T>::MetadataKeyTooLong)?;
+
+			let is_set = <MetaKeys<T>>::get(&bounded_metadata_key)
+				.map_or(false, |index| proto.metadata.contains_key(&Compact(index)));
+			ensure!(
+				is_set || proto.metadata.len() < ProtoMetadata::bound(),
+				Error::<T>::TooManyMetadataKeys
+			);
+
 			let data_hash = blake2_256(&data);
 
 			// we need this to index transactions
 			let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index()
 				.ok_or(Error::<T>::SystematicFailure)?;
 
+			// Write STATE from now, ensure no errors from now...
+
 			let metadata_key_index = {
-				let index = <MetaKeys<T>>::get(metadata_key.clone());
+				let index = <MetaKeys<T>>::get(&bounded_metadata_key);
 				if let Some(index) = index {
 					<Compact<u64>>::from(index)
 				} else {
 					let next_index = <MetaKeysIndex<T>>::try_get().unwrap_or_default() + 1;
-					<MetaKeys<T>>::insert(metadata_key.clone(), next_index);
-					// storing is dangerous inside a closure
-					// but after this call we start storing..
-					// so it's fine here
+					<MetaKeys<T>>::insert(&bounded_metadata_key, next_index);
 					<MetaKeysIndex<T>>::put(next_index);
 					<Compact<u64>>::from(next_index)
 				}
 			};
 
-			// Write STATE from now, ensure no errors from now...
-
-			<Protos<T>>::mutate(&proto_hash, |proto| {
-				let proto = proto.as_mut().unwrap();
+			<Protos<T>>::try_mutate(&proto_hash, |proto| -> DispatchResult {
+				let proto = proto.as_mut().ok_or(Error::<T>::ProtoNotFound)?;
 				// update metadata
-				proto.metadata.insert(metadata_key_index, data_hash);
-			});
+				proto
+					.metadata
+					.try_insert(metadata_key_index, data_hash)
+					.map_err(|_| Error::<T>::TooManyMetadataKeys)?;
+				Ok(())
+			})?;
 
 			// index data
 			transaction_index::index(extrinsic_index, data.len() as u32, data_hash);
@@ -644,11 +703,14 @@ pub mod pallet {
 
 			ensure!(!<DetachedHashes<T>>::contains_key(&proto_hash), Error::<T>::Detached);
 
-			<DetachRequests<T>>::mutate(|requests| {
-				requests.push(DetachRequest { hash: proto_hash, target_chain, target_account });
-			});
+			let target_account: TargetAccount =
+				target_account.try_into().map_err(|_| Error::<T>::InvalidTargetAccount)?;
 
-			Ok(())
+			pallet_detach::Pallet::<T>::add_detach_request(DetachRequest {
+				hash: DetachHash::Proto(proto_hash),
+				target_chain,
+				target_account,
+			})
 		}
 
 		/// Stake FRAG tokens on a Proto-Fragment
@@ -658,7 +720,7 @@ pub mod pallet {
 		/// * `origin` - The origin of the extrinsic function
 		/// * `proto_hash` - **Hash of the Proto-Fragment** to **stake on**
 		/// * `amount` - **Amount of FRAG tokens** to **stake**
-		#[pallet::weight(50_000)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::stake())]
 		pub fn stake(
 			origin: OriginFor<T>,
 			proto_hash: Hash256,
@@ -691,7 +753,7 @@ pub mod pallet {
 
 			// take record of the stake
 			<ProtoStakes<T>>::insert(proto_hash, &who, (amount, current_block_number));
-			<AccountStakes<T>>::append(who.clone(), proto_hash.clone());
+			<AccountStakes<T>>::insert(who.clone(), proto_hash.clone(), ());
 
 			// also emit event
 			Self::deposit_event(Event::Staked { proto_hash, account_id: who, balance: amount }); // 问Gio
@@ -705,7 +767,7 @@ pub mod pallet {
 		///
 		/// * `origin` - The origin of the extrinsic function
 		/// * `proto_hash` - **Hash of the Proto-Fragment** on which the FRAG tokens were staked on
-		#[pallet::weight(50_000)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unstake())]
 		pub fn unstake(origin: OriginFor<T>, proto_hash: Hash256) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
@@ -730,11 +792,7 @@ pub mod pallet {
 
 			// take record of the unstake
 			<ProtoStakes<T>>::remove(proto_hash, &who);
-			<AccountStakes<T>>::mutate(who.clone(), |stakes| {
-				if let Some(stakes) = stakes {
-					stakes.retain(|h| h != &proto_hash); // Retain all Proto-Fragments except `proto_hash`
-				}
-			});
+			<AccountStakes<T>>::remove(who.clone(), proto_hash);
 
 			// also emit event
 			Self::deposit_event(Event::Unstaked { proto_hash, account_id: who, balance: stake.0 });
@@ -750,14 +808,14 @@ pub mod pallet {
 		/// the list of Clamor Account IDs in `PendingUnlocks`. And then subsequently, clear `PendingUnlocks`.
 		fn on_finalize(_n: T::BlockNumber) {
 			// drain unlinks
-			let unlinks = <pallet_accounts::PendingUnlinks<T>>::take();
+			let unlinks: Vec<T::AccountId> =
+				<pallet_accounts::PendingUnlinks<T>>::drain().map(|(unlink, _)| unlink).collect();
 			for unlink in unlinks {
-				// take emptying the storage
-				let stakes = <AccountStakes<T>>::take(unlink.clone());
-				if let Some(stakes) = stakes {
-					for stake in stakes {
-						<ProtoStakes<T>>::remove(stake, &unlink);
-					}
+				// drain emptying the storage
+				let stakes: Vec<Hash256> =
+					<AccountStakes<T>>::drain_prefix(unlink.clone()).map(|(stake, _)| stake).collect();
+				for stake in stakes {
+					<ProtoStakes<T>>::remove(stake, &unlink);
 				}
 			}
 		}
@@ -812,6 +870,36 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// **Bound** the **Tags `tags`** (see `Tag` and `ProtoTags`)
+		fn bound_tags(tags: Vec<Vec<u8>>) -> Result<Vec<Tag>, Error<T>> {
+			ensure!(tags.len() <= ProtoTags::bound(), Error::<T>::TooManyTags);
+			tags.into_iter()
+				.map(|tag| tag.try_into().map_err(|_| Error::<T>::TagTooLong))
+				.collect()
+		}
+
+		/// **Get** the **indices** of the **Tags `tags`** (see `Tags`), **adding** the **Tags** that **don't exist yet**
+		///
+		/// Footnotes:
+		///
+		/// `tags` must come from `bound_tags`, so there are **never too many** of them
+		fn intern_tags(tags: Vec<Tag>) -> ProtoTags {
+			let mut indices = ProtoTags::default();
+			for tag in tags {
+				let index = if let Some(index) = <Tags<T>>::get(&tag) {
+					index
+				} else {
+					let next_index = <TagsIndex<T>>::try_get().unwrap_or_default() + 1;
+					<Tags<T>>::insert(&tag, next_index);
+					<TagsIndex<T>>::put(next_index);
+					next_index
+				};
+				// can't fail, `bound_tags` checked the number of tags
+				let _ = indices.try_push(Compact(index));
+			}
+			indices
+		}
+
 		fn filter_proto(
 			proto_id: &Hash256,
 			tags: &[Vec<u8>],
@@ -829,14 +917,14 @@ pub mod pallet {
 
 				if categories.len() == 0
 				// Use any here to match any category towards proto
-					|| categories.into_iter().any(|cat| *cat == struct_proto.category)
+					|| categories.into_iter().any(|cat| *cat == struct_proto.category.0)
 				{
 					// Use all here to match all tags always
 					if tags.len() == 0 {
 						true
 					} else {
 						tags.into_iter().all(|tag| {
-							let tag_idx = <Tags<T>>::get(tag);
+							let tag_idx = Tag::try_from(tag.clone()).ok().and_then(<Tags<T>>::get);
 							if let Some(tag_idx) = tag_idx {
 								struct_proto.tags.contains(&Compact::from(tag_idx))
 							} else {
@@ -852,6 +940,22 @@ pub mod pallet {
 			}
 		}
 
+		/// **Sort** the **Proto-Fragment hashes `proto_hashes`** by the **block number they were uploaded in**
+		///
+		/// Index maps iterate in key (hash) order, so this restores the chronological ordering.
+		/// Proto-Fragments uploaded in the **same block** are **ordered by hash**, as the order of the uploads within a block isn't stored.
+		fn sort_by_upload_block(proto_hashes: Vec<Hash256>, desc: bool) -> Vec<Hash256> {
+			let mut protos: Vec<(T::BlockNumber, Hash256)> = proto_hashes
+				.into_iter()
+				.filter_map(|proto_id| <Protos<T>>::get(&proto_id).map(|proto| (proto.block, proto_id)))
+				.collect();
+			protos.sort();
+			if desc {
+				protos.reverse();
+			}
+			protos.into_iter().map(|(_, proto_id)| proto_id).collect()
+		}
+
 		/// **Query** and **Return** **Proto-Fragment(s)** based on **`params`**. The **return type** is a **JSON string**
 		/// Furthermore, this function also indexes `data` in the Blockchain's Database and makes it available via bitswap (IPFS) directly from every chain node permanently.
 		///
@@ -863,102 +967,49 @@ pub mod pallet {
 		) -> Result<Vec<u8>, Vec<u8>> {
 			let mut map = Map::new();
 
-			let list_protos_final: Vec<Hash256> = if let Some(owner) = params.owner {
+			let list_protos: Vec<Hash256> = if let Some(owner) = params.owner {
 				// `owner` exists
-				if let Some(list_protos_owner) =
-					<ProtosByOwner<T>>::get(ProtoOwner::<T::AccountId>::User(owner))
-				{
-					// `owner` exists in `ProtosByOwner`
-					if params.desc {
-						// Sort in descending order
-						list_protos_owner
-							.into_iter()
-							.rev()
-							.filter(|proto_id| {
-								Self::filter_proto(
-									proto_id,
-									&params.tags,
-									&params.categories,
-									params.available,
-								)
-							})
-							.skip(params.from as usize)
-							.take(params.limit as usize)
-							.collect::<Vec<Hash256>>()
-					} else {
-						// Sort in ascending order
-						list_protos_owner
-							.into_iter()
-							.filter(|proto_id| {
-								Self::filter_proto(
-									proto_id,
-									&params.tags,
-									&params.categories,
-									params.available,
-								)
-							})
-							.skip(params.from as usize)
-							.take(params.limit as usize)
-							.collect::<Vec<Hash256>>()
-					}
-				} else {
+				let list_protos_owner = <ProtosByOwner<T>>::iter_key_prefix(
+					ProtoOwner::<T::AccountId>::User(owner),
+				)
+				.collect::<Vec<Hash256>>();
+				if list_protos_owner.is_empty() {
 					// `owner` doesn't exist in `ProtosByOwner`
 					return Err("Owner not found".into())
 				}
+				Self::sort_by_upload_block(list_protos_owner, params.desc)
 			} else {
+				// Group the Proto-Fragments by category, in the order in which the categories are stored
+				// (and in reverse order if `params.desc`), then sort each group by upload block
 				// Notice this wastes time and memory and needs a better implementation
-				let mut flat = Vec::<Hash256>::new();
-
-				let cats = <ProtosByCategory<T>>::iter_keys().collect::<Vec<Categories>>();
-				let cats: Vec<Categories> =
-					if params.desc { cats.iter().rev().map(|x| x.clone()).collect() } else { cats };
-
-				for category in cats {
-					if params.categories.len() != 0 && !params.categories.contains(&category) {
+				let mut groups: Vec<(ProtoCategory, Vec<Hash256>)> = Vec::new();
+				for (category, proto_id) in <ProtosByCategory<T>>::iter_keys() {
+					if params.categories.len() != 0 && !params.categories.contains(&category.0) {
 						continue
 					}
-
-					let protos = <ProtosByCategory<T>>::get(category);
-					if let Some(protos) = protos {
-						let collection: Vec<Hash256> = if params.desc {
-							// Sort in descending order
-							protos
-								.into_iter()
-								.rev()
-								.filter(|proto_id| {
-									Self::filter_proto(
-										proto_id,
-										&params.tags,
-										&params.categories,
-										params.available,
-									)
-								})
-								.collect()
-						} else {
-							// Sort in ascending order
-							protos
-								.into_iter()
-								.filter(|proto_id| {
-									Self::filter_proto(
-										proto_id,
-										&params.tags,
-										&params.categories,
-										params.available,
-									)
-								})
-								.collect()
-						};
-						flat.extend(collection);
+					match groups.last_mut() {
+						Some((last, proto_ids)) if *last == category => proto_ids.push(proto_id),
+						_ => groups.push((category, vec![proto_id])),
 					}
 				}
-
-				flat.iter()
-					.skip(params.from as usize)
-					.take(params.limit as usize)
-					.map(|x| *x)
+				if params.desc {
+					groups.reverse();
+				}
+				groups
+					.into_iter()
+					.flat_map(|(_, proto_ids)| Self::sort_by_upload_block(proto_ids, params.desc))
 					.collect()
 			};
 
+			let list_protos_final: Vec<Hash256> = list_protos
+				.into_iter()
+				.filter(|proto_id| {
+					Self::filter_proto(proto_id, &params.tags, &params.categories, params.available)
+				})
+				.skip(params.from as usize)
+				.take(params.limit as usize)
+				.collect();
+
 			for proto_id in list_protos_final.into_iter() {
 				map.insert(hex::encode(proto_id), Value::Object(Map::new()));
 			}
@@ -1029,7 +1080,9 @@ pub mod pallet {
 
 					if !params.metadata_keys.is_empty() {
 						for metadata_key in params.metadata_keys.iter() {
-							let metadata_key_index = <MetaKeys<T>>::get(metadata_key.clone());
+							let metadata_key_index = MetadataKey::try_from(metadata_key.clone())
+								.ok()
+								.and_then(<MetaKeys<T>>::get);
 							let metadata_value = if let Some(metadata_key_index) =
 								metadata_key_index
 							{
@@ -0,0 +1,178 @@
+//! Storage migrations for the pallet `protos`
+
+use crate::*;
+
+use frame_support::{
+	storage::{
+		migration::{move_prefix, storage_key_iter},
+		storage_prefix,
+	},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+	Twox64Concat,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, traits::OnRuntimeUpgradeHelpersExt};
+
+/// Version 1 moves the `Vec<Hash256>` lists of `ProtosByCategory`, `ProtosByOwner` and `AccountStakes`
+/// to one storage entry per Proto-Fragment
+pub mod v1 {
+	use super::*;
+
+	/// **Move** the **old entries** of the **storage item `item`** under the **storage item `stash`**, so that they can be **drained**
+	/// one at a time without **meeting** the **new entries** (which are written under the **same prefix**)
+	fn stash(pallet: &[u8], item: &[u8], stash: &[u8]) {
+		move_prefix(&storage_prefix(pallet, item), &storage_prefix(pallet, stash));
+	}
+
+	/// **Migrate** the **storage** of this pallet from **version 0** to **version 1**
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(
+					"protos: skipping v1 migration, on-chain version is {:?}",
+					onchain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 0;
+
+			stash(pallet, b"ProtosByCategory", b"OldProtosByCategory");
+			stash(pallet, b"ProtosByOwner", b"OldProtosByOwner");
+			stash(pallet, b"AccountStakes", b"OldAccountStakes");
+
+			// every old entry is moved and then drained
+			for (category, proto_hashes) in storage_key_iter::<
+				ProtoCategory,
+				Vec<Hash256>,
+				Twox64Concat,
+			>(pallet, b"OldProtosByCategory")
+			.drain()
+			{
+				reads += 2;
+				writes += 2;
+				for proto_hash in proto_hashes {
+					<ProtosByCategory<T>>::insert(&category, proto_hash, ());
+					writes += 1;
+				}
+			}
+
+			for (owner, proto_hashes) in storage_key_iter::<
+				ProtoOwner<T::AccountId>,
+				Vec<Hash256>,
+				Twox64Concat,
+			>(pallet, b"OldProtosByOwner")
+			.drain()
+			{
+				reads += 2;
+				writes += 2;
+				for proto_hash in proto_hashes {
+					<ProtosByOwner<T>>::insert(&owner, proto_hash, ());
+					writes += 1;
+				}
+			}
+
+			for (account, proto_hashes) in storage_key_iter::<
+				T::AccountId,
+				Vec<Hash256>,
+				Twox64Concat,
+			>(pallet, b"OldAccountStakes")
+			.drain()
+			{
+				reads += 2;
+				writes += 2;
+				for proto_hash in proto_hashes {
+					<AccountStakes<T>>::insert(&account, proto_hash, ());
+					writes += 1;
+				}
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 1;
+
+			log::info!("protos: migrated to storage version 1");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// **Record** the **number of entries** that are **expected** after the **migration**
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 1,
+				"protos: v1 migration was already applied"
+			);
+
+			let pallet = <Pallet<T>>::name().as_bytes();
+
+			let by_category: u32 = storage_key_iter::<ProtoCategory, Vec<Hash256>, Twox64Concat>(
+				pallet,
+				b"ProtosByCategory",
+			)
+			.map(|(_, proto_hashes)| proto_hashes.len() as u32)
+			.sum();
+			let by_owner: u32 = storage_key_iter::<
+				ProtoOwner<T::AccountId>,
+				Vec<Hash256>,
+				Twox64Concat,
+			>(pallet, b"ProtosByOwner")
+			.map(|(_, proto_hashes)| proto_hashes.len() as u32)
+			.sum();
+			let account_stakes: u32 = storage_key_iter::<T::AccountId, Vec<Hash256>, Twox64Concat>(
+				pallet,
+				b"AccountStakes",
+			)
+			.map(|(_, proto_hashes)| proto_hashes.len() as u32)
+			.sum();
+
+			Self::set_temp_storage(by_category, "protos_v1_by_category");
+			Self::set_temp_storage(by_owner, "protos_v1_by_owner");
+			Self::set_temp_storage(account_stakes, "protos_v1_account_stakes");
+
+			Ok(())
+		}
+
+		/// **Check** that **every entry** was **migrated** and that **every `Proto` still decodes**
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"protos: storage version was not updated to 1"
+			);
+
+			let by_category: u32 = Self::get_temp_storage("protos_v1_by_category")
+				.ok_or("protos: missing pre_upgrade data")?;
+			let by_owner: u32 = Self::get_temp_storage("protos_v1_by_owner")
+				.ok_or("protos: missing pre_upgrade data")?;
+			let account_stakes: u32 = Self::get_temp_storage("protos_v1_account_stakes")
+				.ok_or("protos: missing pre_upgrade data")?;
+
+			ensure!(
+				<ProtosByCategory<T>>::iter_keys().count() as u32 == by_category,
+				"protos: `ProtosByCategory` entries were lost"
+			);
+			ensure!(
+				<ProtosByOwner<T>>::iter_keys().count() as u32 == by_owner,
+				"protos: `ProtosByOwner` entries were lost"
+			);
+			ensure!(
+				<AccountStakes<T>>::iter_keys().count() as u32 == account_stakes,
+				"protos: `AccountStakes` entries were lost"
+			);
+			ensure!(
+				<Protos<T>>::iter_keys().count() == <Protos<T>>::iter_values().count(),
+				"protos: some `Protos` entries do not decode"
+			);
+
+			Ok(())
+		}
+	}
+}
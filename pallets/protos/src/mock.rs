@@ -154,6 +154,8 @@ impl pallet_detach::Config for Test {
 	type Event = Event;
 	type WeightInfo = ();
 	type AuthorityId = pallet_detach::crypto::DetachAuthId;
+	type MaxDetachRequests = ConstU32<8>;
+	type DetachTimeout = ConstU64<10>;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -6,7 +6,6 @@ use crate::*;
 use codec::Compact;
 use frame_support::dispatch::DispatchResult;
 use frame_support::{assert_noop, assert_ok, traits::Get};
-use std::collections::BTreeMap;
 
 use stake_tests::stake_;
 use upload_tests::upload;
@@ -81,14 +80,14 @@ mod upload_tests {
 
 			let correct_proto_struct = Proto {
 				block: block_number,
-				patches: Vec::new(),
+				patches: ProtoPatches::default(),
 				include_cost: proto.include_cost.map(|cost| Compact::from(cost)),
 				creator: dd.account_id,
 				owner: ProtoOwner::User(dd.account_id),
-				references: proto.references.clone(),
-				category: proto.category.clone(),
-				tags: Vec::new(), // proto.tags,
-				metadata: BTreeMap::new(),
+				references: proto.references.clone().try_into().unwrap(),
+				category: ProtoCategory(proto.category.clone()),
+				tags: ProtoTags::default(), // proto.tags,
+				metadata: ProtoMetadata::new(),
 				accounts_info: AccountsInfo::default(),
 			};
 
@@ -98,12 +97,14 @@ mod upload_tests {
 				_ => panic!("The correct `Proto` struct was not saved in the StorageMap `Protos`"),
 			}
 
-			assert!(<ProtosByCategory<Test>>::get(&proto.category)
-				.unwrap()
-				.contains(&proto.get_proto_hash()));
-			assert!(<ProtosByOwner<Test>>::get(ProtoOwner::User(dd.account_id))
-				.unwrap()
-				.contains(&proto.get_proto_hash()));
+			assert!(<ProtosByCategory<Test>>::contains_key(
+				ProtoCategory(proto.category.clone()),
+				&proto.get_proto_hash()
+			));
+			assert!(<ProtosByOwner<Test>>::contains_key(
+				ProtoOwner::User(dd.account_id),
+				&proto.get_proto_hash()
+			));
 
 			let event = <frame_system::Pallet<Test>>::events()
 				.pop()
@@ -129,6 +130,30 @@ mod upload_tests {
 		});
 	}
 
+	#[test]
+	fn upload_should_not_work_if_tags_are_out_of_bounds() {
+		new_test_ext().execute_with(|| {
+			let dd = DummyData::new();
+
+			let proto_with_long_tag =
+				ProtoFragment { tags: vec![vec![7u8; 65]], ..dd.proto_fragment.clone() };
+			assert_noop!(upload(dd.account_id, &proto_with_long_tag), Error::<Test>::TagTooLong);
+
+			let proto_with_many_tags = ProtoFragment {
+				tags: (0..33u8).map(|i| vec![i]).collect(),
+				..dd.proto_fragment.clone()
+			};
+			assert_noop!(upload(dd.account_id, &proto_with_many_tags), Error::<Test>::TooManyTags);
+
+			let proto_with_many_references =
+				ProtoFragment { references: vec![[7u8; 32]; 33], ..dd.proto_fragment };
+			assert_noop!(
+				upload(dd.account_id, &proto_with_many_references),
+				Error::<Test>::TooManyReferences
+			);
+		});
+	}
+
 	// TODO
 	#[test]
 	#[ignore]
@@ -253,7 +278,7 @@ mod patch_tests {
 			assert!(proto_struct.patches.contains(&ProtoPatch {
 				block: block_number,
 				data_hash: patch.get_data_hash(),
-				references: patch.new_references.clone()
+				references: patch.new_references.clone().try_into().unwrap()
 			}));
 
 			let event = <frame_system::Pallet<Test>>::events()
@@ -475,14 +500,15 @@ mod transfer_tests {
 			);
 
 			assert!(
-				<ProtosByOwner<Test>>::get(ProtoOwner::User(dd.account_id))
-					.unwrap()
-					.contains(&proto.get_proto_hash())
-					== false
+				<ProtosByOwner<Test>>::contains_key(
+					ProtoOwner::User(dd.account_id),
+					&proto.get_proto_hash()
+				) == false
 			);
-			assert!(<ProtosByOwner<Test>>::get(ProtoOwner::User(dd.account_id_second))
-				.unwrap()
-				.contains(&proto.get_proto_hash()));
+			assert!(<ProtosByOwner<Test>>::contains_key(
+				ProtoOwner::User(dd.account_id_second),
+				&proto.get_proto_hash()
+			));
 
 			let event = <frame_system::Pallet<Test>>::events()
 				.pop()
@@ -559,7 +585,10 @@ mod set_metadata_tests {
 
 			let metadata_map =
 				<Protos<Test>>::get(metadata.proto_fragment.get_proto_hash()).unwrap().metadata;
-			let metadata_key_index = <MetaKeys<Test>>::get(&metadata.metadata_key).unwrap();
+			let metadata_key_index = <MetaKeys<Test>>::get(
+				MetadataKey::try_from(metadata.metadata_key.clone()).unwrap(),
+			)
+			.unwrap();
 			assert_eq!(
 				metadata_map[&<Compact<u64>>::from(metadata_key_index)],
 				metadata.get_data_hash()
@@ -602,6 +631,16 @@ mod set_metadata_tests {
 			assert_noop!(set_metadata(dd.account_id, &metadata), Error::<Test>::ProtoNotFound);
 		});
 	}
+
+	#[test]
+	fn set_metadata_should_not_work_if_metadata_key_is_too_long() {
+		new_test_ext().execute_with(|| {
+			let dd = DummyData::new();
+			let metadata = Metadata { metadata_key: vec![7u8; 65], ..dd.metadata };
+			assert_ok!(upload(dd.account_id, &metadata.proto_fragment));
+			assert_noop!(set_metadata(dd.account_id, &metadata), Error::<Test>::MetadataKeyTooLong);
+		});
+	}
 }
 
 
@@ -651,9 +690,10 @@ mod stake_tests {
 					.unwrap(),
 				(stake.get_stake_amount(), current_block_number)
 			);
-			assert!(<AccountStakes<Test>>::get(dd.account_id)
-				.unwrap()
-				.contains(&stake.proto_fragment.get_proto_hash()));
+			assert!(<AccountStakes<Test>>::contains_key(
+				dd.account_id,
+				&stake.proto_fragment.get_proto_hash()
+			));
 
 			let event = <frame_system::Pallet<Test>>::events()
 				.pop()
@@ -809,10 +849,10 @@ mod unstake_tests {
 			);
 
 			assert!(
-				<AccountStakes<Test>>::get(stake.lock.link.clamor_account_id)
-					.unwrap()
-					.contains(&stake.proto_fragment.get_proto_hash())
-					== false
+				<AccountStakes<Test>>::contains_key(
+					stake.lock.link.clamor_account_id,
+					&stake.proto_fragment.get_proto_hash()
+				) == false
 			);
 
 			let event = <frame_system::Pallet<Test>>::events()
@@ -883,3 +923,64 @@ mod unstake_tests {
 		});
 	}
 }
+
+mod migration_tests {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{
+		storage::migration::put_storage_value,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+		StorageHasher, Twox64Concat,
+	};
+	use protos::categories::{Categories, TextCategories};
+
+	#[test]
+	fn migration_to_v1_should_split_lists() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<ProtosPallet>();
+
+			let pallet = <ProtosPallet as PalletInfoAccess>::name().as_bytes();
+			let owner = sp_core::ed25519::Public::from_raw([1u8; 32]);
+			let category = Categories::Text(TextCategories::Plain);
+			let proto_hashes = vec![[1u8; 32], [2u8; 32]];
+
+			put_storage_value(
+				pallet,
+				b"ProtosByCategory",
+				&Twox64Concat::hash(&category.encode()),
+				proto_hashes.clone(),
+			);
+			put_storage_value(
+				pallet,
+				b"ProtosByOwner",
+				&Twox64Concat::hash(&ProtoOwner::User(owner).encode()),
+				proto_hashes.clone(),
+			);
+			put_storage_value(
+				pallet,
+				b"AccountStakes",
+				&Twox64Concat::hash(&owner.encode()),
+				vec![[1u8; 32]],
+			);
+
+			migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(ProtosPallet::on_chain_storage_version(), 1);
+			for proto_hash in proto_hashes {
+				assert!(<ProtosByCategory<Test>>::contains_key(
+					ProtoCategory(category.clone()),
+					proto_hash
+				));
+				assert!(<ProtosByOwner<Test>>::contains_key(ProtoOwner::User(owner), proto_hash));
+			}
+			assert_eq!(
+				<AccountStakes<Test>>::iter_keys().collect::<Vec<_>>(),
+				vec![(owner, [1u8; 32])]
+			);
+
+			// running it again does nothing
+			migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(<ProtosByCategory<Test>>::iter_keys().count(), 2);
+		});
+	}
+}
@@ -15,15 +15,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_protos
+//! Weights for pallet_protos
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-02-22, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! NOTE: these weights are estimates that follow `benchmarking.rs`, they were NOT produced by the benchmark CLI.
+//! Regenerate this file with the command below (on the reference hardware) before relying on them.
 
-// Executed Command:
+// Command to regenerate it:
 // ./target/release/clamor
 // benchmark
+// pallet
 // --chain=dev
 // --execution=wasm
 // --wasm-execution=compiled
@@ -50,6 +50,8 @@ pub trait WeightInfo {
 	fn patch() -> Weight;
 	fn detach() -> Weight;
 	fn transfer() -> Weight;
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
 }
 
 /// Weights for pallet_protos using the Substrate node and recommended hardware.
@@ -67,26 +69,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	// Storage: Protos UserNonces (r:1 w:1)
-	// Storage: Protos UploadAuthorities (r:1 w:0)
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
-	// Storage: Protos ProtosByCategory (r:1 w:1)
-	// Storage: Protos ProtosByOwner (r:1 w:1)
+	// Storage: Protos ProtosByCategory (r:0 w:1)
+	// Storage: Protos ProtosByOwner (r:0 w:1)
+	// Storage: Protos Tags (r:1 w:1)
+	// Storage: Protos TagsIndex (r:1 w:1)
 	fn upload() -> Weight {
 		(158_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
-	// Storage: Protos UserNonces (r:1 w:1)
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: Detach DetachedHashes (r:1 w:0)
-	// Storage: Protos UploadAuthorities (r:1 w:0)
 	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos Tags (r:1 w:1)
+	// Storage: Protos TagsIndex (r:1 w:1)
 	fn patch() -> Weight {
 		(162_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Protos Protos (r:1 w:0)
 	// Storage: Detach DetachedHashes (r:1 w:0)
@@ -98,12 +100,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: Detach DetachedHashes (r:1 w:0)
-	// Storage: Protos ProtosByOwner (r:2 w:2)
+	// Storage: Protos ProtosByOwner (r:0 w:2)
 	fn transfer() -> Weight {
 		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Accounts EVMLinks (r:1 w:0)
+	// Storage: Accounts EthLockedFrag (r:1 w:0)
+	// Storage: Accounts FragUsage (r:1 w:1)
+	// Storage: Protos ProtoStakes (r:0 w:1)
+	// Storage: Protos AccountStakes (r:0 w:1)
+	fn stake() -> Weight {
+		(41_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Protos ProtoStakes (r:1 w:1)
+	// Storage: Accounts FragUsage (r:1 w:1)
+	// Storage: Protos AccountStakes (r:0 w:1)
+	fn unstake() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -120,26 +142,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	// Storage: Protos UserNonces (r:1 w:1)
-	// Storage: Protos UploadAuthorities (r:1 w:0)
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
-	// Storage: Protos ProtosByCategory (r:1 w:1)
-	// Storage: Protos ProtosByOwner (r:1 w:1)
+	// Storage: Protos ProtosByCategory (r:0 w:1)
+	// Storage: Protos ProtosByOwner (r:0 w:1)
+	// Storage: Protos Tags (r:1 w:1)
+	// Storage: Protos TagsIndex (r:1 w:1)
 	fn upload() -> Weight {
 		(158_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
-	// Storage: Protos UserNonces (r:1 w:1)
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: Detach DetachedHashes (r:1 w:0)
-	// Storage: Protos UploadAuthorities (r:1 w:0)
 	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Protos Tags (r:1 w:1)
+	// Storage: Protos TagsIndex (r:1 w:1)
 	fn patch() -> Weight {
 		(162_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Protos Protos (r:1 w:0)
 	// Storage: Detach DetachedHashes (r:1 w:0)
@@ -151,10 +173,31 @@ impl WeightInfo for () {
 	}
 	// Storage: Protos Protos (r:1 w:1)
 	// Storage: Detach DetachedHashes (r:1 w:0)
-	// Storage: Protos ProtosByOwner (r:2 w:2)
+	// Storage: Protos ProtosByOwner (r:0 w:2)
 	fn transfer() -> Weight {
 		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Accounts EVMLinks (r:1 w:0)
+	// Storage: Accounts EthLockedFrag (r:1 w:0)
+	// Storage: Accounts FragUsage (r:1 w:1)
+	// Storage: Protos ProtoStakes (r:0 w:1)
+	// Storage: Protos AccountStakes (r:0 w:1)
+	fn stake() -> Weight {
+		(41_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Protos Protos (r:1 w:0)
+	// Storage: Protos ProtoStakes (r:1 w:1)
+	// Storage: Accounts FragUsage (r:1 w:1)
+	// Storage: Protos AccountStakes (r:0 w:1)
+	fn unstake() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
+
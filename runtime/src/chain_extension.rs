@@ -0,0 +1,54 @@
+//! Chain extension that lets smart contracts (`pallet_contracts`) query the pallet `fragments`,
+//! e.g. so that marketplaces built as smart contracts can honor the royalties of Fragment Definitions
+
+use codec::Encode;
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+use sp_clamor::Hash128;
+use sp_runtime::DispatchError;
+
+use crate::{Fragments, Runtime};
+
+/// **Function ID** that **returns** the **royalty** owed when a **Fragment Instance** is **resold**.
+///
+/// Input: `(Hash128, u128)` (Fragment Definition ID, sale price).
+/// Output: `Option<(AccountId, u128)>` (beneficiary, amount to pay them)
+pub const GET_ROYALTY: u32 = 1;
+
+/// **Chain extension** of the **Clamor runtime**
+#[derive(Default)]
+pub struct FragmentsExtension;
+
+impl ChainExtension<Runtime> for FragmentsExtension {
+	fn call<E>(&mut self, func_id: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		match func_id {
+			GET_ROYALTY => {
+				let mut env = env.buf_in_buf_out();
+				let (fragment_hash, price): (Hash128, u128) = env.read_as()?;
+
+				// reads `Definitions`
+				env.charge_weight(<Runtime as frame_system::Config>::DbWeight::get().reads(1))?;
+
+				let royalty = Fragments::get_royalty(fragment_hash, price);
+				env.write(&royalty.encode(), false, None)
+					.map_err(|_| DispatchError::Other("FragmentsExtension: failed to write the royalty"))?;
+
+				Ok(RetVal::Converging(0))
+			},
+			_ => {
+				log::error!("FragmentsExtension: called an unknown function ID {}", func_id);
+				Err(DispatchError::Other("FragmentsExtension: unknown function ID"))
+			},
+		}
+	}
+
+	fn enabled() -> bool {
+		true
+	}
+}
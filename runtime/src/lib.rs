@@ -60,7 +60,14 @@ use sp_runtime::traits::{SaturatedConversion, StaticLookup};
 pub use pallet_protos;
 
 pub use pallet_contracts::Schedule;
+mod chain_extension;
+use chain_extension::FragmentsExtension;
+
+use pallet_fragments::{
+	GetDefinitionsParams, GetInstancesParams, GetOwnersParams, Listing, VaultBalances,
+};
 use pallet_protos::GetProtosParams;
+use sp_clamor::Hash128;
 
 // Prints debug output of the `contracts` pallet to stdout if the node is
 // started with `-lruntime::contracts=debug`.
@@ -79,6 +86,9 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of a fungible asset (see `pallet_assets`).
+pub type AssetId = u64;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -130,10 +140,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -360,7 +370,13 @@ impl pallet_sudo::Config for Runtime {
 
 impl pallet_fragments::Config for Runtime {
 	type Event = Event;
-	type WeightInfo = ();
+	type Call = Call;
+	type MaxBatchSize = ConstU32<1000>;
+	type MaxExpirationsPerBlock = ConstU32<256>;
+	type MaxAuctionsPerBlock = ConstU32<64>;
+	type MaxSalePhases = ConstU32<16>;
+	type MaxInstanceMetadata = ConstU32<64>;
+	type WeightInfo = pallet_fragments::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_accounts::EthFragContract for Runtime {
@@ -381,7 +397,7 @@ impl pallet_accounts::Config for Runtime {
 
 impl pallet_protos::Config for Runtime {
 	type Event = Event;
-	type WeightInfo = ();
+	type WeightInfo = pallet_protos::weights::SubstrateWeight<Runtime>;
 	type StorageBytesMultiplier = StorageBytesMultiplier;
 	// type StakeLockupPeriod = ConstU64<100800>; // one week
 	type StakeLockupPeriod = ConstU64<5>; // one week
@@ -391,6 +407,8 @@ impl pallet_detach::Config for Runtime {
 	type Event = Event;
 	type WeightInfo = ();
 	type AuthorityId = pallet_detach::crypto::DetachAuthId;
+	type MaxDetachRequests = ConstU32<256>;
+	type DetachTimeout = ConstU64<HOURS>;
 }
 
 impl pallet_multisig::Config for Runtime {
@@ -535,7 +553,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 31];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = ();
-	type ChainExtension = ();
+	type ChainExtension = FragmentsExtension;
 	type DeletionQueueDepth = DeletionQueueDepth;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = MySchedule;
@@ -569,7 +587,7 @@ parameter_types! {
 impl pallet_assets::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
-	type AssetId = u64;
+	type AssetId = AssetId;
 	type Currency = Balances;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type AssetDeposit = AssetDeposit;
@@ -655,6 +673,23 @@ pub type SignedExtra = (
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
+/// Storage migrations to run on runtime upgrade.
+pub type Migrations = (
+	pallet_protos::migration::v1::MigrateToV1<Runtime>,
+	pallet_fragments::migration::v1::MigrateToV1<Runtime>,
+	pallet_fragments::migration::v2::MigrateToV2<Runtime>,
+	pallet_fragments::migration::v3::MigrateToV3<Runtime>,
+	pallet_fragments::migration::v4::MigrateToV4<Runtime>,
+	pallet_fragments::migration::v5::MigrateToV5<Runtime>,
+	pallet_fragments::migration::v6::MigrateToV6<Runtime>,
+	pallet_fragments::migration::v7::MigrateToV7<Runtime>,
+	pallet_fragments::migration::v8::MigrateToV8<Runtime>,
+	pallet_fragments::migration::v9::MigrateToV9<Runtime>,
+	pallet_accounts::migration::v1::MigrateToV1<Runtime>,
+	pallet_detach::migration::v1::MigrateToV1<Runtime>,
+	pallet_detach::migration::v2::MigrateToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -662,6 +697,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 impl_runtime_apis! {
@@ -861,6 +897,51 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fragments_rpc_runtime_api::FragmentsApi<Block, AccountId, <Runtime as pallet_assets::Config>::AssetId, BlockNumber> for Runtime {
+		fn get_vault_balances(fragment_hash: Hash128) -> Option<VaultBalances<<Runtime as pallet_assets::Config>::AssetId>> {
+			Fragments::get_vault_balances(fragment_hash)
+		}
+
+		fn get_royalty(fragment_hash: Hash128, price: u128) -> Option<(AccountId, u128)> {
+			Fragments::get_royalty(fragment_hash, price)
+		}
+
+		fn get_listings(fragment_hash: Hash128) -> Vec<(u64, u64, Listing<<Runtime as pallet_assets::Config>::AssetId, AccountId, BlockNumber>)> {
+			Fragments::get_listings(fragment_hash)
+		}
+
+		fn get_definitions(params: GetDefinitionsParams<AccountId, Vec<u8>>) -> Result<Vec<u8>, Vec<u8>> {
+			Fragments::get_definitions(params)
+		}
+
+		fn get_instances(params: GetInstancesParams<AccountId, Vec<u8>>) -> Result<Vec<u8>, Vec<u8>> {
+			Fragments::get_instances(params)
+		}
+
+		fn get_owners(params: GetOwnersParams<Vec<u8>>) -> Result<Vec<u8>, Vec<u8>> {
+			Fragments::get_owners(params)
+		}
+
+		fn quote_price(fragment_hash: Hash128, quantity: u64) -> Option<u128> {
+			Fragments::quote_price(fragment_hash, quantity)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> (Weight, Weight) {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
+			// right here and right now.
+			let weight = Executive::try_runtime_upgrade().unwrap();
+			(weight, RuntimeBlockWeights::get().max_block)
+		}
+
+		fn execute_block_no_check(block: Block) -> Weight {
+			Executive::execute_block_no_check(block)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (